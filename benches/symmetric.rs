@@ -0,0 +1,81 @@
+use accelerate_general::constants::{CblasOrder, CblasUpLow};
+use accelerate_general::matrix_f32::{pack_sym_mat_vec_mul, sym_mat_vec_mul};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const SIZES: [usize; 4] = [64, 256, 1024, 2048];
+
+/// Deterministic values in `[-1, 1)` from a 64-bit LCG, so runs are comparable across machines.
+fn lcg_values(len: usize, mut state: u64) -> Vec<f32> {
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 40) as f32 / (1u64 << 24) as f32) * 2.0 - 1.0
+        })
+        .collect()
+}
+
+/// Returns the same symmetric `n x n` matrix in full row-major storage and in packed upper storage.
+fn symmetric_input(n: usize) -> (Vec<f32>, Vec<f32>) {
+    let values = lcg_values(n * n, n as u64);
+    let mut full = vec![0.0; n * n];
+    let mut packed = Vec::with_capacity(n * (n + 1) / 2);
+    for i in 0..n {
+        for j in i..n {
+            let v = values[i * n + j];
+            full[i * n + j] = v;
+            full[j * n + i] = v;
+            packed.push(v);
+        }
+    }
+    (full, packed)
+}
+
+fn bench_symmetric_matvec(c: &mut Criterion) {
+    let mut group = c.benchmark_group("symmetric_matvec");
+    for &n in SIZES.iter() {
+        let (full, packed) = symmetric_input(n);
+        let x = lcg_values(n, 7);
+        let mut y = vec![0.0f32; n];
+
+        group.bench_with_input(BenchmarkId::new("ssymv_full", n), &n, |b, &n| {
+            b.iter(|| unsafe {
+                sym_mat_vec_mul(
+                    CblasOrder::RowMajor,
+                    CblasUpLow::Upper,
+                    n as i32,
+                    1.0,
+                    black_box(full.as_ptr()),
+                    n as i32,
+                    x.as_ptr(),
+                    1,
+                    0.0,
+                    y.as_mut_ptr(),
+                    1,
+                )
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("sspmv_packed", n), &n, |b, &n| {
+            b.iter(|| unsafe {
+                pack_sym_mat_vec_mul(
+                    CblasOrder::RowMajor,
+                    CblasUpLow::Upper,
+                    n as i32,
+                    1.0,
+                    black_box(packed.as_ptr()),
+                    x.as_ptr(),
+                    1,
+                    0.0,
+                    y.as_mut_ptr(),
+                    1,
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_symmetric_matvec);
+criterion_main!(benches);
@@ -0,0 +1,71 @@
+use accelerate_general::constants::{CblasOrder, CblasTranspose};
+use accelerate_general::matrix_f32::{mat_mul, sgemm_materialized_transpose};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const SIZES: [usize; 4] = [64, 256, 1024, 2048];
+
+/// Deterministic values in `[-1, 1)` from a 64-bit LCG, so runs are comparable across machines.
+fn lcg_values(len: usize, mut state: u64) -> Vec<f32> {
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 40) as f32 / (1u64 << 24) as f32) * 2.0 - 1.0
+        })
+        .collect()
+}
+
+/// `C = A^T * B^T` for square `n x n` operands, once with the transpose flags handed to `cblas_sgemm`
+/// and once with the operands transposed in memory first.
+fn bench_transposed_gemm(c: &mut Criterion) {
+    let mut group = c.benchmark_group("transposed_gemm");
+    for &n in SIZES.iter() {
+        let a = lcg_values(n * n, n as u64);
+        let b = lcg_values(n * n, 2 * n as u64 + 1);
+        let mut out = vec![0.0f32; n * n];
+
+        group.bench_with_input(BenchmarkId::new("sgemm_trans_flags", n), &n, |bench, &n| {
+            bench.iter(|| unsafe {
+                mat_mul(
+                    CblasOrder::RowMajor,
+                    CblasTranspose::Trans,
+                    CblasTranspose::Trans,
+                    n as i32,
+                    n as i32,
+                    n as i32,
+                    1.0,
+                    black_box(a.as_ptr()),
+                    n as i32,
+                    black_box(b.as_ptr()),
+                    n as i32,
+                    0.0,
+                    out.as_mut_ptr(),
+                    n as i32,
+                )
+            })
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("sgemm_materialized", n),
+            &n,
+            |bench, &n| {
+                bench.iter(|| {
+                    sgemm_materialized_transpose(
+                        black_box(&a),
+                        black_box(&b),
+                        &mut out,
+                        (n, n, n),
+                        CblasTranspose::Trans,
+                        CblasTranspose::Trans,
+                    )
+                    .unwrap()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_transposed_gemm);
+criterion_main!(benches);
@@ -1,15 +1,46 @@
+use crate::error::BlasError;
+use std::convert::TryFrom;
+use std::ffi::c_char;
+
 pub type RowColMajor = CblasOrder;
 pub type TransposeMode = CblasTranspose;
 pub type UpOrLowTriangle = CblasUpLow;
 pub type IsDiagUnit = CblasDiag;
 pub type MultiplyOrder = CblasSide;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(i32)]
 pub enum CblasOrder {
     RowMajor = 101,
     ColMajor = 102,
 }
 
+impl CblasOrder {
+    /// Returns the minimal leading dimension (`lda`) of a tightly packed `rows x cols` matrix in this order:
+    /// `cols` for row-major and `rows` for column-major.
+    pub fn leading_dimension(self, rows: i32, cols: i32) -> i32 {
+        match self {
+            CblasOrder::RowMajor => cols,
+            CblasOrder::ColMajor => rows,
+        }
+    }
+}
+
+/// Returns `true` when a `rows x cols` matrix stored with leading dimension `lda` in `order` has no padding
+/// between its rows (row-major) or columns (column-major), so its elements form one flat, gap-free run of
+/// `rows * cols` values.
+///
+/// That is the case when `lda` equals [`CblasOrder::leading_dimension`], or when there is at most one
+/// row (row-major) or column (column-major) and `lda` never comes into play.
+pub fn is_contiguous(rows: i32, cols: i32, lda: i32, order: CblasOrder) -> bool {
+    let outer = match order {
+        CblasOrder::RowMajor => rows,
+        CblasOrder::ColMajor => cols,
+    };
+    outer <= 1 || lda == order.leading_dimension(rows, cols)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(i32)]
 pub enum CblasTranspose {
     NoTrans = 111,
@@ -18,20 +49,177 @@ pub enum CblasTranspose {
     AtlasConj = 114,
 }
 
+/// The stored shapes `gemm` requires of each operand, as returned by [`gemm_shapes`].
+///
+/// Shapes are logical `(rows, cols)` as the matrices are stored, before any transpose is applied; the
+/// storage order and leading dimensions are up to the caller.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GemmShapes {
+    pub a_rows: usize,
+    pub a_cols: usize,
+    pub b_rows: usize,
+    pub b_cols: usize,
+    pub c_rows: usize,
+    pub c_cols: usize,
+}
+
+/// Returns the shapes `A`, `B` and `C` must be stored in for `C = alpha * op(A) * op(B) + beta * C` to
+/// produce an `m x n` result with inner dimension `k`.
+///
+/// `op(A)` is `m x k` and `op(B)` is `k x n`, so an operand that is transposed (`Trans` or `ConjTrans`)
+/// must be stored with its dimensions swapped: `A` is `k x m` under `Trans`. `NoTrans` and `AtlasConj`,
+/// which conjugates without transposing, keep the shape. These are the rules the checked `gemm` wrappers
+/// validate against, so a buffer of `a_rows * a_cols` elements with a tight leading dimension always passes.
+pub fn gemm_shapes(
+    trans_a: CblasTranspose,
+    trans_b: CblasTranspose,
+    m: usize,
+    n: usize,
+    k: usize,
+) -> GemmShapes {
+    let ((a_rows, a_cols), (b_rows, b_cols)) = (trans_a.stored(m, k), trans_b.stored(k, n));
+    GemmShapes {
+        a_rows,
+        a_cols,
+        b_rows,
+        b_cols,
+        c_rows: m,
+        c_cols: n,
+    }
+}
+
+impl CblasTranspose {
+    /// Rows and columns of a matrix as stored, given the `rows x cols` shape of `op(X)`.
+    fn stored(self, rows: usize, cols: usize) -> (usize, usize) {
+        match self {
+            CblasTranspose::NoTrans | CblasTranspose::AtlasConj => (rows, cols),
+            CblasTranspose::Trans | CblasTranspose::ConjTrans => (cols, rows),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(i32)]
 pub enum CblasUpLow {
     Upper = 121,
     Lower = 122,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(i32)]
 pub enum CblasDiag {
     NonUnit = 131,
     Unit = 132,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(i32)]
 pub enum CblasSide {
     Left = 141,
     Right = 142,
 }
+
+/// Implements `TryFrom<char>` (case-insensitive) and `as_char` for an option enum, using the characters
+/// reference BLAS takes for it.
+macro_rules! blas_char {
+    ($ty:ident, $which:literal, { $($c:literal => $variant:ident),+ $(,)? }) => {
+        impl $ty {
+            /// The reference BLAS character for this option, in upper case.
+            pub fn as_char(&self) -> char {
+                match self {
+                    $($ty::$variant => $c,)+
+                }
+            }
+        }
+
+        impl TryFrom<char> for $ty {
+            type Error = BlasError;
+
+            /// Parses the reference BLAS character for this option, in either case.
+            ///
+            /// # Errors
+            /// Returns [`BlasError::InvalidFlag`] for any other character.
+            fn try_from(flag: char) -> Result<Self, BlasError> {
+                match flag.to_ascii_uppercase() {
+                    $($c => Ok($ty::$variant),)+
+                    _ => Err(BlasError::InvalidFlag { which: $which, flag }),
+                }
+            }
+        }
+    };
+}
+
+// `AtlasConj` (conjugate without transposing) is not part of reference BLAS; `'R'` is the character
+// OpenBLAS uses for it.
+blas_char!(CblasTranspose, "trans", { 'N' => NoTrans, 'T' => Trans, 'C' => ConjTrans, 'R' => AtlasConj });
+blas_char!(CblasUpLow, "uplo", { 'U' => Upper, 'L' => Lower });
+blas_char!(CblasDiag, "diag", { 'N' => NonUnit, 'U' => Unit });
+blas_char!(CblasSide, "side", { 'L' => Left, 'R' => Right });
+
+/// Which eigenvectors a LAPACK symmetric eigensolver (`?syev`) computes, passed as its `jobz` argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EigenJob {
+    /// Eigenvalues only (`'N'`).
+    ValuesOnly,
+    /// Eigenvalues and eigenvectors (`'V'`).
+    ValuesAndVectors,
+}
+
+impl EigenJob {
+    /// The LAPACK character for this job, as passed by pointer to the Fortran routine.
+    pub fn to_lapack(self) -> c_char {
+        (match self {
+            EigenJob::ValuesOnly => b'N',
+            EigenJob::ValuesAndVectors => b'V',
+        }) as c_char
+    }
+}
+
+/// Which singular vectors LAPACK `?gesvd` computes, passed as its `jobu` or `jobvt` argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SvdJob {
+    /// All `m` columns of `U` (all `n` rows of `V^T`) (`'A'`).
+    All,
+    /// Only the leading `min(m, n)` columns of `U` (rows of `V^T`) (`'S'`).
+    Thin,
+    /// The leading `min(m, n)` vectors, written over `A` instead of a separate buffer (`'O'`).
+    Overwrite,
+    /// No singular vectors (`'N'`).
+    None,
+}
+
+impl SvdJob {
+    /// The LAPACK character for this job, as passed by pointer to the Fortran routine.
+    pub fn to_lapack(self) -> c_char {
+        (match self {
+            SvdJob::All => b'A',
+            SvdJob::Thin => b'S',
+            SvdJob::Overwrite => b'O',
+            SvdJob::None => b'N',
+        }) as c_char
+    }
+}
+
+/// Which eigenvalues a selective symmetric eigensolver (`?syevr`) computes, passed as its `range` argument
+/// together with the bounds it selects.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EigenRange<T> {
+    /// All eigenvalues (`'A'`).
+    All,
+    /// The eigenvalues in the half-open interval `(lower, upper]` (`'V'`).
+    Values(T, T),
+    /// The eigenvalues at these positions in ascending order, counted from zero (`'I'`). `0..3` selects the
+    /// three smallest.
+    Indices(std::ops::Range<usize>),
+}
+
+impl<T> EigenRange<T> {
+    /// The LAPACK character for this range, as passed by pointer to the Fortran routine.
+    pub fn to_lapack(&self) -> c_char {
+        (match self {
+            EigenRange::All => b'A',
+            EigenRange::Values(..) => b'V',
+            EigenRange::Indices(_) => b'I',
+        }) as c_char
+    }
+}
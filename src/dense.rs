@@ -0,0 +1,205 @@
+use crate::constants::{RowColMajor, TransposeMode};
+use crate::error::BlasError;
+use crate::scalar::BlasScalar;
+use crate::validate::{check_ld, check_len, check_shape, strided_len, to_c_int};
+use crate::view::{outer_inner, MatrixView};
+
+/// An owned dense matrix: a `Vec` together with its shape, leading dimension and storage order.
+///
+/// Consecutive rows (row-major) or columns (column-major) are `lda` elements apart, so padded buffers
+/// from other libraries can be adopted without repacking.
+#[derive(Clone)]
+pub struct DenseMatrix<T> {
+    data: Vec<T>,
+    rows: usize,
+    cols: usize,
+    lda: usize,
+    order: RowColMajor,
+}
+
+impl<T> DenseMatrix<T> {
+    /// Takes ownership of `data` as a tightly packed `rows x cols` matrix, so `lda` is `cols` (row-major)
+    /// or `rows` (column-major).
+    ///
+    /// # Errors
+    /// Returns [`BlasError::DimensionMismatch`] unless `data.len() == rows * cols` exactly. A longer
+    /// buffer is rejected too, since trailing elements usually mean the shape is wrong; use
+    /// [`from_vec_with_lda`](DenseMatrix::from_vec_with_lda) for padded buffers.
+    pub fn new(
+        data: Vec<T>,
+        rows: usize,
+        cols: usize,
+        order: RowColMajor,
+    ) -> Result<Self, BlasError> {
+        // A product that overflows cannot match any buffer length, so saturating is enough.
+        check_shape("data", rows.saturating_mul(cols), data.len())?;
+        let (_, inner) = outer_inner(rows, cols, order);
+        Ok(DenseMatrix {
+            data,
+            rows,
+            cols,
+            lda: inner.max(1),
+            order,
+        })
+    }
+
+    /// Takes ownership of `data`, checking that it holds a `rows x cols` matrix with leading dimension `lda`.
+    ///
+    /// # Errors
+    /// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is smaller than `cols` (row-major) or
+    /// `rows` (column-major), and [`BlasError::BufferTooSmall`] if `data` is too short.
+    pub fn from_vec_with_lda(
+        data: Vec<T>,
+        rows: usize,
+        cols: usize,
+        lda: usize,
+        order: RowColMajor,
+    ) -> Result<Self, BlasError> {
+        let (outer, inner) = outer_inner(rows, cols, order);
+        check_ld("lda", inner.max(1), lda)?;
+        check_len("data", strided_len(outer, inner, lda), data.len())?;
+        Ok(DenseMatrix {
+            data,
+            rows,
+            cols,
+            lda,
+            order,
+        })
+    }
+
+    /// The number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The distance, in elements, between consecutive rows (row-major) or columns (column-major).
+    pub fn lda(&self) -> usize {
+        self.lda
+    }
+
+    /// The storage order of the underlying buffer.
+    pub fn order(&self) -> RowColMajor {
+        self.order
+    }
+
+    /// The underlying buffer, including any padding between rows or columns.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns element `(row, col)`, or `None` if it is out of range.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.index(row, col).map(|i| &self.data[i])
+    }
+
+    /// Returns element `(row, col)` mutably, or `None` if it is out of range.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.index(row, col).map(move |i| &mut self.data[i])
+    }
+
+    /// Gives the underlying buffer back.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Borrows the matrix as a [`MatrixView`].
+    pub fn view(&self) -> MatrixView<'_, T> {
+        // The shape was validated on construction, so this cannot fail.
+        MatrixView::new(&self.data, self.rows, self.cols, self.lda, self.order).unwrap()
+    }
+
+    /// The position of element `(row, col)` in `data`, or `None` if it is out of range.
+    fn index(&self, row: usize, col: usize) -> Option<usize> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        let (outer, inner) = outer_inner(row, col, self.order);
+        Some(outer * self.lda + inner)
+    }
+}
+
+impl<T: BlasScalar> DenseMatrix<T> {
+    /// Returns a `rows x cols` matrix of zeros with a tight leading dimension.
+    pub fn zeros(rows: usize, cols: usize, order: RowColMajor) -> Self {
+        let (_, inner) = outer_inner(rows, cols, order);
+        DenseMatrix {
+            data: vec![T::ZERO; rows * cols],
+            rows,
+            cols,
+            lda: inner.max(1),
+            order,
+        }
+    }
+
+    /// Computes the product `self * b` into a newly allocated matrix in `self`'s storage order.
+    ///
+    /// # Errors
+    /// Returns [`BlasError::DimensionMismatch`] if `self.cols() != b.rows()`.
+    pub fn gemm(&self, b: &DenseMatrix<T>) -> Result<DenseMatrix<T>, BlasError> {
+        let mut c = DenseMatrix::zeros(self.rows, b.cols, self.order);
+        self.gemm_into(b, &mut c, T::ONE, T::ZERO)?;
+        Ok(c)
+    }
+
+    /// Computes `c = alpha * self * b + beta * c` in place, reusing `c`'s storage.
+    ///
+    /// This is the accumulating counterpart of [`gemm`](DenseMatrix::gemm): with `beta = 1` it adds the
+    /// product onto `c`, so a sum of products can be built up without allocating per step.
+    ///
+    /// # Parameters
+    /// - `b`: The right-hand factor, `self.cols() x n`.
+    /// - `c`: The `self.rows() x n` accumulator, overwritten with the result.
+    /// - `alpha`: The scalar applied to the product.
+    /// - `beta`: The scalar applied to `c` before accumulation. When `beta` is zero `c` need not be
+    ///   initialized.
+    ///
+    /// # Errors
+    /// Returns [`BlasError::DimensionMismatch`] if `b` has the wrong number of rows or `c` the wrong shape.
+    ///
+    /// # Discussion
+    /// The three matrices may use different storage orders. The call is made in `c`'s order, and an
+    /// operand stored in the other order is passed to `cblas_?gemm` as its transpose, which reads the same
+    /// buffer without copying.
+    pub fn gemm_into(
+        &self,
+        b: &DenseMatrix<T>,
+        c: &mut DenseMatrix<T>,
+        alpha: T,
+        beta: T,
+    ) -> Result<(), BlasError> {
+        check_shape("b", self.cols, b.rows)?;
+        check_shape("c", self.rows, c.rows)?;
+        check_shape("c", b.cols, c.cols)?;
+        let trans = |order: RowColMajor| {
+            if order == c.order {
+                TransposeMode::NoTrans
+            } else {
+                TransposeMode::Trans
+            }
+        };
+        unsafe {
+            T::gemm(
+                c.order,
+                trans(self.order),
+                trans(b.order),
+                to_c_int(c.rows, "m")?,
+                to_c_int(c.cols, "n")?,
+                to_c_int(self.cols, "k")?,
+                alpha,
+                self.data.as_ptr(),
+                to_c_int(self.lda, "lda")?,
+                b.data.as_ptr(),
+                to_c_int(b.lda, "ldb")?,
+                beta,
+                c.data.as_mut_ptr(),
+                to_c_int(c.lda, "ldc")?,
+            );
+        }
+        Ok(())
+    }
+}
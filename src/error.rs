@@ -0,0 +1,130 @@
+use std::error::Error;
+use std::ffi::c_int;
+use std::fmt;
+
+/// Errors reported by the safe wrappers before a call reaches Accelerate.
+///
+/// The raw bindings perform no checking at all; the safe wrappers validate their slices and
+/// dimensions up front and return one of these variants instead of handing Accelerate an
+/// out-of-bounds pointer. Every `usize` dimension is also range-checked before being narrowed to the
+/// C `int` Accelerate takes, so an oversized dimension surfaces as [`BlasError::IntOverflow`] rather than
+/// wrapping to a negative or truncated value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlasError {
+    /// A buffer holds fewer elements than the dimensions and strides require.
+    BufferTooSmall {
+        which: &'static str,
+        needed: usize,
+        got: usize,
+    },
+    /// Two operands that must agree in size do not.
+    DimensionMismatch {
+        which: &'static str,
+        expected: usize,
+        got: usize,
+    },
+    /// A leading dimension is smaller than the minimum the routine accepts.
+    InvalidLeadingDimension {
+        which: &'static str,
+        min: usize,
+        got: usize,
+    },
+    /// An index addresses an element past the end of a buffer.
+    IndexOutOfBounds {
+        which: &'static str,
+        index: usize,
+        len: usize,
+    },
+    /// The same index is given twice where the routine needs distinct ones.
+    RepeatedIndex { which: &'static str, index: usize },
+    /// A dimension, leading dimension or length does not fit in the C `int` Accelerate takes.
+    IntOverflow { which: &'static str, value: usize },
+    /// A type-erased buffer is not aligned for the element type it is read as.
+    Misaligned { which: &'static str, align: usize },
+    /// A Cholesky factorization failed because the matrix is not positive definite.
+    NotPositiveDefinite { minor: usize },
+    /// A vector increment is zero, which BLAS leaves undefined.
+    ZeroIncrement { which: &'static str },
+    /// A lower or upper bound is NaN, or the lower bound exceeds the upper one.
+    InvalidBounds { which: &'static str },
+    /// A character does not name any value of the option it was parsed as.
+    InvalidFlag { which: &'static str, flag: char },
+    /// An iterative LAPACK routine stopped with `unconverged` quantities still not converged.
+    NoConvergence { unconverged: usize },
+}
+
+/// Shorthand for a [`std::result::Result`] whose error is [`BlasError`].
+pub type Result<T> = std::result::Result<T, BlasError>;
+
+impl fmt::Display for BlasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlasError::BufferTooSmall { which, needed, got } => write!(
+                f,
+                "buffer `{}` is too small: needed {} elements, got {}",
+                which, needed, got
+            ),
+            BlasError::DimensionMismatch {
+                which,
+                expected,
+                got,
+            } => write!(
+                f,
+                "dimension mismatch for `{}`: expected {}, got {}",
+                which, expected, got
+            ),
+            BlasError::InvalidLeadingDimension { which, min, got } => write!(
+                f,
+                "leading dimension `{}` is invalid: must be at least {}, got {}",
+                which, min, got
+            ),
+            BlasError::IndexOutOfBounds { which, index, len } => write!(
+                f,
+                "index {} into `{}` is out of bounds for length {}",
+                index, which, len
+            ),
+            BlasError::RepeatedIndex { which, index } => write!(
+                f,
+                "index {} appears twice in `{}`, which needs distinct indices",
+                index, which
+            ),
+            BlasError::IntOverflow { which, value } => write!(
+                f,
+                "`{}` = {} does not fit in a C int (max {})",
+                which,
+                value,
+                c_int::MAX
+            ),
+            BlasError::Misaligned { which, align } => write!(
+                f,
+                "buffer `{}` is not aligned to {} bytes for its element type",
+                which, align
+            ),
+            BlasError::NotPositiveDefinite { minor } => write!(
+                f,
+                "matrix is not positive definite: the leading minor of order {} is not positive",
+                minor
+            ),
+            BlasError::ZeroIncrement { which } => write!(
+                f,
+                "increment `{}` is zero: BLAS needs a non-zero stride between vector elements",
+                which
+            ),
+            BlasError::InvalidBounds { which } => write!(
+                f,
+                "bound `{}` is invalid: bounds must not be NaN and `min` must not exceed `max`",
+                which
+            ),
+            BlasError::InvalidFlag { which, flag } => {
+                write!(f, "'{}' is not a valid `{}` character", flag, which)
+            }
+            BlasError::NoConvergence { unconverged } => write!(
+                f,
+                "iteration did not converge: {} values remain unconverged",
+                unconverged
+            ),
+        }
+    }
+}
+
+impl Error for BlasError {}
@@ -1,3 +1,5 @@
+use crate::error::BlasError;
+use crate::validate::{check_inc, check_len, to_c_int, vec_len};
 use num_complex::Complex;
 use std::ffi::{c_double, c_float, c_int};
 
@@ -32,6 +34,35 @@ extern "C" {
         s: c_float,
     );
 
+    /// Applies a Givens rotation matrix to a pair of vectors `x` and `y`.
+    ///
+    /// It is applied to each pair of elements from `x` and `y`.
+    ///
+    /// # Precision
+    /// This function operates on `f64` numbers (double-precision).
+    ///
+    /// # Parameters
+    /// - `n`: The number of elements in vectors `x` and `y`.
+    /// - `x`: A pointer to the vector `x`, modified on return.
+    /// - `inc_x`: The increment (stride) between elements in `x`. For example, if `inc_x = 7`, every 7th element is used.
+    /// - `y`: A pointer to the vector `y`, modified on return.
+    /// - `inc_y`: The increment (stride) between elements in `y`. For example, if `inc_y = 7`, every 7th element is used.
+    /// - `c`: The value `cos(θ)` in the Givens rotation matrix.
+    /// - `s`: The value `sin(θ)` in the Givens rotation matrix.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function. The caller must ensure that the memory regions accessed by `x` and `y` (up to `n * inc_x` and `n * inc_y`) are valid and within bounds.
+    #[link_name = "cblas_drot"]
+    pub fn givens_rot_f64(
+        n: c_int,
+        x: *mut c_double,
+        inc_x: c_int,
+        y: *mut c_double,
+        inc_y: c_int,
+        c: c_double,
+        s: c_double,
+    );
+
     /// Constructs a Givens rotation matrix that zeroes the lower value (`b`) in a vertical matrix containing `a` and `b`.
     ///
     /// A Givens rotation is used to introduce zeros into vectors or matrices, which is useful in algorithms like QR decomposition.
@@ -62,6 +93,36 @@ extern "C" {
     #[link_name = "cblas_srotg"]
     pub fn givens_gen_f32(a: *mut c_float, b: *mut c_float, c: *mut c_float, s: *mut c_float);
 
+    /// Constructs a Givens rotation matrix that zeroes the lower value (`b`) in a vertical matrix containing `a` and `b`.
+    ///
+    /// A Givens rotation is used to introduce zeros into vectors or matrices, which is useful in algorithms like QR decomposition.
+    ///
+    /// Given two numbers `a` and `b`, this function computes the values of `cos(θ)` (`c`) and `sin(θ)` (`s`) such that the resulting vector after the Givens rotation will have a zero in the second position (`b` becomes `0`).
+    ///
+    /// # Formula
+    /// Givens Rotation Matrix, this matrix rotates a 2D vector such that the second component becomes zero.
+    /// Given values `a` and `b`, the function computes the cosine (c = cos(θ)) and sine (s = sin(θ)) that zero out the second component `b`.
+    ///
+    /// The resulting values are:
+    ///
+    ///    a' = sqrt(a² + b²),   b' = 0
+    ///
+    /// where a' is the updated value of `a`, and b' is set to zero.
+    ///
+    /// # Precision
+    /// This function operates on `f64` values (double-precision).
+    ///
+    /// # Parameters
+    /// - `a`: Double-precision value `a`. Overwritten on return with result `r`, the magnitude of the Givens rotation.
+    /// - `b`: Double-precision value `b`. Overwritten on return with result `z` (zero).
+    /// - `c`: Overwritten on return with the value `cos(θ)`, the cosine of the Givens rotation.
+    /// - `s`: Overwritten on return with the value `sin(θ)`, the sine of the Givens rotation.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function. The caller must ensure that the pointers passed to `a`, `b`, `c`, and `s` are valid memory locations for the results.
+    #[link_name = "cblas_drotg"]
+    pub fn givens_gen_f64(a: *mut c_double, b: *mut c_double, c: *mut c_double, s: *mut c_double);
+
     /// Applies a modified Givens transformation to two single-precision vectors `X` and `Y`.
     ///
     /// # Precision
@@ -96,6 +157,40 @@ extern "C" {
         p: *const c_float,
     );
 
+    /// Applies a modified Givens transformation to two double-precision vectors `X` and `Y`.
+    ///
+    /// # Precision
+    /// This function operates on `f64` values (double-precision).
+    ///
+    /// # Parameters
+    /// - `n`: The number of elements in the vectors `X` and `Y`.
+    /// - `x`: Pointer to the vector `X`, which is modified on return.
+    /// - `inc_x`: The increment between elements in `X`. For example, if `inc_x = 7`, every 7th element is used.
+    /// - `y`: Pointer to the vector `Y`, which is modified on return.
+    /// - `inc_y`: The increment between elements in `Y`. For example, if `inc_y = 7`, every 7th element is used.
+    /// - `p`: Pointer to a 5-element vector, where:
+    ///   - `p[0]`: Flag that defines the form of matrix `H`. Possible values are:
+    ///     - `-2.0`: Matrix `H` is the identity matrix.
+    ///     - `-1.0`: Matrix `H` is the matrix `SH` (defined by the remaining values in `p`).
+    ///     - `0.0`: Matrix `H[1,2]` and `H[2,1]` are derived from `SH`. The remaining values are `1.0`.
+    ///     - `1.0`: Matrix `H[1,1]` and `H[2,2]` are derived from `SH`. `H[1,2]` is `1.0` and `H[2,1]` is `-1.0`.
+    ///   - `p[1]`: Value for `SH[1,1]`.
+    ///   - `p[2]`: Value for `SH[2,1]`.
+    ///   - `p[3]`: Value for `SH[1,2]`.
+    ///   - `p[4]`: Value for `SH[2,2]`.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function. The caller must ensure that the memory regions pointed to by `x`, `y`, and `p` are valid.
+    #[link_name = "cblas_drotm"]
+    pub fn givens_mod_rot_f64(
+        n: c_int,
+        x: *mut c_double,
+        inc_x: c_int,
+        y: *mut c_double,
+        inc_y: c_int,
+        p: *const c_double,
+    );
+
     /// Generates a modified Givens rotation matrix that zeroes the second component of the vector (`sqrt(D1) * B1`, `sqrt(D2) * B2`).
     ///
     /// # Precision
@@ -128,6 +223,38 @@ extern "C" {
         p: *mut c_float,
     );
 
+    /// Generates a modified Givens rotation matrix that zeroes the second component of the vector (`sqrt(D1) * B1`, `sqrt(D2) * B2`).
+    ///
+    /// # Precision
+    /// This function operates on `f64` values (double-precision).
+    ///
+    /// # Parameters
+    /// - `d1`: Scaling factor `D1`, overwritten with an updated value on return.
+    /// - `d2`: Scaling factor `D2`, overwritten with an updated value on return.
+    /// - `b1`: Scaling factor `B1`, overwritten with an updated value on return.
+    /// - `b2`: Scaling factor `B2`, used as input.
+    /// - `p`: A 5-element vector for storing the resulting modified Givens rotation matrix:
+    ///   - `p[0]`: Flag value that defines the form of matrix `H`:
+    ///     - `-2.0`: Identity matrix.
+    ///     - `-1.0`: Matrix `H` is identical to `SH`.
+    ///     - `0.0`: `H[1,2]` and `H[2,1]` are derived from `SH`; other values are `1.0`.
+    ///     - `1.0`: `H[1,1]` and `H[2,2]` are derived from `SH`; `H[1,2] = 1.0`, `H[2,1] = -1.0`.
+    ///   - `p[1]`: Value for `SH[1,1]`.
+    ///   - `p[2]`: Value for `SH[2,1]`.
+    ///   - `p[3]`: Value for `SH[1,2]`.
+    ///   - `p[4]`: Value for `SH[2,2]`.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function. The caller must ensure that the pointers to `d1`, `d2`, `b1`, and `p` are valid.
+    #[link_name = "cblas_drotmg"]
+    pub fn givens_mod_gen_f64(
+        d1: *mut c_double,
+        d2: *mut c_double,
+        b1: *mut c_double,
+        b2: c_double,
+        p: *mut c_double,
+    );
+
     /// Constructs a complex Givens rotation that zeroes the second element of a 2-element complex vector.
     ///
     /// # Precision
@@ -243,3 +370,194 @@ extern "C" {
     );
 
 }
+
+/// Applies a plane rotation with **real** `c` and `s` to a pair of complex vectors via [`givens_rot_c32`]
+/// (`cblas_csrot`):
+///
+/// ```text
+/// x[i] =  c * x[i] + s * y[i]
+/// y[i] = -s * x[i] + c * y[i]
+/// ```
+///
+/// Both `c` and `s` are real, so each complex element is combined as a whole, with its real and imaginary
+/// parts rotated by the same factors. This is not the general complex rotation produced by
+/// [`givens_gen_c32`] (`cblas_crotg`), whose `s` is complex; that rotation cannot be applied with `csrot`.
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers with real `f32` coefficients.
+///
+/// # Parameters
+/// - `n`: The number of logical elements in `x` and `y`.
+/// - `x`: The first vector, rotated in place.
+/// - `inc_x`: The increment between elements in `x`.
+/// - `y`: The second vector, rotated in place.
+/// - `inc_y`: The increment between elements in `y`.
+/// - `c`: The value `cos(θ)`.
+/// - `s`: The value `sin(θ)`.
+///
+/// # Errors
+/// Returns [`BlasError::BufferTooSmall`] if `x` or `y` is too short for `n` elements at its increment.
+/// Returns [`BlasError::ZeroIncrement`] if `inc_x` or `inc_y` is zero.
+pub fn csrot(
+    n: usize,
+    x: &mut [Complex<f32>],
+    inc_x: i32,
+    y: &mut [Complex<f32>],
+    inc_y: i32,
+    c: f32,
+    s: f32,
+) -> Result<(), BlasError> {
+    check_inc("inc_x", inc_x)?;
+    check_len("x", vec_len(n, inc_x), x.len())?;
+    check_inc("inc_y", inc_y)?;
+    check_len("y", vec_len(n, inc_y), y.len())?;
+    unsafe {
+        traced!(
+            "cblas_csrot",
+            [n],
+            givens_rot_c32(
+                to_c_int(n, "n")?,
+                x.as_mut_ptr(),
+                inc_x,
+                y.as_mut_ptr(),
+                inc_y,
+                c,
+                s,
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Constructs a Givens rotation that zeroes `b` in `[a, b]`, implemented in Rust with [`f32::hypot`].
+///
+/// This is a portable alternative to [`givens_gen_f32`] (`cblas_srotg`), whose sign choices and handling of
+/// `a = 0`, `b = 0` and overflow vary subtly between BLAS implementations. It follows the LAPACK `slartg`
+/// convention: `c >= 0` and `r` carries the sign of `a`, so that
+///
+/// ```text
+/// [ c  s ] [ a ]   [ r ]
+/// [-s  c ] [ b ] = [ 0 ]
+/// ```
+///
+/// The edge cases are `b = 0` → `(c, s, r) = (1, 0, a)` and `a = 0` → `(c, s, r) = (0, sign(b), |b|)`.
+/// `hypot` avoids the intermediate overflow and underflow of `sqrt(a * a + b * b)`.
+///
+/// # Precision
+/// This function operates on `f32` numbers (single-precision).
+///
+/// # Returns
+/// - `(r, z, c, s)`, in the order `cblas_srotg` overwrites its `a`, `b`, `c` and `s` arguments. `z` is the
+///   BLAS reconstruction value: `s` if `|a| > |b|`, otherwise `1 / c` (or `1` when `c = 0`).
+pub fn srotg_stable(a: f32, b: f32) -> (f32, f32, f32, f32) {
+    let (r, c, s) = if b == 0.0 {
+        (a, 1.0, 0.0)
+    } else if a == 0.0 {
+        (b.abs(), 0.0, b.signum())
+    } else {
+        let r = a.hypot(b).copysign(a);
+        (r, a / r, b / r)
+    };
+    let z = if a.abs() > b.abs() {
+        s
+    } else if c != 0.0 {
+        1.0 / c
+    } else {
+        1.0
+    };
+    (r, z, c, s)
+}
+
+/// Constructs a Givens rotation that zeroes `b` in `[a, b]` by calling [`givens_gen_f32`] (`cblas_srotg`) on
+/// locals, returning its outputs by value.
+///
+/// # Precision
+/// This function operates on `f32` numbers (single-precision).
+///
+/// # Returns
+/// - `(r, z, c, s)`, the values `cblas_srotg` writes over its `a`, `b`, `c` and `s` arguments: `r` is the
+///   rotated first component, `z` the BLAS reconstruction value, and `c`, `s` the cosine and sine.
+///
+/// # Discussion
+/// The result follows whatever sign convention Accelerate's `srotg` uses; see [`srotg_stable`] for a
+/// portable Rust implementation with the LAPACK convention.
+pub fn srotg(a: f32, b: f32) -> (f32, f32, f32, f32) {
+    let (mut r, mut z, mut c, mut s) = (a, b, 0.0, 0.0);
+    unsafe {
+        traced!(
+            "cblas_srotg",
+            [],
+            givens_gen_f32(&mut r, &mut z, &mut c, &mut s)
+        )
+    };
+    (r, z, c, s)
+}
+
+/// Constructs a Givens rotation that zeroes `b` in `[a, b]` by calling [`givens_gen_f64`] (`cblas_drotg`) on
+/// locals, returning its outputs by value.
+///
+/// # Precision
+/// This function operates on `f64` numbers (double-precision).
+///
+/// # Returns
+/// - `(r, z, c, s)`, the values `cblas_drotg` writes over its `a`, `b`, `c` and `s` arguments: `r` is the
+///   rotated first component, `z` the BLAS reconstruction value, and `c`, `s` the cosine and sine.
+///
+/// # Discussion
+/// The result follows whatever sign convention Accelerate's `drotg` uses; see [`srotg_stable`] for a
+/// portable Rust implementation with the LAPACK convention.
+pub fn drotg(a: f64, b: f64) -> (f64, f64, f64, f64) {
+    let (mut r, mut z, mut c, mut s) = (a, b, 0.0, 0.0);
+    unsafe {
+        traced!(
+            "cblas_drotg",
+            [],
+            givens_gen_f64(&mut r, &mut z, &mut c, &mut s)
+        )
+    };
+    (r, z, c, s)
+}
+
+/// Constructs a complex Givens rotation that zeroes `b` in `[a, b]` by calling [`givens_gen_c32`]
+/// (`cblas_crotg`) on locals, returning its outputs by value.
+///
+/// # Precision
+/// This function operates on `Complex<f32>` numbers (single-precision complex) with a real cosine.
+///
+/// # Returns
+/// - `(r, b, c, s)`, the values `cblas_crotg` writes over its arguments: `r` is the rotated first
+///   component, `b` is the second argument as the routine leaves it, `c` is the real cosine and `s` the
+///   complex sine, so that `c * a + s * b = r` and `-conj(s) * a + c * b = 0`.
+pub fn crotg(a: Complex<f32>, b: Complex<f32>) -> (Complex<f32>, Complex<f32>, f32, Complex<f32>) {
+    let (mut r, mut b, mut c, mut s) = (a, b, 0.0, Complex::new(0.0, 0.0));
+    unsafe {
+        traced!(
+            "cblas_crotg",
+            [],
+            givens_gen_c32(&mut r, &mut b, &mut c, &mut s)
+        )
+    };
+    (r, b, c, s)
+}
+
+/// Constructs a complex Givens rotation that zeroes `b` in `[a, b]` by calling [`givens_gen_c64`]
+/// (`cblas_zrotg`) on locals, returning its outputs by value.
+///
+/// # Precision
+/// This function operates on `Complex<f64>` numbers (double-precision complex) with a real cosine.
+///
+/// # Returns
+/// - `(r, b, c, s)`, the values `cblas_zrotg` writes over its arguments: `r` is the rotated first
+///   component, `b` is the second argument as the routine leaves it, `c` is the real cosine and `s` the
+///   complex sine, so that `c * a + s * b = r` and `-conj(s) * a + c * b = 0`.
+pub fn zrotg(a: Complex<f64>, b: Complex<f64>) -> (Complex<f64>, Complex<f64>, f64, Complex<f64>) {
+    let (mut r, mut b, mut c, mut s) = (a, b, 0.0, Complex::new(0.0, 0.0));
+    unsafe {
+        traced!(
+            "cblas_zrotg",
+            [],
+            givens_gen_c64(&mut r, &mut b, &mut c, &mut s)
+        )
+    };
+    (r, b, c, s)
+}
@@ -1,10 +1,59 @@
+//! Rust bindings to the BLAS, LAPACK and vForce routines of Apple's Accelerate framework.
+//!
+//! The raw bindings are split by precision: `matrix_f32`, `matrix_f64`, `matrix_c32` and `matrix_c64` hold
+//! the matrix routines, and `vector_f32`, `vector_f64`, `vector_c32` and `vector_c64` the vector ones.
+//! Each module uses the same descriptive names for its bindings, so `matrix_f32::mat_mul` (`cblas_sgemm`)
+//! and `matrix_c32::mat_mul_add` (`cblas_cgemm`) sit side by side with their siblings in the other
+//! modules. They are not re-exported at the crate root and are meant to be called through their module
+//! path, as in `matrix_f32::mat_mul`, which keeps the precision visible at every call site. The checked
+//! wrappers in those modules are named after their BLAS symbol instead (`sgemm`, `ddot`, ...).
+//!
+//! Code that should work in every precision can use the generic wrappers in [`scalar`] or
+//! [`DenseMatrix`](dense::DenseMatrix), which pick the routine from the element type.
+//!
+//! The argument enums from [`constants`] are re-exported at the crate root, and [`prelude`] gathers them
+//! with the most commonly used wrappers:
+//!
+//! ```
+//! use accelerate_general::prelude::*;
+//!
+//! assert_eq!(CblasTranspose::try_from('t'), Ok(CblasTranspose::Trans));
+//! assert_eq!(CblasOrder::RowMajor, RowColMajor::RowMajor);
+//! ```
+
+#[macro_use]
+mod trace;
+
 pub mod constants;
+pub mod dense;
+pub mod error;
 pub mod givens;
 pub mod matrix_c32;
 pub mod matrix_c64;
 pub mod matrix_f32;
 pub mod matrix_f64;
+#[cfg(feature = "ndarray")]
+pub mod ndarray_interop;
+pub mod precision;
+pub mod prelude;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod scalar;
+pub mod small_gemm;
+pub mod square;
+pub mod timing;
+pub mod trsm;
+mod validate;
 pub mod vector_c32;
 pub mod vector_c64;
 pub mod vector_f32;
 pub mod vector_f64;
+pub mod vforce;
+pub mod view;
+pub mod workspace;
+
+pub use constants::{
+    CblasDiag, CblasOrder, CblasSide, CblasTranspose, CblasUpLow, EigenJob, EigenRange, IsDiagUnit,
+    MultiplyOrder, RowColMajor, SvdJob, TransposeMode, UpOrLowTriangle,
+};
+pub use error::BlasError;
@@ -1,6 +1,14 @@
-use crate::constants::{IsDiagUnit, MultiplyOrder, RowColMajor, TransposeMode, UpOrLowTriangle};
+use crate::constants::{
+    IsDiagUnit, MultiplyOrder, RowColMajor, SvdJob, TransposeMode, UpOrLowTriangle,
+};
+use crate::error::BlasError;
+use crate::givens;
+use crate::validate::{
+    check_inc, check_indices, check_ld, check_len, check_shape, strided_len, to_c_int, vec_len,
+};
+use crate::vector_c32;
 use num_complex::Complex;
-use std::ffi::{c_float, c_int};
+use std::ffi::{c_char, c_float, c_int};
 
 #[link(name = "Accelerate", kind = "framework")]
 extern "C" {
@@ -996,4 +1004,954 @@ extern "C" {
         inc_x: c_int,               // Stride within vector X
     ) -> c_float;
 
+    /// Computes the singular value decomposition `A = U * S * V^H` of a general matrix (LAPACK `cgesvd`).
+    ///
+    /// # Precision
+    /// This function operates on single-precision complex (`Complex<f32>`) numbers.
+    ///
+    /// # Parameters
+    /// - `jobu`, `jobvt`: `b'A'`, `b'S'`, `b'O'` or `b'N'`, selecting all, the leading `min(m, n)`, an
+    ///   overwrite of `a`, or none of the columns of `U` (rows of `V^H`). Use `SvdJob::to_lapack`.
+    /// - `m`, `n`: The number of rows and columns of `A`.
+    /// - `a`: The matrix `A`, column-major `m x n`. Destroyed on return (or overwritten with `U` or `V^H`
+    ///   for `'O'`).
+    /// - `lda`: The leading dimension of `a`, at least `m`.
+    /// - `s`: The `min(m, n)` singular values, returned in descending order.
+    /// - `u`, `ldu`: The left singular vectors, not referenced when `jobu` is `'N'` or `'O'`.
+    /// - `vt`, `ldvt`: The right singular vectors, not referenced when `jobvt` is `'N'` or `'O'`.
+    /// - `work`, `lwork`: Workspace of `lwork` elements. With `lwork = -1` only the optimal size is
+    ///   written to `work[0]`.
+    /// - `rwork`: Real workspace of `5 * min(m, n)` elements.
+    /// - `info`: Set to `0` on success, `-i` if argument `i` was invalid, and `i` if `i` superdiagonals of
+    ///   the intermediate bidiagonal form did not converge.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function using the Fortran calling convention: every argument is passed by
+    /// pointer. The caller must ensure that every buffer is valid for the sizes the job flags imply.
+    #[link_name = "cgesvd_"]
+    pub fn singular_value_decomposition(
+        jobu: *const c_char,
+        jobvt: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        a: *mut Complex<c_float>,
+        lda: *const c_int,
+        s: *mut c_float,
+        u: *mut Complex<c_float>,
+        ldu: *const c_int,
+        vt: *mut Complex<c_float>,
+        ldvt: *const c_int,
+        work: *mut Complex<c_float>,
+        lwork: *const c_int,
+        rwork: *mut c_float,
+        info: *mut c_int,
+    );
+}
+
+/// Solves a triangular banded system `op(A) * x = b` in place, after validating the band layout.
+///
+/// This is the checked counterpart of [`tri_band_solve`] (`cblas_ctbsv`).
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `tri`: Specifies whether `A` is upper or lower triangular. Use `UpOrLowTriangle`.
+/// - `trans_a`: Specifies whether to use matrix `A` ('N') or the conjugate transpose of `A` ('C'). Use `TransposeMode`.
+/// - `diag`: Specifies whether `A` is unit triangular ('U') or not ('N'). Use `IsDiagUnit`.
+/// - `n`: The order of the matrix `A`.
+/// - `k`: The number of super-diagonals (upper) or sub-diagonals (lower) of `A`.
+/// - `a`: The band storage of `A`: `n` columns (column-major) or rows (row-major) of `lda` elements each.
+/// - `lda`: The leading dimension of the band storage, must be at least `k + 1`.
+/// - `x`: On entry, the right-hand side `b`. On return, overwritten with the solution `x`.
+/// - `inc_x`: The stride within `x`. For example, if `inc_x` is 7, every 7th element is used.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < k + 1`, and [`BlasError::BufferTooSmall`]
+/// if `a` or `x` is shorter than `n`, `lda` and `inc_x` require.
+/// Returns [`BlasError::ZeroIncrement`] if `inc_x` is zero.
+#[allow(clippy::too_many_arguments)]
+pub fn ctbsv(
+    major: RowColMajor,
+    tri: UpOrLowTriangle,
+    trans_a: TransposeMode,
+    diag: IsDiagUnit,
+    n: usize,
+    k: usize,
+    a: &[Complex<f32>],
+    lda: usize,
+    x: &mut [Complex<f32>],
+    inc_x: i32,
+) -> Result<(), BlasError> {
+    check_ld("lda", k + 1, lda)?;
+    check_len("a", strided_len(n, k + 1, lda), a.len())?;
+    check_inc("inc_x", inc_x)?;
+    check_len("x", vec_len(n, inc_x), x.len())?;
+    unsafe {
+        traced!(
+            "cblas_ctbsv",
+            [n, k],
+            tri_band_solve(
+                major,
+                tri,
+                trans_a,
+                diag,
+                to_c_int(n, "n")?,
+                to_c_int(k, "k")?,
+                a.as_ptr(),
+                to_c_int(lda, "lda")?,
+                x.as_mut_ptr(),
+                inc_x,
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Solves the tridiagonal system `A * x = b` in place.
+///
+/// `A` is factored as `L * U` without pivoting (the Thomas algorithm), where `L` is unit lower
+/// bidiagonal and `U` is upper bidiagonal. Both factors are assembled in band storage
+/// (`k = 1`, column-major) and applied with two calls to [`ctbsv`].
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers.
+///
+/// # Parameters
+/// - `lower`: The `n - 1` sub-diagonal elements of `A`.
+/// - `diag`: The `n` diagonal elements of `A`.
+/// - `upper`: The `n - 1` super-diagonal elements of `A`.
+/// - `b`: On entry, the right-hand side `b`. On return, overwritten with the solution `x`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `lower`, `upper` or `b` does not match the length of `diag`.
+///
+/// # Discussion
+/// Since no pivoting is performed, `A` should be diagonally dominant or positive definite, as is the case
+/// for most 1-D PDE discretizations and cubic spline systems. A zero pivot produces non-finite results.
+pub fn solve_tridiagonal(
+    lower: &[Complex<f32>],
+    diag: &[Complex<f32>],
+    upper: &[Complex<f32>],
+    b: &mut [Complex<f32>],
+) -> Result<(), BlasError> {
+    let n = diag.len();
+    check_shape("lower", n.saturating_sub(1), lower.len())?;
+    check_shape("upper", n.saturating_sub(1), upper.len())?;
+    check_shape("b", n, b.len())?;
+    if n == 0 {
+        return Ok(());
+    }
+
+    // Column j of each band holds (A[j - 1][j], A[j][j]) for `U` and (A[j][j], A[j + 1][j]) for `L`.
+    let mut l_band = vec![Complex::new(0.0, 0.0); 2 * n];
+    let mut u_band = vec![Complex::new(0.0, 0.0); 2 * n];
+    u_band[1] = diag[0];
+    for j in 0..n {
+        l_band[2 * j] = Complex::new(1.0, 0.0);
+        if j > 0 {
+            let l = lower[j - 1] / u_band[2 * (j - 1) + 1];
+            l_band[2 * (j - 1) + 1] = l;
+            u_band[2 * j] = upper[j - 1];
+            u_band[2 * j + 1] = diag[j] - l * upper[j - 1];
+        }
+    }
+
+    ctbsv(
+        RowColMajor::ColMajor,
+        UpOrLowTriangle::Lower,
+        TransposeMode::NoTrans,
+        IsDiagUnit::Unit,
+        n,
+        1,
+        &l_band,
+        2,
+        b,
+        1,
+    )?;
+    ctbsv(
+        RowColMajor::ColMajor,
+        UpOrLowTriangle::Upper,
+        TransposeMode::NoTrans,
+        IsDiagUnit::NonUnit,
+        n,
+        1,
+        &u_band,
+        2,
+        b,
+        1,
+    )
+}
+
+/// Fills the opposite triangle of a symmetric matrix from the populated one, so `a[j][i] = a[i][j]`.
+///
+/// Routines such as `cblas_csyrk` only write one triangle; this materializes the full matrix for code that expects a dense one. The diagonal is left untouched.
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `tri`: The triangle of `A` that is populated and is copied from. Use `UpOrLowTriangle`.
+/// - `n`: The order of the matrix `A`.
+/// - `a`: The `n x n` matrix `A`, completed in place.
+/// - `lda`: The leading dimension of `A`, must be at least `n`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n` and [`BlasError::BufferTooSmall`] if `a` is too short.
+pub fn symmetrize(
+    major: RowColMajor,
+    tri: UpOrLowTriangle,
+    n: usize,
+    a: &mut [Complex<f32>],
+    lda: usize,
+) -> Result<(), BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    for i in 0..n {
+        for j in (i + 1)..n {
+            // (i, j) lies in the upper triangle and (j, i) in the lower one.
+            let (upper, lower) = match major {
+                RowColMajor::RowMajor => (i * lda + j, j * lda + i),
+                RowColMajor::ColMajor => (j * lda + i, i * lda + j),
+            };
+            match tri {
+                UpOrLowTriangle::Upper => a[lower] = a[upper],
+                UpOrLowTriangle::Lower => a[upper] = a[lower],
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Fills the opposite triangle of a Hermitian matrix from the populated one, so `a[j][i] = conj(a[i][j])`.
+///
+/// Routines such as `cblas_cherk` only write one triangle; this materializes the full matrix for code that expects a dense one. The imaginary part of the diagonal is set to zero, since a Hermitian matrix has a real diagonal (the `her*` routines make the same guarantee).
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `tri`: The triangle of `A` that is populated and is copied from. Use `UpOrLowTriangle`.
+/// - `n`: The order of the matrix `A`.
+/// - `a`: The `n x n` matrix `A`, completed in place.
+/// - `lda`: The leading dimension of `A`, must be at least `n`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n` and [`BlasError::BufferTooSmall`] if `a` is too short.
+pub fn hermitianize(
+    major: RowColMajor,
+    tri: UpOrLowTriangle,
+    n: usize,
+    a: &mut [Complex<f32>],
+    lda: usize,
+) -> Result<(), BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    for i in 0..n {
+        a[i * (lda + 1)].im = 0.0;
+        for j in (i + 1)..n {
+            // (i, j) lies in the upper triangle and (j, i) in the lower one.
+            let (upper, lower) = match major {
+                RowColMajor::RowMajor => (i * lda + j, j * lda + i),
+                RowColMajor::ColMajor => (j * lda + i, i * lda + j),
+            };
+            match tri {
+                UpOrLowTriangle::Upper => a[lower] = a[upper].conj(),
+                UpOrLowTriangle::Lower => a[upper] = a[lower].conj(),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns whether the `n x n` matrix `A` is symmetric to within `tol`, i.e. `|a[i][j] - a[j][i]| <= tol` for
+/// every `i != j`.
+///
+/// Symmetric routines such as [`csymv`] read only one triangle, so a matrix that is not actually symmetric
+/// gives a wrong result without any error. This predicate lets that assumption be checked, for instance in a
+/// `debug_assert!`, before the call.
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers.
+///
+/// # Parameters
+/// - `a`: The `n x n` matrix `A`.
+/// - `n`: The order of the matrix `A`.
+/// - `lda`: The leading dimension of `A`, must be at least `n`.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `tol`: The largest absolute difference accepted between mirrored entries. `0` demands exact symmetry.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n` and [`BlasError::BufferTooSmall`] if `a` is too short.
+pub fn is_symmetric(
+    a: &[Complex<f32>],
+    n: usize,
+    lda: usize,
+    order: RowColMajor,
+    tol: f32,
+) -> Result<bool, BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let (upper, lower) = match order {
+                RowColMajor::RowMajor => (i * lda + j, j * lda + i),
+                RowColMajor::ColMajor => (j * lda + i, i * lda + j),
+            };
+            let diff = (a[upper] - a[lower]).norm();
+            if diff.is_nan() || diff > tol {
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// Returns whether the `n x n` matrix `A` is Hermitian to within `tol`, i.e. `|a[i][j] - conj(a[j][i])| <= tol`
+/// for every `i != j` and `|Im(a[i][i])| <= tol` on the diagonal.
+///
+/// Hermitian routines such as `cblas_chemv` and the Cholesky factorization read only one triangle and
+/// assume a real diagonal, so a matrix that is not actually Hermitian gives a wrong result without any error.
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers.
+///
+/// # Parameters
+/// - `a`: The `n x n` matrix `A`.
+/// - `n`: The order of the matrix `A`.
+/// - `lda`: The leading dimension of `A`, must be at least `n`.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `tol`: The largest absolute deviation accepted. `0` demands an exactly Hermitian matrix.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n` and [`BlasError::BufferTooSmall`] if `a` is too short.
+pub fn is_hermitian(
+    a: &[Complex<f32>],
+    n: usize,
+    lda: usize,
+    order: RowColMajor,
+    tol: f32,
+) -> Result<bool, BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    for i in 0..n {
+        let diff = a[i * lda + i].im.abs();
+        if diff.is_nan() || diff > tol {
+            return Ok(false);
+        }
+        for j in (i + 1)..n {
+            let (upper, lower) = match order {
+                RowColMajor::RowMajor => (i * lda + j, j * lda + i),
+                RowColMajor::ColMajor => (j * lda + i, i * lda + j),
+            };
+            let diff = (a[upper] - a[lower].conj()).norm();
+            if diff.is_nan() || diff > tol {
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// Builds a dense, tightly packed `n x n` matrix with `d` on its diagonal and zeros elsewhere.
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers.
+///
+/// # Parameters
+/// - `d`: The `n` diagonal entries.
+///
+/// # Returns
+/// The `n x n` matrix with leading dimension `n`. The diagonal occupies the same positions in row- and
+/// column-major storage, so the result can be passed with either `RowColMajor`.
+pub fn from_diagonal(d: &[Complex<f32>]) -> Vec<Complex<f32>> {
+    let n = d.len();
+    let mut a = vec![Complex::new(0.0, 0.0); n * n];
+    for (i, &v) in d.iter().enumerate() {
+        a[i * (n + 1)] = v;
+    }
+    a
+}
+
+/// Extracts the diagonal of an `n x n` matrix.
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers.
+///
+/// # Parameters
+/// - `n`: The order of the matrix `A`.
+/// - `a`: The matrix `A`, in either `RowColMajor` order; element `(i, i)` sits at `i * (lda + 1)` in both.
+/// - `lda`: The leading dimension of `A`, must be at least `n`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n` and [`BlasError::BufferTooSmall`] if `a` is too short.
+pub fn diagonal(n: usize, a: &[Complex<f32>], lda: usize) -> Result<Vec<Complex<f32>>, BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    Ok((0..n).map(|i| a[i * (lda + 1)]).collect())
+}
+
+/// Computes the Kronecker product `A ⊗ B` of an `ma x na` matrix `A` and an `mb x nb` matrix `B`.
+///
+/// The result is the `(ma * mb) x (na * nb)` block matrix whose `(i, j)` block is `a[i][j] * B`. Each of its
+/// rows is an outer product of a row of `A` with a row of `B`, formed with [`rank1_update_unconj`] (`cblas_cgeru`).
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers.
+///
+/// # Parameters
+/// - `major`: The storage order of `a`, `b` and the returned matrix. Use `RowColMajor`.
+/// - `a`: The tightly packed matrix `A`.
+/// - `(ma, na)`: The number of rows and columns of `A`.
+/// - `b`: The tightly packed matrix `B`.
+/// - `(mb, nb)`: The number of rows and columns of `B`.
+///
+/// # Returns
+/// The tightly packed `(ma * mb) x (na * nb)` product, stored in `major` order.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `a` or `b` does not hold exactly the number of elements its
+/// shape requires.
+pub fn kron(
+    major: RowColMajor,
+    a: &[Complex<f32>],
+    (ma, na): (usize, usize),
+    b: &[Complex<f32>],
+    (mb, nb): (usize, usize),
+) -> Result<Vec<Complex<f32>>, BlasError> {
+    check_shape("a", ma * na, a.len())?;
+    check_shape("b", mb * nb, b.len())?;
+    // Column-major storage of a matrix is the row-major storage of its transpose, and
+    // (A ⊗ B)^T = A^T ⊗ B^T, so both orders reduce to the row-major case.
+    let ((ma, na), (mb, nb)) = match major {
+        RowColMajor::RowMajor => ((ma, na), (mb, nb)),
+        RowColMajor::ColMajor => ((na, ma), (nb, mb)),
+    };
+    let cols = na * nb;
+    let mut out = vec![Complex::new(0.0, 0.0); ma * mb * cols];
+    if out.is_empty() {
+        return Ok(out);
+    }
+    let na_c = to_c_int(na, "na")?;
+    let nb_c = to_c_int(nb, "nb")?;
+    // The alpha of every outer product below, named once rather than rebuilt inside the loop.
+    let one = Complex::new(1.0, 0.0);
+    for i in 0..ma {
+        for p in 0..mb {
+            // Row (i, p) of the result is the na x nb outer product of row i of A and row p of B.
+            let row = &mut out[(i * mb + p) * cols..][..cols];
+            unsafe {
+                traced!(
+                    "cblas_cgeru",
+                    [na, nb],
+                    rank1_update_unconj(
+                        RowColMajor::RowMajor,
+                        na_c,
+                        nb_c,
+                        &one,
+                        a[i * na..].as_ptr(),
+                        1,
+                        b[p * nb..].as_ptr(),
+                        1,
+                        row.as_mut_ptr(),
+                        nb_c,
+                    )
+                );
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Applies a sequence of plane rotations to pairs of rows of a matrix, in order.
+///
+/// Each `(i, j, c, s)` replaces rows `i` and `j` of `M` by `c * row_i + s * row_j` and `c * row_j - s * row_i`,
+/// using [`givens::givens_rot_c32`] (`cblas_csrot`) on the two row views. In row-major order a row is contiguous; in
+/// column-major order its elements are `rows` apart, so the row views use that stride instead. This is the
+/// update QR-by-Givens and bidiagonalization codes apply when they zero one subdiagonal entry at a time.
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers with real `f32` rotation coefficients.
+///
+/// # Parameters
+/// - `rotations`: The `(i, j, c, s)` rotations, applied first to last. `i` and `j` must differ.
+/// - `m`: The tightly packed `rows x cols` matrix `M`, updated in place.
+/// - `rows`: The number of rows of `M`.
+/// - `cols`: The number of columns of `M`.
+/// - `major`: The storage order of `m`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `m` does not hold exactly `rows * cols` elements and
+/// [`BlasError::IndexOutOfBounds`] if a rotation names a row `>= rows`, and [`BlasError::RepeatedIndex`] if
+/// a rotation pairs a row with itself. All rotations are validated before any is applied, so `m` is left
+/// untouched on error.
+pub fn apply_givens_sequence(
+    rotations: &[(usize, usize, f32, f32)],
+    m: &mut [Complex<f32>],
+    rows: usize,
+    cols: usize,
+    major: RowColMajor,
+) -> Result<(), BlasError> {
+    check_shape("m", rows * cols, m.len())?;
+    for &(i, j, _, _) in rotations {
+        check_indices("rotations", &[i, j], rows)?;
+        if i == j {
+            return Err(BlasError::RepeatedIndex {
+                which: "rotations",
+                index: i,
+            });
+        }
+    }
+    let (row_stride, inc) = match major {
+        RowColMajor::RowMajor => (cols, 1),
+        RowColMajor::ColMajor => (1, rows),
+    };
+    let n = to_c_int(cols, "cols")?;
+    let inc = to_c_int(inc, "rows")?;
+    let ptr = m.as_mut_ptr();
+    for &(i, j, c, s) in rotations {
+        unsafe {
+            traced!(
+                "cblas_csrot",
+                [cols],
+                givens::givens_rot_c32(
+                    n,
+                    ptr.add(i * row_stride),
+                    inc,
+                    ptr.add(j * row_stride),
+                    inc,
+                    c,
+                    s,
+                )
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Computes `y = alpha * A * x + beta * y` for a complex **symmetric** (not Hermitian) matrix `A`.
+///
+/// CBLAS offers no `csymv` for complex data, only the Hermitian [`herm_mat_vec_mul_add`] (`cblas_chemv`)
+/// product, which conjugates the mirrored triangle. Complex symmetric matrices (`A = A^T`, as in some EM and
+/// scattering solvers) need the mirrored entries used as stored, so this fills the gap with a Rust kernel that
+/// reads only the `tri` triangle of `A` and mirrors it without conjugation.
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `tri`: The triangle of `A` that is referenced. Use `UpOrLowTriangle`.
+/// - `n`: The order of the matrix `A` and the length of `x` and `y`.
+/// - `alpha`: Scalar multiplier for `A * x`.
+/// - `a`: The `n x n` matrix `A`; only the `tri` triangle is read.
+/// - `lda`: The leading dimension of `A`, must be at least `n`.
+/// - `x`: The vector `x`.
+/// - `beta`: Scalar multiplier for `y`. When it is zero `y` is not read, so it may hold NaNs on entry.
+/// - `y`: The vector `y`, overwritten with the result.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n`, [`BlasError::BufferTooSmall`] if `a` is too
+/// short, and [`BlasError::DimensionMismatch`] if `x` or `y` does not hold `n` elements.
+#[allow(clippy::too_many_arguments)]
+pub fn csymv(
+    major: RowColMajor,
+    tri: UpOrLowTriangle,
+    n: usize,
+    alpha: Complex<f32>,
+    a: &[Complex<f32>],
+    lda: usize,
+    x: &[Complex<f32>],
+    beta: Complex<f32>,
+    y: &mut [Complex<f32>],
+) -> Result<(), BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    check_shape("x", n, x.len())?;
+    check_shape("y", n, y.len())?;
+    let zero = Complex::new(0.0, 0.0);
+    for (i, yi) in y.iter_mut().enumerate() {
+        let mut sum = zero;
+        for (j, &xj) in x.iter().enumerate() {
+            // Map (i, j) into the referenced triangle; symmetry makes (j, i) the same element.
+            let (r, c) = match tri {
+                UpOrLowTriangle::Upper => (i.min(j), i.max(j)),
+                UpOrLowTriangle::Lower => (i.max(j), i.min(j)),
+            };
+            let aij = match major {
+                RowColMajor::RowMajor => a[r * lda + c],
+                RowColMajor::ColMajor => a[c * lda + r],
+            };
+            sum += aij * xj;
+        }
+        *yi = if beta == zero {
+            alpha * sum
+        } else {
+            alpha * sum + beta * *yi
+        };
+    }
+    Ok(())
+}
+
+/// Band widths up to `n / BAND_FRACTION` are multiplied with the banded kernel by [`gemv_banded_auto`];
+/// wider bands fall back to the dense one, since the band kernel would do nearly as much work.
+const BAND_FRACTION: usize = 4;
+
+/// Computes `y = A * x` for a densely stored `m x n` matrix, using the banded kernel when `A` turns out to
+/// be banded.
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers.
+///
+/// # Parameters
+/// - `a`: The matrix `A`, stored densely.
+/// - `m`, `n`: The number of rows and columns of `A`.
+/// - `lda`: The leading dimension of `a`.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `x`: The input vector, of length `n`.
+///
+/// # Returns
+/// The product `A * x`, of length `m`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is below its minimum,
+/// [`BlasError::BufferTooSmall`] if `a` is too short, and [`BlasError::DimensionMismatch`] if `x` is not of
+/// length `n`.
+///
+/// # Discussion
+/// The matrix is scanned once for its lower and upper bandwidths `kl` and `ku`, the distance of the
+/// furthest nonzero below and above the diagonal. If the band `kl + ku + 1` spans at most a quarter of the
+/// columns, it is packed into BLAS band storage and multiplied with `cblas_cgbmv`, which touches only the
+/// band. Otherwise `a` is passed straight to `cblas_cgemv`.
+///
+/// This is a convenience for matrices whose structure is not known in advance, not an optimization. Nothing
+/// is cached between calls, and the scan and the packing read all `m * n` elements, which is already as much
+/// memory traffic as one dense `cblas_cgemv`. A call is never faster than calling `cblas_cgemv` directly.
+pub fn gemv_banded_auto(
+    a: &[Complex<f32>],
+    m: usize,
+    n: usize,
+    lda: usize,
+    order: RowColMajor,
+    x: &[Complex<f32>],
+) -> Result<Vec<Complex<f32>>, BlasError> {
+    let (outer, inner) = match order {
+        RowColMajor::RowMajor => (m, n),
+        RowColMajor::ColMajor => (n, m),
+    };
+    check_ld("lda", inner.max(1), lda)?;
+    check_len("a", strided_len(outer, inner, lda), a.len())?;
+    check_shape("x", n, x.len())?;
+    let mut y = vec![Complex::new(0.0, 0.0); m];
+    if m == 0 || n == 0 {
+        return Ok(y);
+    }
+    let at = |i: usize, j: usize| match order {
+        RowColMajor::RowMajor => a[i * lda + j],
+        RowColMajor::ColMajor => a[j * lda + i],
+    };
+    let (mut kl, mut ku) = (0, 0);
+    for i in 0..m {
+        for j in 0..n {
+            if at(i, j) != Complex::new(0.0, 0.0) {
+                if i > j {
+                    kl = kl.max(i - j);
+                } else {
+                    ku = ku.max(j - i);
+                }
+            }
+        }
+    }
+    let band = kl + ku + 1;
+    let one = Complex::new(1.0, 0.0);
+    let zero = Complex::new(0.0, 0.0);
+    if band * BAND_FRACTION <= n {
+        // Row-major band storage keeps row i at offset kl - i, column-major keeps column j at ku - j.
+        let mut ab = vec![Complex::new(0.0, 0.0); band * outer];
+        for i in 0..m {
+            for j in i.saturating_sub(kl)..n.min(i + ku + 1) {
+                let idx = match order {
+                    RowColMajor::RowMajor => i * band + kl + j - i,
+                    RowColMajor::ColMajor => j * band + ku + i - j,
+                };
+                ab[idx] = at(i, j);
+            }
+        }
+        unsafe {
+            traced!(
+                "cblas_cgbmv",
+                [m, n, kl, ku],
+                band_mat_vec_mul(
+                    order,
+                    TransposeMode::NoTrans,
+                    to_c_int(m, "m")?,
+                    to_c_int(n, "n")?,
+                    to_c_int(kl, "kl")?,
+                    to_c_int(ku, "ku")?,
+                    &one,
+                    ab.as_ptr(),
+                    to_c_int(band, "band")?,
+                    x.as_ptr(),
+                    1,
+                    &zero,
+                    y.as_mut_ptr(),
+                    1,
+                )
+            );
+        }
+    } else {
+        unsafe {
+            traced!(
+                "cblas_cgemv",
+                [m, n],
+                mat_vec_mul(
+                    order,
+                    TransposeMode::NoTrans,
+                    to_c_int(m, "m")?,
+                    to_c_int(n, "n")?,
+                    &one,
+                    a.as_ptr(),
+                    to_c_int(lda, "lda")?,
+                    x.as_ptr(),
+                    1,
+                    &zero,
+                    y.as_mut_ptr(),
+                    1,
+                )
+            );
+        }
+    }
+    Ok(y)
+}
+
+/// Swaps rows `i` and `j` of a `rows x cols` matrix in place with `cblas_cswap`.
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers.
+///
+/// # Parameters
+/// - `a`: The matrix.
+/// - `rows`, `cols`: The shape of the matrix.
+/// - `lda`: The leading dimension of `a`.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `i`, `j`: The rows to exchange. Swapping a row with itself does nothing.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is below its minimum,
+/// [`BlasError::BufferTooSmall`] if `a` is too short, and [`BlasError::IndexOutOfBounds`] if `i` or `j` is
+/// not less than `rows`.
+///
+/// # Discussion
+/// In row-major storage a row is contiguous; in column-major storage its elements are `lda` apart. The
+/// strided swap handles both without copying, which makes this the building block for row pivoting.
+pub fn swap_rows(
+    a: &mut [Complex<f32>],
+    rows: usize,
+    cols: usize,
+    lda: usize,
+    order: RowColMajor,
+    i: usize,
+    j: usize,
+) -> Result<(), BlasError> {
+    let (outer, inner) = match order {
+        RowColMajor::RowMajor => (rows, cols),
+        RowColMajor::ColMajor => (cols, rows),
+    };
+    check_ld("lda", inner.max(1), lda)?;
+    check_len("a", strided_len(outer, inner, lda), a.len())?;
+    check_indices("i", &[i], rows)?;
+    check_indices("j", &[j], rows)?;
+    let (start_i, start_j, inc) = match order {
+        RowColMajor::RowMajor => (i * lda, j * lda, 1),
+        RowColMajor::ColMajor => (i, j, lda),
+    };
+    swap_strided(a, cols, start_i, start_j, inc)
+}
+
+/// Swaps columns `i` and `j` of a `rows x cols` matrix in place with `cblas_cswap`.
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers.
+///
+/// # Parameters
+/// - `a`: The matrix.
+/// - `rows`, `cols`: The shape of the matrix.
+/// - `lda`: The leading dimension of `a`.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `i`, `j`: The columns to exchange. Swapping a column with itself does nothing.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is below its minimum,
+/// [`BlasError::BufferTooSmall`] if `a` is too short, and [`BlasError::IndexOutOfBounds`] if `i` or `j` is
+/// not less than `cols`.
+pub fn swap_columns(
+    a: &mut [Complex<f32>],
+    rows: usize,
+    cols: usize,
+    lda: usize,
+    order: RowColMajor,
+    i: usize,
+    j: usize,
+) -> Result<(), BlasError> {
+    let (outer, inner) = match order {
+        RowColMajor::RowMajor => (rows, cols),
+        RowColMajor::ColMajor => (cols, rows),
+    };
+    check_ld("lda", inner.max(1), lda)?;
+    check_len("a", strided_len(outer, inner, lda), a.len())?;
+    check_indices("i", &[i], cols)?;
+    check_indices("j", &[j], cols)?;
+    let (start_i, start_j, inc) = match order {
+        RowColMajor::RowMajor => (i, j, lda),
+        RowColMajor::ColMajor => (i * lda, j * lda, 1),
+    };
+    swap_strided(a, rows, start_i, start_j, inc)
+}
+
+/// Swaps the `len`-element lines of `a` starting at `start_i` and `start_j`, both with stride `inc`.
+/// Shared by [`swap_rows`] and [`swap_columns`], which have already checked that both lines lie in `a`.
+fn swap_strided(
+    a: &mut [Complex<f32>],
+    len: usize,
+    start_i: usize,
+    start_j: usize,
+    inc: usize,
+) -> Result<(), BlasError> {
+    if start_i == start_j || len == 0 {
+        return Ok(());
+    }
+    let ptr = a.as_mut_ptr();
+    let inc = to_c_int(inc, "lda")?;
+    unsafe {
+        traced!(
+            "cblas_cswap",
+            [len],
+            vector_c32::swap(
+                to_c_int(len, "len")?,
+                ptr.add(start_i),
+                inc,
+                ptr.add(start_j),
+                inc
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Embeds a real matrix (or vector) in the complex numbers, element by element, with zero imaginary parts.
+///
+/// The layout is kept as is, so the result has the same shape, leading dimension and storage order as `a`
+/// and can be handed to the complex routines in its place.
+///
+/// # Precision
+/// This function produces single-precision complex (`Complex<f32>`) numbers from `f32`.
+pub fn real_to_complex(a: &[f32]) -> Vec<Complex<f32>> {
+    a.iter().map(|&re| Complex::new(re, 0.0)).collect()
+}
+
+/// Extracts the real part of every element, keeping the layout of `a`.
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers.
+pub fn complex_real_part(a: &[Complex<f32>]) -> Vec<f32> {
+    a.iter().map(|z| z.re).collect()
+}
+
+/// Extracts the imaginary part of every element, keeping the layout of `a`.
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers.
+pub fn complex_imag_part(a: &[Complex<f32>]) -> Vec<f32> {
+    a.iter().map(|z| z.im).collect()
+}
+
+/// The `min(m, n)` singular values of the row-major `m x n` matrix `a`, in descending order, computed with
+/// `cgesvd` on a copy of `a`.
+fn singular_values(a: &[Complex<f32>], m: usize, n: usize) -> Result<Vec<f32>, BlasError> {
+    check_shape("a", m * n, a.len())?;
+    let k = m.min(n);
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+    let mut a = a.to_vec();
+    let mut s = vec![0.0; k];
+    // The row-major `m x n` buffer is the column-major `n x m` transpose, which is what LAPACK reads. A
+    // matrix and its transpose share their singular values.
+    let job = SvdJob::None.to_lapack();
+    let (m_c, n_c, one) = (to_c_int(n, "n")?, to_c_int(m, "m")?, 1);
+    let mut rwork = vec![0.0; 5 * k];
+    let mut gesvd = |work: *mut Complex<f32>, lwork: c_int| {
+        let mut info: c_int = 0;
+        unsafe {
+            traced!(
+                "cgesvd_",
+                [n, m],
+                singular_value_decomposition(
+                    &job,
+                    &job,
+                    &m_c,
+                    &n_c,
+                    a.as_mut_ptr(),
+                    &m_c,
+                    s.as_mut_ptr(),
+                    std::ptr::null_mut(),
+                    &one,
+                    std::ptr::null_mut(),
+                    &one,
+                    work,
+                    &lwork,
+                    rwork.as_mut_ptr(),
+                    &mut info,
+                )
+            );
+        }
+        info
+    };
+    let mut query = Complex::new(0.0, 0.0);
+    gesvd(&mut query, -1);
+    let mut work = vec![Complex::new(0.0, 0.0); (query.re as usize).max(1)];
+    let info = gesvd(work.as_mut_ptr(), to_c_int(work.len(), "lwork")?);
+    // Every argument was validated above, so only a convergence failure (info > 0) can be reported.
+    if info > 0 {
+        return Err(BlasError::NoConvergence {
+            unconverged: info as usize,
+        });
+    }
+    Ok(s)
+}
+
+/// Computes the numerical rank of an `m x n` matrix: the number of singular values above a tolerance.
+///
+/// # Precision
+/// This function operates on single-precision complex (`Complex<f32>`) numbers.
+///
+/// # Parameters
+/// - `a`: The matrix, row-major and tightly packed. A column-major buffer can be passed with `m` and `n`
+///   swapped, since it then holds the transpose, which has the same singular values.
+/// - `m`, `n`: The shape of the matrix.
+/// - `tol`: The threshold a singular value must exceed to count. `None` selects
+///   `max(m, n) * f32::EPSILON * sigma_max`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `a` does not hold `m * n` elements, and
+/// [`BlasError::NoConvergence`] if [`singular_value_decomposition`] (`cgesvd`) fails to converge.
+///
+/// # Discussion
+/// See [`matrix_f64::matrix_rank`](crate::matrix_f64::matrix_rank) for how the default tolerance is chosen.
+pub fn matrix_rank(
+    a: &[Complex<f32>],
+    m: usize,
+    n: usize,
+    tol: Option<f32>,
+) -> Result<usize, BlasError> {
+    let s = singular_values(a, m, n)?;
+    let tol = match (tol, s.first()) {
+        (Some(tol), _) => tol,
+        (None, Some(&max)) => m.max(n) as f32 * f32::EPSILON * max,
+        (None, None) => return Ok(0),
+    };
+    Ok(s.iter().filter(|&&sigma| sigma > tol).count())
 }
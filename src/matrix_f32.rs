@@ -1,5 +1,16 @@
-use crate::constants::{IsDiagUnit, MultiplyOrder, RowColMajor, TransposeMode, UpOrLowTriangle};
-use std::ffi::{c_float, c_int};
+use crate::constants::{
+    gemm_shapes, EigenJob, EigenRange, IsDiagUnit, MultiplyOrder, RowColMajor, SvdJob,
+    TransposeMode, UpOrLowTriangle,
+};
+use crate::error::BlasError;
+use crate::givens;
+use crate::timing::timed;
+use crate::validate::{
+    check_inc, check_indices, check_ld, check_len, check_shape, strided_len, to_c_int, vec_len,
+};
+use crate::vector_f32;
+use std::ffi::{c_char, c_float, c_int};
+use std::time::Duration;
 
 #[link(name = "Accelerate", kind = "framework")]
 extern "C" {
@@ -768,4 +779,2212 @@ extern "C" {
         x: *mut c_float,        // Pointer to vector X
         inc_x: c_int,           // Increment for vector X
     );
+
+    /// Computes the Cholesky factorization of a symmetric positive-definite band matrix (LAPACK `spbtrf`).
+    ///
+    /// # Precision
+    /// This function operates on single-precision (`f32`) numbers.
+    ///
+    /// # Parameters
+    /// - `uplo`: `b'U'` if `ab` holds the upper triangle of the band, `b'L'` for the lower.
+    /// - `n`: The order of the matrix `A`.
+    /// - `kd`: The number of super-diagonals (`'U'`) or sub-diagonals (`'L'`) of `A`.
+    /// - `ab`: The band of `A` in LAPACK band storage, column-major: `A(i, j)` is at `ab[kd + i - j + j * ldab]`
+    ///   for `'U'` and at `ab[i - j + j * ldab]` for `'L'`. Overwritten with the Cholesky factor.
+    /// - `ldab`: The leading dimension of `ab`, at least `kd + 1`.
+    /// - `info`: Set to `0` on success, `-i` if argument `i` was invalid, and `i` if the leading minor of
+    ///   order `i` is not positive definite.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function using the Fortran calling convention: every argument is passed by
+    /// pointer. The caller must ensure that `ab` is valid for `ldab * n` elements.
+    #[link_name = "spbtrf_"]
+    pub fn band_cholesky(
+        uplo: *const c_char,
+        n: *const c_int,
+        kd: *const c_int,
+        ab: *mut c_float,
+        ldab: *const c_int,
+        info: *mut c_int,
+    );
+
+    /// Solves `A * X = B` using the band Cholesky factor computed by [`band_cholesky`] (LAPACK `spbtrs`).
+    ///
+    /// # Precision
+    /// This function operates on single-precision (`f32`) numbers.
+    ///
+    /// # Parameters
+    /// - `uplo`, `n`, `kd`, `ab`, `ldab`: As passed to, and returned by, [`band_cholesky`].
+    /// - `nrhs`: The number of right-hand sides, i.e. columns of `B`.
+    /// - `b`: The right-hand sides, column-major `n x nrhs`. Overwritten with the solution `X`.
+    /// - `ldb`: The leading dimension of `b`, at least `n`.
+    /// - `info`: Set to `0` on success and `-i` if argument `i` was invalid.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function using the Fortran calling convention: every argument is passed by
+    /// pointer. The caller must ensure that `ab` and `b` are valid for `ldab * n` and `ldb * nrhs` elements.
+    #[link_name = "spbtrs_"]
+    pub fn band_cholesky_solve(
+        uplo: *const c_char,
+        n: *const c_int,
+        kd: *const c_int,
+        nrhs: *const c_int,
+        ab: *const c_float,
+        ldab: *const c_int,
+        b: *mut c_float,
+        ldb: *const c_int,
+        info: *mut c_int,
+    );
+
+    /// Computes selected eigenvalues and, optionally, eigenvectors of a symmetric matrix using the Relatively
+    /// Robust Representations algorithm (LAPACK `ssyevr`).
+    ///
+    /// # Precision
+    /// This function operates on single-precision (`f32`) numbers.
+    ///
+    /// # Parameters
+    /// - `jobz`: `b'N'` for eigenvalues only, `b'V'` for eigenvectors too. Use `EigenJob::to_lapack`.
+    /// - `range`: `b'A'` for all eigenvalues, `b'V'` for those in `(vl, vu]`, `b'I'` for the `il`-th through
+    ///   `iu`-th. Use `EigenRange::to_lapack`.
+    /// - `uplo`: `b'U'` or `b'L'`, the triangle of `a` that is read.
+    /// - `n`: The order of the matrix `A`.
+    /// - `a`: The symmetric matrix `A`, column-major. The `uplo` triangle is destroyed on return.
+    /// - `lda`: The leading dimension of `a`, at least `n`.
+    /// - `vl`, `vu`: The value bounds, used only when `range` is `'V'`.
+    /// - `il`, `iu`: The one-based, inclusive index bounds, used only when `range` is `'I'`.
+    /// - `abstol`: The absolute error tolerance for the eigenvalues. `2 * slamch('S')` gives the most
+    ///   accurate results.
+    /// - `m`: Set to the number of eigenvalues found.
+    /// - `w`: The `m` eigenvalues, in ascending order, in the first `m` of its `n` elements.
+    /// - `z`, `ldz`: The `m` eigenvectors as the columns of a column-major `n x m` matrix, not referenced
+    ///   when `jobz` is `'N'`. `ldz` is at least `n` when they are computed and at least `1` otherwise.
+    /// - `isuppz`: The `2 * max(1, m)` support indices of the eigenvectors.
+    /// - `work`, `lwork`, `iwork`, `liwork`: Real and integer workspace. With `lwork = -1` or `liwork = -1`
+    ///   only the optimal sizes are written to `work[0]` and `iwork[0]`.
+    /// - `info`: Set to `0` on success, `-i` if argument `i` was invalid, and a positive value on an internal
+    ///   error.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function using the Fortran calling convention: every argument is passed by
+    /// pointer. The caller must ensure that every buffer is valid for the sizes described above.
+    #[link_name = "ssyevr_"]
+    pub fn symmetric_eigen_range(
+        jobz: *const c_char,
+        range: *const c_char,
+        uplo: *const c_char,
+        n: *const c_int,
+        a: *mut c_float,
+        lda: *const c_int,
+        vl: *const c_float,
+        vu: *const c_float,
+        il: *const c_int,
+        iu: *const c_int,
+        abstol: *const c_float,
+        m: *mut c_int,
+        w: *mut c_float,
+        z: *mut c_float,
+        ldz: *const c_int,
+        isuppz: *mut c_int,
+        work: *mut c_float,
+        lwork: *const c_int,
+        iwork: *mut c_int,
+        liwork: *const c_int,
+        info: *mut c_int,
+    );
+    /// Computes the singular value decomposition `A = U * S * V^T` of a general matrix (LAPACK `sgesvd`).
+    ///
+    /// # Precision
+    /// This function operates on single-precision (`f32`) numbers.
+    ///
+    /// # Parameters
+    /// - `jobu`, `jobvt`: `b'A'`, `b'S'`, `b'O'` or `b'N'`, selecting all, the leading `min(m, n)`, an
+    ///   overwrite of `a`, or none of the columns of `U` (rows of `V^T`). Use `SvdJob::to_lapack`.
+    /// - `m`, `n`: The number of rows and columns of `A`.
+    /// - `a`: The matrix `A`, column-major `m x n`. Destroyed on return (or overwritten with `U` or `V^T`
+    ///   for `'O'`).
+    /// - `lda`: The leading dimension of `a`, at least `m`.
+    /// - `s`: The `min(m, n)` singular values, returned in descending order.
+    /// - `u`, `ldu`: The left singular vectors, not referenced when `jobu` is `'N'` or `'O'`.
+    /// - `vt`, `ldvt`: The right singular vectors, not referenced when `jobvt` is `'N'` or `'O'`.
+    /// - `work`, `lwork`: Workspace of `lwork` elements. With `lwork = -1` only the optimal size is
+    ///   written to `work[0]`.
+    /// - `info`: Set to `0` on success, `-i` if argument `i` was invalid, and `i` if `i` superdiagonals of
+    ///   the intermediate bidiagonal form did not converge.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function using the Fortran calling convention: every argument is passed by
+    /// pointer. The caller must ensure that every buffer is valid for the sizes the job flags imply.
+    #[link_name = "sgesvd_"]
+    pub fn singular_value_decomposition(
+        jobu: *const c_char,
+        jobvt: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        a: *mut c_float,
+        lda: *const c_int,
+        s: *mut c_float,
+        u: *mut c_float,
+        ldu: *const c_int,
+        vt: *mut c_float,
+        ldvt: *const c_int,
+        work: *mut c_float,
+        lwork: *const c_int,
+        info: *mut c_int,
+    );
+}
+
+/// Solves a triangular banded system `op(A) * x = b` in place, after validating the band layout.
+///
+/// This is the checked counterpart of [`tri_band_solve`] (`cblas_stbsv`).
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `tri`: Specifies whether `A` is upper or lower triangular. Use `UpOrLowTriangle`.
+/// - `trans_a`: Specifies whether to use matrix `A` ('N') or the transpose of `A` ('T'). Use `TransposeMode`.
+/// - `diag`: Specifies whether `A` is unit triangular ('U') or not ('N'). Use `IsDiagUnit`.
+/// - `n`: The order of the matrix `A`.
+/// - `k`: The number of super-diagonals (upper) or sub-diagonals (lower) of `A`.
+/// - `a`: The band storage of `A`: `n` columns (column-major) or rows (row-major) of `lda` elements each.
+/// - `lda`: The leading dimension of the band storage, must be at least `k + 1`.
+/// - `x`: On entry, the right-hand side `b`. On return, overwritten with the solution `x`.
+/// - `inc_x`: The stride within `x`. For example, if `inc_x` is 7, every 7th element is used.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < k + 1`, and [`BlasError::BufferTooSmall`]
+/// if `a` or `x` is shorter than `n`, `lda` and `inc_x` require.
+/// Returns [`BlasError::ZeroIncrement`] if `inc_x` is zero.
+#[allow(clippy::too_many_arguments)]
+pub fn stbsv(
+    major: RowColMajor,
+    tri: UpOrLowTriangle,
+    trans_a: TransposeMode,
+    diag: IsDiagUnit,
+    n: usize,
+    k: usize,
+    a: &[f32],
+    lda: usize,
+    x: &mut [f32],
+    inc_x: i32,
+) -> Result<(), BlasError> {
+    check_ld("lda", k + 1, lda)?;
+    check_len("a", strided_len(n, k + 1, lda), a.len())?;
+    check_inc("inc_x", inc_x)?;
+    check_len("x", vec_len(n, inc_x), x.len())?;
+    unsafe {
+        traced!(
+            "cblas_stbsv",
+            [n, k],
+            tri_band_solve(
+                major,
+                tri,
+                trans_a,
+                diag,
+                to_c_int(n, "n")?,
+                to_c_int(k, "k")?,
+                a.as_ptr(),
+                to_c_int(lda, "lda")?,
+                x.as_mut_ptr(),
+                inc_x,
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Computes `op(A) * x` for a packed triangular matrix `A` into a new vector, leaving `x` untouched.
+///
+/// [`pack_tri_mat_vec_mul`] (`cblas_stpmv`) overwrites its input vector with the product; this copies `x`
+/// first and applies the in-place routine to the copy.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering of the packed triangle. Use `RowColMajor`.
+/// - `tri`: Specifies whether `A` is upper or lower triangular. Use `UpOrLowTriangle`.
+/// - `trans_a`: Specifies whether to use matrix `A` ('N') or the transpose of `A` ('T'). Use `TransposeMode`.
+/// - `diag`: Specifies whether `A` is unit triangular ('U') or not ('N'). Use `IsDiagUnit`.
+/// - `ap`: The packed triangle of `A`, at least `n * (n + 1) / 2` elements.
+/// - `n`: The order of the matrix `A`.
+/// - `x`: The vector `x`.
+///
+/// # Errors
+/// Returns [`BlasError::BufferTooSmall`] if `ap` is too short and [`BlasError::DimensionMismatch`] if `x`
+/// does not hold `n` elements.
+pub fn stpmv_to_vec(
+    major: RowColMajor,
+    tri: UpOrLowTriangle,
+    trans_a: TransposeMode,
+    diag: IsDiagUnit,
+    ap: &[f32],
+    n: usize,
+    x: &[f32],
+) -> Result<Vec<f32>, BlasError> {
+    check_len("ap", n * (n + 1) / 2, ap.len())?;
+    check_shape("x", n, x.len())?;
+    let mut y = x.to_vec();
+    unsafe {
+        traced!(
+            "cblas_stpmv",
+            [n],
+            pack_tri_mat_vec_mul(
+                major,
+                tri,
+                trans_a,
+                diag,
+                to_c_int(n, "n")?,
+                ap.as_ptr(),
+                y.as_mut_ptr(),
+                1,
+            )
+        );
+    }
+    Ok(y)
+}
+
+/// Solves the tridiagonal system `A * x = b` in place.
+///
+/// `A` is factored as `L * U` without pivoting (the Thomas algorithm), where `L` is unit lower
+/// bidiagonal and `U` is upper bidiagonal. Both factors are assembled in band storage
+/// (`k = 1`, column-major) and applied with two calls to [`stbsv`].
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `lower`: The `n - 1` sub-diagonal elements of `A`.
+/// - `diag`: The `n` diagonal elements of `A`.
+/// - `upper`: The `n - 1` super-diagonal elements of `A`.
+/// - `b`: On entry, the right-hand side `b`. On return, overwritten with the solution `x`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `lower`, `upper` or `b` does not match the length of `diag`.
+///
+/// # Discussion
+/// Since no pivoting is performed, `A` should be diagonally dominant or positive definite, as is the case
+/// for most 1-D PDE discretizations and cubic spline systems. A zero pivot produces non-finite results.
+pub fn solve_tridiagonal(
+    lower: &[f32],
+    diag: &[f32],
+    upper: &[f32],
+    b: &mut [f32],
+) -> Result<(), BlasError> {
+    let n = diag.len();
+    check_shape("lower", n.saturating_sub(1), lower.len())?;
+    check_shape("upper", n.saturating_sub(1), upper.len())?;
+    check_shape("b", n, b.len())?;
+    if n == 0 {
+        return Ok(());
+    }
+
+    // Column j of each band holds (A[j - 1][j], A[j][j]) for `U` and (A[j][j], A[j + 1][j]) for `L`.
+    let mut l_band = vec![0.0; 2 * n];
+    let mut u_band = vec![0.0; 2 * n];
+    u_band[1] = diag[0];
+    for j in 0..n {
+        l_band[2 * j] = 1.0;
+        if j > 0 {
+            let l = lower[j - 1] / u_band[2 * (j - 1) + 1];
+            l_band[2 * (j - 1) + 1] = l;
+            u_band[2 * j] = upper[j - 1];
+            u_band[2 * j + 1] = diag[j] - l * upper[j - 1];
+        }
+    }
+
+    stbsv(
+        RowColMajor::ColMajor,
+        UpOrLowTriangle::Lower,
+        TransposeMode::NoTrans,
+        IsDiagUnit::Unit,
+        n,
+        1,
+        &l_band,
+        2,
+        b,
+        1,
+    )?;
+    stbsv(
+        RowColMajor::ColMajor,
+        UpOrLowTriangle::Upper,
+        TransposeMode::NoTrans,
+        IsDiagUnit::NonUnit,
+        n,
+        1,
+        &u_band,
+        2,
+        b,
+        1,
+    )
+}
+
+/// Computes the quadratic form `x^T * A * x` for a general square matrix `A`.
+///
+/// `y = A * x` is formed with [`mat_vec_mul`] (`cblas_sgemv`) and reduced with
+/// [`vector_f32::dot`] (`cblas_sdot`).
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `n`: The order of the matrix `A` and the length of `x`.
+/// - `a`: The `n x n` matrix `A`.
+/// - `lda`: The leading dimension of `A`, must be at least `n`.
+/// - `x`: The vector `x`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n`, [`BlasError::BufferTooSmall`] if `a` is too
+/// short, and [`BlasError::DimensionMismatch`] if `x` does not hold `n` elements.
+pub fn quadratic_form(
+    major: RowColMajor,
+    n: usize,
+    a: &[f32],
+    lda: usize,
+    x: &[f32],
+) -> Result<f32, BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    check_shape("x", n, x.len())?;
+    let mut y = vec![0.0; n];
+    unsafe {
+        traced!(
+            "cblas_sgemv",
+            [n],
+            mat_vec_mul(
+                major,
+                TransposeMode::NoTrans,
+                to_c_int(n, "n")?,
+                to_c_int(n, "n")?,
+                1.0,
+                a.as_ptr(),
+                to_c_int(lda, "lda")?,
+                x.as_ptr(),
+                1,
+                0.0,
+                y.as_mut_ptr(),
+                1,
+            )
+        );
+        Ok(traced!(
+            "cblas_sdot",
+            [n],
+            vector_f32::dot(to_c_int(n, "n")?, x.as_ptr(), 1, y.as_ptr(), 1)
+        ))
+    }
+}
+
+/// Computes the quadratic form `x^T * A * x` for a symmetric matrix `A`, reading only one triangle.
+///
+/// `y = A * x` is formed with [`sym_mat_vec_mul`] (`cblas_ssymv`) and reduced with
+/// [`vector_f32::dot`] (`cblas_sdot`).
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `tri`: Specifies whether the upper or lower triangle of `A` is referenced. Use `UpOrLowTriangle`.
+/// - `n`: The order of the matrix `A` and the length of `x`.
+/// - `a`: The symmetric `n x n` matrix `A`.
+/// - `lda`: The leading dimension of `A`, must be at least `n`.
+/// - `x`: The vector `x`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n`, [`BlasError::BufferTooSmall`] if `a` is too
+/// short, and [`BlasError::DimensionMismatch`] if `x` does not hold `n` elements.
+pub fn quadratic_form_sym(
+    major: RowColMajor,
+    tri: UpOrLowTriangle,
+    n: usize,
+    a: &[f32],
+    lda: usize,
+    x: &[f32],
+) -> Result<f32, BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    check_shape("x", n, x.len())?;
+    let mut y = vec![0.0; n];
+    unsafe {
+        traced!(
+            "cblas_ssymv",
+            [n],
+            sym_mat_vec_mul(
+                major,
+                tri,
+                to_c_int(n, "n")?,
+                1.0,
+                a.as_ptr(),
+                to_c_int(lda, "lda")?,
+                x.as_ptr(),
+                1,
+                0.0,
+                y.as_mut_ptr(),
+                1,
+            )
+        );
+        Ok(traced!(
+            "cblas_sdot",
+            [n],
+            vector_f32::dot(to_c_int(n, "n")?, x.as_ptr(), 1, y.as_ptr(), 1)
+        ))
+    }
+}
+
+/// Computes the biased (population) covariance matrix `(1 / n) * Xc^T * Xc` of a data matrix.
+///
+/// `Xc` is the data matrix with each feature (column) centered on its mean. The product is formed
+/// with [`sym_rank_k_update`] (`cblas_ssyrk`), which only writes the upper triangle, and the lower
+/// triangle is then mirrored so the result is a full symmetric matrix.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `major`: The storage order of `data` and of the returned matrix. Use `RowColMajor`.
+/// - `n_samples`: The number of samples (rows of the data matrix).
+/// - `n_features`: The number of features (columns of the data matrix).
+/// - `data`: The tightly packed `n_samples x n_features` data matrix.
+///
+/// # Returns
+/// The `n_features x n_features` covariance matrix, stored in `major` order.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `data` does not hold exactly `n_samples * n_features` elements.
+/// Returns it with `which = "n_samples"` if there are no samples, since the mean is then undefined.
+///
+/// # Discussion
+/// Dividing by `n` gives the maximum-likelihood estimate, which is biased for small samples. Use
+/// [`covariance_unbiased`] for the sample covariance that divides by `n - 1`.
+pub fn covariance(
+    major: RowColMajor,
+    n_samples: usize,
+    n_features: usize,
+    data: &[f32],
+) -> Result<Vec<f32>, BlasError> {
+    if n_samples == 0 {
+        return Err(BlasError::DimensionMismatch {
+            which: "n_samples",
+            expected: 1,
+            got: 0,
+        });
+    }
+    covariance_scaled(major, n_samples, n_features, data, 1.0 / n_samples as f32)
+}
+
+/// Computes the unbiased sample covariance matrix `(1 / (n - 1)) * Xc^T * Xc` of a data matrix.
+///
+/// Identical to [`covariance`] except for the `n - 1` divisor (Bessel's correction), which makes the
+/// estimate unbiased. It needs at least two samples.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `data` does not hold exactly `n_samples * n_features` elements.
+/// Returns it with `which = "n_samples"` if there are fewer than two samples, since the `n - 1` divisor
+/// is then zero or negative.
+pub fn covariance_unbiased(
+    major: RowColMajor,
+    n_samples: usize,
+    n_features: usize,
+    data: &[f32],
+) -> Result<Vec<f32>, BlasError> {
+    if n_samples < 2 {
+        return Err(BlasError::DimensionMismatch {
+            which: "n_samples",
+            expected: 2,
+            got: n_samples,
+        });
+    }
+    covariance_scaled(
+        major,
+        n_samples,
+        n_features,
+        data,
+        1.0 / (n_samples as f32 - 1.0),
+    )
+}
+
+fn covariance_scaled(
+    major: RowColMajor,
+    n_samples: usize,
+    n_features: usize,
+    data: &[f32],
+    scale: f32,
+) -> Result<Vec<f32>, BlasError> {
+    check_shape("data", n_samples * n_features, data.len())?;
+    let (lda, sample_stride, feature_stride) = match major {
+        RowColMajor::RowMajor => (n_features, n_features, 1),
+        RowColMajor::ColMajor => (n_samples, 1, n_samples),
+    };
+
+    let mut centered = data.to_vec();
+    for j in 0..n_features {
+        let column = (0..n_samples).map(|i| i * sample_stride + j * feature_stride);
+        let mean = column.clone().map(|idx| data[idx]).sum::<f32>() / n_samples as f32;
+        for idx in column {
+            centered[idx] -= mean;
+        }
+    }
+
+    let mut cov = vec![0.0; n_features * n_features];
+    unsafe {
+        traced!(
+            "cblas_ssyrk",
+            [n_features, n_samples],
+            sym_rank_k_update(
+                major,
+                UpOrLowTriangle::Upper,
+                TransposeMode::Trans,
+                to_c_int(n_features, "n_features")?,
+                to_c_int(n_samples, "n_samples")?,
+                scale,
+                centered.as_ptr(),
+                to_c_int(lda.max(1), "lda")?,
+                0.0,
+                cov.as_mut_ptr(),
+                to_c_int(n_features.max(1), "n_features")?,
+            )
+        );
+    }
+    symmetrize(
+        major,
+        UpOrLowTriangle::Upper,
+        n_features,
+        &mut cov,
+        n_features,
+    )?;
+    Ok(cov)
+}
+
+/// Forms the Gram matrix of `A` on its smaller side: `A^T * A` (`n x n`) when `n <= m`, otherwise
+/// `A * A^T` (`m x m`).
+///
+/// The product is computed with [`sym_rank_k_update`] (`cblas_ssyrk`), which only writes the upper
+/// triangle, and the lower triangle is then mirrored so the result is a full symmetric matrix.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `major`: The storage order of `a` and of the returned matrix. Use `RowColMajor`.
+/// - `m`: The number of rows of `A`.
+/// - `n`: The number of columns of `A`.
+/// - `a`: The tightly packed `m x n` matrix `A`.
+///
+/// # Returns
+/// The tightly packed Gram matrix, and `true` if it is `A * A^T` rather than `A^T * A`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `a` does not hold exactly `m * n` elements.
+///
+/// # Discussion
+/// For a tall-skinny `A` (overdetermined least squares) `A^T * A` gives the normal equations
+/// `A^T * A * x = A^T * b`; for a short-fat `A` (minimum-norm solutions) `A * A^T` gives
+/// `A * A^T * y = b` with `x = A^T * y`. Either way the smaller Gram matrix costs
+/// `min(m, n)^2 * max(m, n)` multiply-adds instead of `max(m, n)^2 * min(m, n)`.
+pub fn normal_equations(
+    major: RowColMajor,
+    m: usize,
+    n: usize,
+    a: &[f32],
+) -> Result<(Vec<f32>, bool), BlasError> {
+    check_shape("a", m * n, a.len())?;
+    let lda = match major {
+        RowColMajor::RowMajor => n,
+        RowColMajor::ColMajor => m,
+    };
+    let transposed = n > m;
+    let (order, depth, trans) = if transposed {
+        (m, n, TransposeMode::NoTrans)
+    } else {
+        (n, m, TransposeMode::Trans)
+    };
+
+    let mut gram = vec![0.0; order * order];
+    unsafe {
+        traced!(
+            "cblas_ssyrk",
+            [order, depth],
+            sym_rank_k_update(
+                major,
+                UpOrLowTriangle::Upper,
+                trans,
+                to_c_int(order, "order")?,
+                to_c_int(depth, "depth")?,
+                1.0,
+                a.as_ptr(),
+                to_c_int(lda.max(1), "lda")?,
+                0.0,
+                gram.as_mut_ptr(),
+                to_c_int(order.max(1), "order")?,
+            )
+        );
+    }
+    symmetrize(major, UpOrLowTriangle::Upper, order, &mut gram, order)?;
+    Ok((gram, transposed))
+}
+
+/// Fills the opposite triangle of a symmetric matrix from the populated one, so `a[j][i] = a[i][j]`.
+///
+/// Routines such as `cblas_ssyrk` only write one triangle; this materializes the full matrix for code that expects a dense one. The diagonal is left untouched.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `tri`: The triangle of `A` that is populated and is copied from. Use `UpOrLowTriangle`.
+/// - `n`: The order of the matrix `A`.
+/// - `a`: The `n x n` matrix `A`, completed in place.
+/// - `lda`: The leading dimension of `A`, must be at least `n`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n` and [`BlasError::BufferTooSmall`] if `a` is too short.
+pub fn symmetrize(
+    major: RowColMajor,
+    tri: UpOrLowTriangle,
+    n: usize,
+    a: &mut [f32],
+    lda: usize,
+) -> Result<(), BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    for i in 0..n {
+        for j in (i + 1)..n {
+            // (i, j) lies in the upper triangle and (j, i) in the lower one.
+            let (upper, lower) = match major {
+                RowColMajor::RowMajor => (i * lda + j, j * lda + i),
+                RowColMajor::ColMajor => (j * lda + i, i * lda + j),
+            };
+            match tri {
+                UpOrLowTriangle::Upper => a[lower] = a[upper],
+                UpOrLowTriangle::Lower => a[upper] = a[lower],
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns whether the `n x n` matrix `A` is symmetric to within `tol`, i.e. `|a[i][j] - a[j][i]| <= tol` for
+/// every `i != j`.
+///
+/// Symmetric routines such as `cblas_ssymv` read only one triangle, so a matrix that is not actually symmetric
+/// gives a wrong result without any error. This predicate lets that assumption be checked, for instance in a
+/// `debug_assert!`, before the call.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `a`: The `n x n` matrix `A`.
+/// - `n`: The order of the matrix `A`.
+/// - `lda`: The leading dimension of `A`, must be at least `n`.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `tol`: The largest absolute difference accepted between mirrored entries. `0` demands exact symmetry.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n` and [`BlasError::BufferTooSmall`] if `a` is too short.
+pub fn is_symmetric(
+    a: &[f32],
+    n: usize,
+    lda: usize,
+    order: RowColMajor,
+    tol: f32,
+) -> Result<bool, BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let (upper, lower) = match order {
+                RowColMajor::RowMajor => (i * lda + j, j * lda + i),
+                RowColMajor::ColMajor => (j * lda + i, i * lda + j),
+            };
+            let diff = (a[upper] - a[lower]).abs();
+            if diff.is_nan() || diff > tol {
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// Builds a dense, tightly packed `n x n` matrix with `d` on its diagonal and zeros elsewhere.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `d`: The `n` diagonal entries.
+///
+/// # Returns
+/// The `n x n` matrix with leading dimension `n`. The diagonal occupies the same positions in row- and
+/// column-major storage, so the result can be passed with either `RowColMajor`.
+pub fn from_diagonal(d: &[f32]) -> Vec<f32> {
+    let n = d.len();
+    let mut a = vec![0.0; n * n];
+    for (i, &v) in d.iter().enumerate() {
+        a[i * (n + 1)] = v;
+    }
+    a
+}
+
+/// Extracts the diagonal of an `n x n` matrix.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `n`: The order of the matrix `A`.
+/// - `a`: The matrix `A`, in either `RowColMajor` order; element `(i, i)` sits at `i * (lda + 1)` in both.
+/// - `lda`: The leading dimension of `A`, must be at least `n`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n` and [`BlasError::BufferTooSmall`] if `a` is too short.
+pub fn diagonal(n: usize, a: &[f32], lda: usize) -> Result<Vec<f32>, BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    Ok((0..n).map(|i| a[i * (lda + 1)]).collect())
+}
+
+/// Computes the Kronecker product `A ⊗ B` of an `ma x na` matrix `A` and an `mb x nb` matrix `B`.
+///
+/// The result is the `(ma * mb) x (na * nb)` block matrix whose `(i, j)` block is `a[i][j] * B`. Each of its
+/// rows is an outer product of a row of `A` with a row of `B`, formed with [`mat_rank1_update`] (`cblas_sger`).
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `major`: The storage order of `a`, `b` and the returned matrix. Use `RowColMajor`.
+/// - `a`: The tightly packed matrix `A`.
+/// - `(ma, na)`: The number of rows and columns of `A`.
+/// - `b`: The tightly packed matrix `B`.
+/// - `(mb, nb)`: The number of rows and columns of `B`.
+///
+/// # Returns
+/// The tightly packed `(ma * mb) x (na * nb)` product, stored in `major` order.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `a` or `b` does not hold exactly the number of elements its
+/// shape requires.
+pub fn kron(
+    major: RowColMajor,
+    a: &[f32],
+    (ma, na): (usize, usize),
+    b: &[f32],
+    (mb, nb): (usize, usize),
+) -> Result<Vec<f32>, BlasError> {
+    check_shape("a", ma * na, a.len())?;
+    check_shape("b", mb * nb, b.len())?;
+    // Column-major storage of a matrix is the row-major storage of its transpose, and
+    // (A ⊗ B)^T = A^T ⊗ B^T, so both orders reduce to the row-major case.
+    let ((ma, na), (mb, nb)) = match major {
+        RowColMajor::RowMajor => ((ma, na), (mb, nb)),
+        RowColMajor::ColMajor => ((na, ma), (nb, mb)),
+    };
+    let cols = na * nb;
+    let mut out = vec![0.0; ma * mb * cols];
+    if out.is_empty() {
+        return Ok(out);
+    }
+    let na_c = to_c_int(na, "na")?;
+    let nb_c = to_c_int(nb, "nb")?;
+    for i in 0..ma {
+        for p in 0..mb {
+            // Row (i, p) of the result is the na x nb outer product of row i of A and row p of B.
+            let row = &mut out[(i * mb + p) * cols..][..cols];
+            unsafe {
+                traced!(
+                    "cblas_sger",
+                    [na, nb],
+                    mat_rank1_update(
+                        RowColMajor::RowMajor,
+                        na_c,
+                        nb_c,
+                        1.0,
+                        a[i * na..].as_ptr(),
+                        1,
+                        b[p * nb..].as_ptr(),
+                        1,
+                        row.as_mut_ptr(),
+                        nb_c,
+                    )
+                );
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Applies a sequence of plane rotations to pairs of rows of a matrix, in order.
+///
+/// Each `(i, j, c, s)` replaces rows `i` and `j` of `M` by `c * row_i + s * row_j` and `c * row_j - s * row_i`,
+/// using [`givens::givens_rot_f32`] (`cblas_srot`) on the two row views. In row-major order a row is contiguous; in
+/// column-major order its elements are `rows` apart, so the row views use that stride instead. This is the
+/// update QR-by-Givens and bidiagonalization codes apply when they zero one subdiagonal entry at a time.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers with real `f32` rotation coefficients.
+///
+/// # Parameters
+/// - `rotations`: The `(i, j, c, s)` rotations, applied first to last. `i` and `j` must differ.
+/// - `m`: The tightly packed `rows x cols` matrix `M`, updated in place.
+/// - `rows`: The number of rows of `M`.
+/// - `cols`: The number of columns of `M`.
+/// - `major`: The storage order of `m`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `m` does not hold exactly `rows * cols` elements and
+/// [`BlasError::IndexOutOfBounds`] if a rotation names a row `>= rows`, and [`BlasError::RepeatedIndex`] if
+/// a rotation pairs a row with itself. All rotations are validated before any is applied, so `m` is left
+/// untouched on error.
+pub fn apply_givens_sequence(
+    rotations: &[(usize, usize, f32, f32)],
+    m: &mut [f32],
+    rows: usize,
+    cols: usize,
+    major: RowColMajor,
+) -> Result<(), BlasError> {
+    check_shape("m", rows * cols, m.len())?;
+    for &(i, j, _, _) in rotations {
+        check_indices("rotations", &[i, j], rows)?;
+        if i == j {
+            return Err(BlasError::RepeatedIndex {
+                which: "rotations",
+                index: i,
+            });
+        }
+    }
+    let (row_stride, inc) = match major {
+        RowColMajor::RowMajor => (cols, 1),
+        RowColMajor::ColMajor => (1, rows),
+    };
+    let n = to_c_int(cols, "cols")?;
+    let inc = to_c_int(inc, "rows")?;
+    let ptr = m.as_mut_ptr();
+    for &(i, j, c, s) in rotations {
+        unsafe {
+            traced!(
+                "cblas_srot",
+                [cols],
+                givens::givens_rot_f32(
+                    n,
+                    ptr.add(i * row_stride),
+                    inc,
+                    ptr.add(j * row_stride),
+                    inc,
+                    c,
+                    s,
+                )
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Band widths up to `n / BAND_FRACTION` are multiplied with the banded kernel by [`gemv_banded_auto`];
+/// wider bands fall back to the dense one, since the band kernel would do nearly as much work.
+const BAND_FRACTION: usize = 4;
+
+/// Computes `y = A * x` for a densely stored `m x n` matrix, using the banded kernel when `A` turns out to
+/// be banded.
+///
+/// # Precision
+/// This function operates on single-precision floating-point (`f32`) numbers.
+///
+/// # Parameters
+/// - `a`: The matrix `A`, stored densely.
+/// - `m`, `n`: The number of rows and columns of `A`.
+/// - `lda`: The leading dimension of `a`.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `x`: The input vector, of length `n`.
+///
+/// # Returns
+/// The product `A * x`, of length `m`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is below its minimum,
+/// [`BlasError::BufferTooSmall`] if `a` is too short, and [`BlasError::DimensionMismatch`] if `x` is not of
+/// length `n`.
+///
+/// # Discussion
+/// The matrix is scanned once for its lower and upper bandwidths `kl` and `ku`, the distance of the
+/// furthest nonzero below and above the diagonal. If the band `kl + ku + 1` spans at most a quarter of the
+/// columns, it is packed into BLAS band storage and multiplied with `cblas_sgbmv`, which touches only the
+/// band. Otherwise `a` is passed straight to `cblas_sgemv`.
+///
+/// This is a convenience for matrices whose structure is not known in advance, not an optimization. Nothing
+/// is cached between calls, and the scan and the packing read all `m * n` elements, which is already as much
+/// memory traffic as one dense `cblas_sgemv`. A call is never faster than calling `cblas_sgemv` directly.
+pub fn gemv_banded_auto(
+    a: &[f32],
+    m: usize,
+    n: usize,
+    lda: usize,
+    order: RowColMajor,
+    x: &[f32],
+) -> Result<Vec<f32>, BlasError> {
+    let (outer, inner) = match order {
+        RowColMajor::RowMajor => (m, n),
+        RowColMajor::ColMajor => (n, m),
+    };
+    check_ld("lda", inner.max(1), lda)?;
+    check_len("a", strided_len(outer, inner, lda), a.len())?;
+    check_shape("x", n, x.len())?;
+    let mut y = vec![0.0; m];
+    if m == 0 || n == 0 {
+        return Ok(y);
+    }
+    let at = |i: usize, j: usize| match order {
+        RowColMajor::RowMajor => a[i * lda + j],
+        RowColMajor::ColMajor => a[j * lda + i],
+    };
+    let (mut kl, mut ku) = (0, 0);
+    for i in 0..m {
+        for j in 0..n {
+            if at(i, j) != 0.0 {
+                if i > j {
+                    kl = kl.max(i - j);
+                } else {
+                    ku = ku.max(j - i);
+                }
+            }
+        }
+    }
+    let band = kl + ku + 1;
+    if band * BAND_FRACTION <= n {
+        // Row-major band storage keeps row i at offset kl - i, column-major keeps column j at ku - j.
+        let mut ab = vec![0.0; band * outer];
+        for i in 0..m {
+            for j in i.saturating_sub(kl)..n.min(i + ku + 1) {
+                let idx = match order {
+                    RowColMajor::RowMajor => i * band + kl + j - i,
+                    RowColMajor::ColMajor => j * band + ku + i - j,
+                };
+                ab[idx] = at(i, j);
+            }
+        }
+        unsafe {
+            traced!(
+                "cblas_sgbmv",
+                [m, n, kl, ku],
+                band_mat_mul_vec(
+                    order,
+                    TransposeMode::NoTrans,
+                    to_c_int(m, "m")?,
+                    to_c_int(n, "n")?,
+                    to_c_int(kl, "kl")?,
+                    to_c_int(ku, "ku")?,
+                    1.0,
+                    ab.as_ptr(),
+                    to_c_int(band, "band")?,
+                    x.as_ptr(),
+                    1,
+                    0.0,
+                    y.as_mut_ptr(),
+                    1,
+                )
+            );
+        }
+    } else {
+        unsafe {
+            traced!(
+                "cblas_sgemv",
+                [m, n],
+                mat_vec_mul(
+                    order,
+                    TransposeMode::NoTrans,
+                    to_c_int(m, "m")?,
+                    to_c_int(n, "n")?,
+                    1.0,
+                    a.as_ptr(),
+                    to_c_int(lda, "lda")?,
+                    x.as_ptr(),
+                    1,
+                    0.0,
+                    y.as_mut_ptr(),
+                    1,
+                )
+            );
+        }
+    }
+    Ok(y)
+}
+
+/// A copy of `op(A)` packed once for repeated use by [`sgemm_packed`].
+///
+/// Accelerate does not export a packed-operand GEMM (there is no counterpart to `cblas_sgemm_pack` /
+/// `cblas_sgemm_compute` in `cblas.h` or the `$NEWLAPACK` interfaces), so the blocking Accelerate does
+/// internally cannot be cached across calls. What can be done once is everything on the Rust side:
+/// validating `A`, resolving `trans_a`, and gathering a strided or padded `A` into a tight row-major
+/// buffer, so every later product reads one contiguous block with `NoTrans` and `lda = k`.
+pub struct PackedA {
+    data: Vec<f32>,
+    m: usize,
+    k: usize,
+}
+
+impl PackedA {
+    /// The number of rows of `op(A)`, and so of every product.
+    pub fn rows(&self) -> usize {
+        self.m
+    }
+
+    /// The number of columns of `op(A)`, which must match the number of rows of every `B`.
+    pub fn cols(&self) -> usize {
+        self.k
+    }
+}
+
+/// Packs `op(A)` into a [`PackedA`] for multiplying by many right-hand sides with [`sgemm_packed`].
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering of `a`. Use `RowColMajor`.
+/// - `trans_a`: Whether `A` is used as stored or transposed. Use `TransposeMode`.
+/// - `m`, `k`: `op(A)` is `m x k`.
+/// - `a`: The matrix `A`.
+/// - `lda`: The leading dimension of `a`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is below its minimum and
+/// [`BlasError::BufferTooSmall`] if `a` is too short.
+pub fn pack_a(
+    major: RowColMajor,
+    trans_a: TransposeMode,
+    m: usize,
+    k: usize,
+    a: &[f32],
+    lda: usize,
+) -> Result<PackedA, BlasError> {
+    let (rows, cols) = match trans_a {
+        TransposeMode::NoTrans => (m, k),
+        _ => (k, m),
+    };
+    let (outer, inner) = match major {
+        RowColMajor::RowMajor => (rows, cols),
+        RowColMajor::ColMajor => (cols, rows),
+    };
+    check_ld("lda", inner.max(1), lda)?;
+    check_len("a", strided_len(outer, inner, lda), a.len())?;
+    // Element (i, j) of the stored matrix, then read through op().
+    let stored = |i: usize, j: usize| match major {
+        RowColMajor::RowMajor => a[i * lda + j],
+        RowColMajor::ColMajor => a[j * lda + i],
+    };
+    let mut data = Vec::with_capacity(m * k);
+    for i in 0..m {
+        for p in 0..k {
+            data.push(match trans_a {
+                TransposeMode::NoTrans => stored(i, p),
+                _ => stored(p, i),
+            });
+        }
+    }
+    Ok(PackedA { data, m, k })
+}
+
+/// Computes `C = A * B` with a pre-packed `A`, overwriting `C`.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `packed_a`: `op(A)` as returned by [`pack_a`], `m x k`.
+/// - `b`: The `k x n` matrix `B`, row-major and tightly packed.
+/// - `c`: The `m x n` result `C`, row-major and tightly packed.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `b.len()` is not a multiple of `k` giving `n` columns that
+/// match `c.len() == m * n`.
+///
+/// # Discussion
+/// Each call is one `cblas_sgemm` on the packed buffer. The saving over calling `sgemm` on the original `A`
+/// is the per-call validation and, for transposed, column-major or padded inputs, the strided reads of `A`.
+pub fn sgemm_packed(packed_a: &PackedA, b: &[f32], c: &mut [f32]) -> Result<(), BlasError> {
+    let (m, k) = (packed_a.m, packed_a.k);
+    // With k = 0, B is empty and the width of the product can only be read off C.
+    let n = b.len().checked_div(k).unwrap_or(c.len() / m.max(1));
+    check_shape("b", k * n, b.len())?;
+    check_shape("c", m * n, c.len())?;
+    unsafe {
+        traced!(
+            "cblas_sgemm",
+            [m, n, k],
+            mat_mul(
+                RowColMajor::RowMajor,
+                TransposeMode::NoTrans,
+                TransposeMode::NoTrans,
+                to_c_int(m, "m")?,
+                to_c_int(n, "n")?,
+                to_c_int(k, "k")?,
+                1.0,
+                packed_a.data.as_ptr(),
+                to_c_int(k.max(1), "lda")?,
+                b.as_ptr(),
+                to_c_int(n.max(1), "ldb")?,
+                0.0,
+                c.as_mut_ptr(),
+                to_c_int(n.max(1), "ldc")?,
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Computes `y = alpha * op(A) * x + beta * y` into a strided segment of a larger buffer, starting at
+/// `y[y_offset]`.
+///
+/// Block algorithms often accumulate each block's product into its own segment of one big output vector.
+/// This writes that segment in place, so no temporary slice or copy is needed, and checks that every
+/// strided write lands inside `y`.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering of `a`. Use `RowColMajor`.
+/// - `trans`: Whether `A` is used as stored or transposed. Use `TransposeMode`.
+/// - `a`: The `m x n` matrix `A`.
+/// - `m`, `n`: The number of rows and columns of `A`.
+/// - `lda`: The leading dimension of `a`.
+/// - `alpha`: The scalar applied to `op(A) * x`.
+/// - `x`: The input vector, of length `n` (or `m` when transposed), with unit stride.
+/// - `beta`: The scalar applied to the existing segment of `y`. With `beta = 0` it need not be initialized.
+/// - `y`: The output buffer. Only the `len` elements of the segment are read or written, where `len` is `m`
+///   (or `n` when transposed).
+/// - `y_offset`: The index in `y` of the first element of the segment. For a negative `inc_y` this is
+///   still the lowest index touched, as BLAS walks the segment backwards from its far end.
+/// - `inc_y`: The increment between elements of the segment.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is below its minimum,
+/// [`BlasError::BufferTooSmall`] if `a` is too short or the segment runs past the end of `y`, and
+/// [`BlasError::DimensionMismatch`] if `x` has the wrong length.
+/// Returns [`BlasError::ZeroIncrement`] if `inc_y` is zero.
+#[allow(clippy::too_many_arguments)]
+pub fn sgemv_into(
+    major: RowColMajor,
+    trans: TransposeMode,
+    a: &[f32],
+    m: usize,
+    n: usize,
+    lda: usize,
+    alpha: f32,
+    x: &[f32],
+    beta: f32,
+    y: &mut [f32],
+    y_offset: usize,
+    inc_y: i32,
+) -> Result<(), BlasError> {
+    let (outer, inner) = match major {
+        RowColMajor::RowMajor => (m, n),
+        RowColMajor::ColMajor => (n, m),
+    };
+    check_ld("lda", inner.max(1), lda)?;
+    check_len("a", strided_len(outer, inner, lda), a.len())?;
+    let (x_len, y_len) = match trans {
+        TransposeMode::NoTrans => (n, m),
+        _ => (m, n),
+    };
+    check_shape("x", x_len, x.len())?;
+    // The segment covers y[y_offset ..= y_offset + (y_len - 1) * |inc_y|]; saturate so an absurd offset
+    // reports as too short rather than wrapping around.
+    check_inc("inc_y", inc_y)?;
+    check_len("y", y_offset.saturating_add(vec_len(y_len, inc_y)), y.len())?;
+    if y_len == 0 {
+        return Ok(());
+    }
+    unsafe {
+        traced!(
+            "cblas_sgemv",
+            [m, n],
+            mat_vec_mul(
+                major,
+                trans,
+                to_c_int(m, "m")?,
+                to_c_int(n, "n")?,
+                alpha,
+                a.as_ptr(),
+                to_c_int(lda, "lda")?,
+                x.as_ptr(),
+                1,
+                beta,
+                y[y_offset..].as_mut_ptr(),
+                inc_y,
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Solves `A * X = B` for a symmetric positive-definite band matrix `A`, factoring it in place with
+/// [`band_cholesky`] (`spbtrf`) and solving with [`band_cholesky_solve`] (`spbtrs`).
+///
+/// Banded SPD systems, such as finite-difference discretizations of 1-D and 2-D diffusion problems, cost
+/// `O(n * kd^2)` to factor this way instead of `O(n^3)` for a dense Cholesky.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `tri`: Which triangle of the band `ab` holds. Use `UpOrLowTriangle`.
+/// - `ab`: The band of `A` in tight LAPACK band storage: `n` columns of `kd + 1` elements, column-major. For
+///   the upper triangle `A(i, j)` is at `ab[kd + i - j + j * (kd + 1)]` (so the diagonal is the last row);
+///   for the lower triangle it is at `ab[i - j + j * (kd + 1)]` (the diagonal is the first row).
+///   Overwritten with the Cholesky factor.
+/// - `n`: The order of `A`.
+/// - `kd`: The number of super-diagonals (upper) or sub-diagonals (lower) of `A`.
+/// - `b`: The right-hand sides, column-major `n x nrhs` with `nrhs = b.len() / n`. Overwritten with `X`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `ab` does not hold exactly `(kd + 1) * n` elements or `b` is
+/// not a whole number of columns of length `n`, and [`BlasError::NotPositiveDefinite`] if the factorization
+/// breaks down, in which case `ab` is left partially factored and `b` is untouched.
+pub fn solve_banded_spd(
+    tri: UpOrLowTriangle,
+    ab: &mut [f32],
+    n: usize,
+    kd: usize,
+    b: &mut [f32],
+) -> Result<(), BlasError> {
+    let ldab = kd + 1;
+    check_shape("ab", ldab * n, ab.len())?;
+    if n == 0 {
+        return Ok(());
+    }
+    let nrhs = b.len() / n;
+    check_shape("b", nrhs * n, b.len())?;
+    let uplo = match tri {
+        UpOrLowTriangle::Upper => b'U',
+        UpOrLowTriangle::Lower => b'L',
+    } as c_char;
+    let (n_c, kd_c, ldab_c) = (
+        to_c_int(n, "n")?,
+        to_c_int(kd, "kd")?,
+        to_c_int(ldab, "ldab")?,
+    );
+    let nrhs_c = to_c_int(nrhs, "nrhs")?;
+    let mut info: c_int = 0;
+    unsafe {
+        traced!(
+            "spbtrf_",
+            [n, kd],
+            band_cholesky(&uplo, &n_c, &kd_c, ab.as_mut_ptr(), &ldab_c, &mut info)
+        );
+    }
+    // Every argument was validated above, so only a breakdown (info > 0) can be reported.
+    if info > 0 {
+        return Err(BlasError::NotPositiveDefinite {
+            minor: info as usize,
+        });
+    }
+    unsafe {
+        traced!(
+            "spbtrs_",
+            [n, kd, nrhs],
+            band_cholesky_solve(
+                &uplo,
+                &n_c,
+                &kd_c,
+                &nrhs_c,
+                ab.as_ptr(),
+                &ldab_c,
+                b.as_mut_ptr(),
+                &n_c,
+                &mut info,
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Swaps rows `i` and `j` of a `rows x cols` matrix in place with `cblas_sswap`.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `a`: The matrix.
+/// - `rows`, `cols`: The shape of the matrix.
+/// - `lda`: The leading dimension of `a`.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `i`, `j`: The rows to exchange. Swapping a row with itself does nothing.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is below its minimum,
+/// [`BlasError::BufferTooSmall`] if `a` is too short, and [`BlasError::IndexOutOfBounds`] if `i` or `j` is
+/// not less than `rows`.
+///
+/// # Discussion
+/// In row-major storage a row is contiguous; in column-major storage its elements are `lda` apart. The
+/// strided swap handles both without copying, which makes this the building block for row pivoting.
+pub fn swap_rows(
+    a: &mut [f32],
+    rows: usize,
+    cols: usize,
+    lda: usize,
+    order: RowColMajor,
+    i: usize,
+    j: usize,
+) -> Result<(), BlasError> {
+    let (outer, inner) = match order {
+        RowColMajor::RowMajor => (rows, cols),
+        RowColMajor::ColMajor => (cols, rows),
+    };
+    check_ld("lda", inner.max(1), lda)?;
+    check_len("a", strided_len(outer, inner, lda), a.len())?;
+    check_indices("i", &[i], rows)?;
+    check_indices("j", &[j], rows)?;
+    let (start_i, start_j, inc) = match order {
+        RowColMajor::RowMajor => (i * lda, j * lda, 1),
+        RowColMajor::ColMajor => (i, j, lda),
+    };
+    swap_strided(a, cols, start_i, start_j, inc)
+}
+
+/// Swaps columns `i` and `j` of a `rows x cols` matrix in place with `cblas_sswap`.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `a`: The matrix.
+/// - `rows`, `cols`: The shape of the matrix.
+/// - `lda`: The leading dimension of `a`.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `i`, `j`: The columns to exchange. Swapping a column with itself does nothing.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is below its minimum,
+/// [`BlasError::BufferTooSmall`] if `a` is too short, and [`BlasError::IndexOutOfBounds`] if `i` or `j` is
+/// not less than `cols`.
+pub fn swap_columns(
+    a: &mut [f32],
+    rows: usize,
+    cols: usize,
+    lda: usize,
+    order: RowColMajor,
+    i: usize,
+    j: usize,
+) -> Result<(), BlasError> {
+    let (outer, inner) = match order {
+        RowColMajor::RowMajor => (rows, cols),
+        RowColMajor::ColMajor => (cols, rows),
+    };
+    check_ld("lda", inner.max(1), lda)?;
+    check_len("a", strided_len(outer, inner, lda), a.len())?;
+    check_indices("i", &[i], cols)?;
+    check_indices("j", &[j], cols)?;
+    let (start_i, start_j, inc) = match order {
+        RowColMajor::RowMajor => (i, j, lda),
+        RowColMajor::ColMajor => (i * lda, j * lda, 1),
+    };
+    swap_strided(a, rows, start_i, start_j, inc)
+}
+
+/// Swaps the `len`-element lines of `a` starting at `start_i` and `start_j`, both with stride `inc`.
+/// Shared by [`swap_rows`] and [`swap_columns`], which have already checked that both lines lie in `a`.
+fn swap_strided(
+    a: &mut [f32],
+    len: usize,
+    start_i: usize,
+    start_j: usize,
+    inc: usize,
+) -> Result<(), BlasError> {
+    if start_i == start_j || len == 0 {
+        return Ok(());
+    }
+    let ptr = a.as_mut_ptr();
+    let inc = to_c_int(inc, "lda")?;
+    unsafe {
+        traced!(
+            "cblas_sswap",
+            [len],
+            vector_f32::swap(
+                to_c_int(len, "len")?,
+                ptr.add(start_i),
+                inc,
+                ptr.add(start_j),
+                inc
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Computes the residual `r = b - A * x` together with its Euclidean norm `||r||`.
+///
+/// # Precision
+/// This function operates on single-precision floating-point (`f32`) numbers.
+///
+/// # Parameters
+/// - `a`: The matrix `A`, of shape `m x n`.
+/// - `m`, `n`: The number of rows and columns of `A`.
+/// - `lda`: The leading dimension of `a`.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `x`: The current iterate, of length `n`.
+/// - `b`: The right-hand side, of length `m`.
+///
+/// # Returns
+/// The residual `r`, of length `m`, and `||r||`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is below its minimum,
+/// [`BlasError::BufferTooSmall`] if `a` is too short, and [`BlasError::DimensionMismatch`] if `x` or `b`
+/// has the wrong length.
+///
+/// # Discussion
+/// `b` is copied once and `cblas_sgemv` runs with `alpha = -1`, `beta = 1` into the copy, so the residual
+/// is formed in a single pass over `A` without a temporary for `A * x`. The norm is then taken with
+/// `cblas_snrm2`, which scales to avoid overflow. This is the step conjugate-gradient and GMRES loops
+/// repeat every iteration.
+pub fn gemv_residual_norm(
+    a: &[f32],
+    m: usize,
+    n: usize,
+    lda: usize,
+    order: RowColMajor,
+    x: &[f32],
+    b: &[f32],
+) -> Result<(Vec<f32>, f32), BlasError> {
+    let (outer, inner) = match order {
+        RowColMajor::RowMajor => (m, n),
+        RowColMajor::ColMajor => (n, m),
+    };
+    check_ld("lda", inner.max(1), lda)?;
+    check_len("a", strided_len(outer, inner, lda), a.len())?;
+    check_shape("x", n, x.len())?;
+    check_shape("b", m, b.len())?;
+    let mut r = b.to_vec();
+    if m == 0 {
+        return Ok((r, 0.0));
+    }
+    unsafe {
+        traced!(
+            "cblas_sgemv",
+            [m, n],
+            mat_vec_mul(
+                order,
+                TransposeMode::NoTrans,
+                to_c_int(m, "m")?,
+                to_c_int(n, "n")?,
+                -1.0,
+                a.as_ptr(),
+                to_c_int(lda, "lda")?,
+                x.as_ptr(),
+                1,
+                1.0,
+                r.as_mut_ptr(),
+                1,
+            )
+        );
+        let norm = traced!(
+            "cblas_snrm2",
+            [m],
+            vector_f32::norm2(to_c_int(m, "m")?, r.as_ptr(), 1)
+        );
+        Ok((r, norm))
+    }
+}
+
+/// Edge length of the square tiles [`transpose_into`] copies, small enough that a source and a
+/// destination tile stay in L1 together.
+const TRANSPOSE_BLOCK: usize = 32;
+
+/// Writes the transpose of the row-major `rows x cols` matrix `a` into `out`, as a row-major
+/// `cols x rows` matrix.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `a` or `out` does not hold exactly `rows * cols` elements.
+///
+/// # Discussion
+/// The copy walks the matrix in square tiles, so both the reads and the strided writes stay within a few
+/// cache lines at a time instead of striding through all of `out` for every row of `a`.
+pub fn transpose_into(
+    a: &[f32],
+    rows: usize,
+    cols: usize,
+    out: &mut [f32],
+) -> Result<(), BlasError> {
+    check_shape("a", rows * cols, a.len())?;
+    check_shape("out", rows * cols, out.len())?;
+    for i0 in (0..rows).step_by(TRANSPOSE_BLOCK) {
+        for j0 in (0..cols).step_by(TRANSPOSE_BLOCK) {
+            for i in i0..rows.min(i0 + TRANSPOSE_BLOCK) {
+                for j in j0..cols.min(j0 + TRANSPOSE_BLOCK) {
+                    out[j * rows + i] = a[i * cols + j];
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Computes `C = op(A) * op(B)` by transposing the operands in memory and calling `cblas_sgemm` with
+/// `NoTrans` on both, overwriting `C`.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `a`: The matrix `A`, row-major and tightly packed: `m x k`, or `k x m` when `trans_a` transposes it.
+/// - `b`: The matrix `B`, row-major and tightly packed: `k x n`, or `n x k` when `trans_b` transposes it.
+/// - `c`: The `m x n` result `C`, row-major and tightly packed.
+/// - `dims`: The product's dimensions `(m, n, k)`.
+/// - `trans_a`, `trans_b`: Whether `A` and `B` are used as stored or transposed. Use `TransposeMode`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `a`, `b` or `c` does not hold the number of elements
+/// `dims` implies.
+///
+/// # Discussion
+/// The result is the same as passing the transpose flags to `cblas_sgemm`. What differs is the memory
+/// access: Accelerate's transposed kernels can be slower than the `NoTrans` one for some shapes, and
+/// for those an explicit [`transpose_into`] of each transposed operand (one extra pass and allocation
+/// of `m * k` or `k * n` elements) is cheaper than the slow kernel. Which route wins depends on the
+/// shape and the machine; `benches/transpose_gemm.rs` compares the two.
+pub fn sgemm_materialized_transpose(
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+    dims: (usize, usize, usize),
+    trans_a: TransposeMode,
+    trans_b: TransposeMode,
+) -> Result<(), BlasError> {
+    let (m, n, k) = dims;
+    check_shape("a", m * k, a.len())?;
+    check_shape("b", k * n, b.len())?;
+    check_shape("c", m * n, c.len())?;
+    let a_t;
+    let a = match trans_a {
+        TransposeMode::NoTrans => a,
+        _ => {
+            let mut t = vec![0.0; m * k];
+            transpose_into(a, k, m, &mut t)?;
+            a_t = t;
+            &a_t
+        }
+    };
+    let b_t;
+    let b = match trans_b {
+        TransposeMode::NoTrans => b,
+        _ => {
+            let mut t = vec![0.0; k * n];
+            transpose_into(b, n, k, &mut t)?;
+            b_t = t;
+            &b_t
+        }
+    };
+    unsafe {
+        traced!(
+            "cblas_sgemm",
+            [m, n, k],
+            mat_mul(
+                RowColMajor::RowMajor,
+                TransposeMode::NoTrans,
+                TransposeMode::NoTrans,
+                to_c_int(m, "m")?,
+                to_c_int(n, "n")?,
+                to_c_int(k, "k")?,
+                1.0,
+                a.as_ptr(),
+                to_c_int(k.max(1), "lda")?,
+                b.as_ptr(),
+                to_c_int(n.max(1), "ldb")?,
+                0.0,
+                c.as_mut_ptr(),
+                to_c_int(n.max(1), "ldc")?,
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Computes `y = A * x` as a sequence of independent `cblas_sgemv` calls, one per block of `block_rows`
+/// rows of `A`.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `a`: The `m x n` matrix `A`.
+/// - `m`, `n`: The number of rows and columns of `A`.
+/// - `lda`: The leading dimension of `a`.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `x`: The input vector, of length `n`.
+/// - `y`: The output vector, of length `m`, overwritten with `A * x`.
+/// - `block_rows`: The number of rows per block. The last block takes whatever remains.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is below its minimum,
+/// [`BlasError::BufferTooSmall`] if `a` is too short, and [`BlasError::DimensionMismatch`] if `x` is not of
+/// length `n` or `y` not of length `m`. A zero `block_rows` is also reported as
+/// [`BlasError::DimensionMismatch`], against the smallest usable block of one row.
+///
+/// # Discussion
+/// Blocks are cut from `y`, so together they cover exactly rows `0..m`. Each block of `A` is a sub-matrix
+/// with the same leading dimension, starting `r0 * lda` elements in (row-major) or `r0` elements in
+/// (column-major) for a block starting at row `r0`, so nothing is copied. With the `rayon` feature the
+/// blocks run on the rayon thread pool; without it they run in order on the calling thread. Either way
+/// the result is the same as a single `cblas_sgemv`. This is meant for very tall matrices, where choosing
+/// the granularity explicitly can beat Accelerate's own threading.
+#[allow(clippy::too_many_arguments)]
+pub fn sgemv_blocked(
+    a: &[f32],
+    m: usize,
+    n: usize,
+    lda: usize,
+    order: RowColMajor,
+    x: &[f32],
+    y: &mut [f32],
+    block_rows: usize,
+) -> Result<(), BlasError> {
+    if block_rows == 0 {
+        return Err(BlasError::DimensionMismatch {
+            which: "block_rows",
+            expected: 1,
+            got: 0,
+        });
+    }
+    let (outer, inner) = match order {
+        RowColMajor::RowMajor => (m, n),
+        RowColMajor::ColMajor => (n, m),
+    };
+    check_ld("lda", inner.max(1), lda)?;
+    check_len("a", strided_len(outer, inner, lda), a.len())?;
+    check_shape("x", n, x.len())?;
+    check_shape("y", m, y.len())?;
+    if n == 0 {
+        y.iter_mut().for_each(|v| *v = 0.0);
+        return Ok(());
+    }
+    // Every block has at most `m` rows, so checking `m` once covers them all.
+    to_c_int(m, "m")?;
+    let (n_c, lda_c) = (to_c_int(n, "n")?, to_c_int(lda, "lda")?);
+    let run = |(block, y_block): (usize, &mut [f32])| {
+        let r0 = block * block_rows;
+        let rows = y_block.len();
+        let offset = match order {
+            RowColMajor::RowMajor => r0 * lda,
+            RowColMajor::ColMajor => r0,
+        };
+        unsafe {
+            traced!(
+                "cblas_sgemv",
+                [rows, n],
+                mat_vec_mul(
+                    order,
+                    TransposeMode::NoTrans,
+                    rows as c_int,
+                    n_c,
+                    1.0,
+                    a[offset..].as_ptr(),
+                    lda_c,
+                    x.as_ptr(),
+                    1,
+                    0.0,
+                    y_block.as_mut_ptr(),
+                    1,
+                )
+            );
+        }
+    };
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        y.par_chunks_mut(block_rows).enumerate().for_each(run);
+    }
+    #[cfg(not(feature = "rayon"))]
+    y.chunks_mut(block_rows).enumerate().for_each(run);
+    Ok(())
+}
+
+/// Computes `C = alpha * op(A) * op(B) + beta * C` with `cblas_sgemm`, after validating every operand.
+///
+/// This is the checked counterpart of [`mat_mul`].
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `trans_a`, `trans_b`: Whether `A` and `B` are used as stored or transposed. Use `TransposeMode`.
+/// - `m`, `n`, `k`: `op(A)` is `m x k`, `op(B)` is `k x n` and `C` is `m x n`.
+/// - `alpha`: The scalar applied to the product.
+/// - `a`, `lda`: The matrix `A` and its leading dimension.
+/// - `b`, `ldb`: The matrix `B` and its leading dimension.
+/// - `beta`: The scalar applied to `C` before accumulation. With `beta = 0`, `C` need not be initialized.
+/// - `c`, `ldc`: The matrix `C`, overwritten with the result, and its leading dimension.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda`, `ldb` or `ldc` is below its minimum and
+/// [`BlasError::BufferTooSmall`] if `a`, `b` or `c` is too short.
+#[allow(clippy::too_many_arguments)]
+pub fn sgemm(
+    major: RowColMajor,
+    trans_a: TransposeMode,
+    trans_b: TransposeMode,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: f32,
+    a: &[f32],
+    lda: usize,
+    b: &[f32],
+    ldb: usize,
+    beta: f32,
+    c: &mut [f32],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let shapes = gemm_shapes(trans_a, trans_b, m, n, k);
+    for (which_ld, which, (rows, cols), ld, len) in [
+        ("lda", "a", (shapes.a_rows, shapes.a_cols), lda, a.len()),
+        ("ldb", "b", (shapes.b_rows, shapes.b_cols), ldb, b.len()),
+        ("ldc", "c", (shapes.c_rows, shapes.c_cols), ldc, c.len()),
+    ] {
+        let (outer, inner) = match major {
+            RowColMajor::RowMajor => (rows, cols),
+            RowColMajor::ColMajor => (cols, rows),
+        };
+        check_ld(which_ld, inner.max(1), ld)?;
+        check_len(which, strided_len(outer, inner, ld), len)?;
+    }
+    unsafe {
+        traced!(
+            "cblas_sgemm",
+            [m, n, k],
+            mat_mul(
+                major,
+                trans_a,
+                trans_b,
+                to_c_int(m, "m")?,
+                to_c_int(n, "n")?,
+                to_c_int(k, "k")?,
+                alpha,
+                a.as_ptr(),
+                to_c_int(lda, "lda")?,
+                b.as_ptr(),
+                to_c_int(ldb, "ldb")?,
+                beta,
+                c.as_mut_ptr(),
+                to_c_int(ldc, "ldc")?,
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Runs [`sgemm`] and also returns how long the call took.
+///
+/// The plain [`sgemm`] does no timing at all; this variant wraps it in [`timing::timed`](crate::timing::timed),
+/// so the cost of one call in a real pipeline can be read without setting up a benchmark harness. The
+/// duration covers validation as well as the `cblas_sgemm` call, and is measured even when validation
+/// fails.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+#[allow(clippy::too_many_arguments)]
+pub fn sgemm_timed(
+    major: RowColMajor,
+    trans_a: TransposeMode,
+    trans_b: TransposeMode,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: f32,
+    a: &[f32],
+    lda: usize,
+    b: &[f32],
+    ldb: usize,
+    beta: f32,
+    c: &mut [f32],
+    ldc: usize,
+) -> (Result<(), BlasError>, Duration) {
+    timed(|| {
+        sgemm(
+            major, trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+        )
+    })
+}
+
+/// Below this many outer products, [`accumulate_outer_products`] issues one `cblas_sger` per pair instead of
+/// gathering the vectors for a single `cblas_sgemm`.
+const OUTER_PRODUCT_GEMM_MIN: usize = 4;
+
+/// Accumulates a sum of outer products into `A` in place: `A += alpha * sum_k xs[k] * ys[k]^T`.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `xs`: The `K` left vectors, each of length `m`.
+/// - `ys`: The `K` right vectors, each of length `n`.
+/// - `a`: The `m x n` matrix `A`, updated in place.
+/// - `m`, `n`: The number of rows and columns of `A`.
+/// - `lda`: The leading dimension of `a`.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `alpha`: The scalar applied to the sum.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is below its minimum,
+/// [`BlasError::BufferTooSmall`] if `a` is too short, and [`BlasError::DimensionMismatch`] if `xs` and `ys`
+/// differ in count or any vector has the wrong length.
+///
+/// # Discussion
+/// The sum is the matrix product `X * Y^T` of the `m x K` matrix `X` whose columns are `xs` and the `n x K`
+/// matrix `Y` whose columns are `ys`. Rank-1 updates are memory bound, since each `cblas_sger` streams all
+/// of `A` for `m + n` inputs, whereas one `cblas_sgemm` with `beta = 1` reads and writes `A` once and runs
+/// at matrix-multiply speed. Gathering the vectors costs one copy of `(m + n) * K` elements, which pays for
+/// itself from a handful of products on, so for fewer than four the function falls back to `cblas_sger`.
+#[allow(clippy::too_many_arguments)]
+pub fn accumulate_outer_products(
+    xs: &[&[f32]],
+    ys: &[&[f32]],
+    a: &mut [f32],
+    m: usize,
+    n: usize,
+    lda: usize,
+    order: RowColMajor,
+    alpha: f32,
+) -> Result<(), BlasError> {
+    let (outer, inner) = match order {
+        RowColMajor::RowMajor => (m, n),
+        RowColMajor::ColMajor => (n, m),
+    };
+    check_ld("lda", inner.max(1), lda)?;
+    check_len("a", strided_len(outer, inner, lda), a.len())?;
+    check_shape("ys", xs.len(), ys.len())?;
+    for (x, y) in xs.iter().zip(ys) {
+        check_shape("xs", m, x.len())?;
+        check_shape("ys", n, y.len())?;
+    }
+    let k = xs.len();
+    if m == 0 || n == 0 || k == 0 {
+        return Ok(());
+    }
+    let (m_c, n_c, lda_c) = (to_c_int(m, "m")?, to_c_int(n, "n")?, to_c_int(lda, "lda")?);
+    if k < OUTER_PRODUCT_GEMM_MIN {
+        for (x, y) in xs.iter().zip(ys) {
+            unsafe {
+                traced!(
+                    "cblas_sger",
+                    [m, n],
+                    mat_rank1_update(
+                        order,
+                        m_c,
+                        n_c,
+                        alpha,
+                        x.as_ptr(),
+                        1,
+                        y.as_ptr(),
+                        1,
+                        a.as_mut_ptr(),
+                        lda_c
+                    )
+                );
+            }
+        }
+        return Ok(());
+    }
+    // Laid end to end, the vectors form X and Y column-major (leading dimensions m and n), which read
+    // row-major are X^T and Y^T.
+    let x_cols = xs.concat();
+    let y_cols = ys.concat();
+    let (trans_x, trans_y) = match order {
+        RowColMajor::ColMajor => (TransposeMode::NoTrans, TransposeMode::Trans),
+        RowColMajor::RowMajor => (TransposeMode::Trans, TransposeMode::NoTrans),
+    };
+    unsafe {
+        traced!(
+            "cblas_sgemm",
+            [m, n, k],
+            mat_mul(
+                order,
+                trans_x,
+                trans_y,
+                m_c,
+                n_c,
+                to_c_int(k, "k")?,
+                alpha,
+                x_cols.as_ptr(),
+                m_c,
+                y_cols.as_ptr(),
+                n_c,
+                1.0,
+                a.as_mut_ptr(),
+                lda_c,
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Computes selected eigenvalues, and optionally eigenvectors, of a symmetric matrix with `ssyevr`.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `job`: Whether eigenvectors are computed. Use `EigenJob`.
+/// - `tri`: The triangle of `a` that holds the matrix; the other one is not read. Use `UpOrLowTriangle`.
+/// - `a`: The `n x n` symmetric matrix. It is copied, so the caller's buffer is left intact.
+/// - `n`: The order of the matrix.
+/// - `lda`: The leading dimension of `a`, at least `n`.
+/// - `order`: The storage order of `a` and of the returned eigenvectors. Use `RowColMajor`.
+/// - `range`: Which eigenvalues to compute. Use `EigenRange`.
+/// - `abstol`: The absolute tolerance for the eigenvalues. `None` selects `2 * f32::MIN_POSITIVE`, which
+///   LAPACK recommends for the most accurate results; a larger value trades accuracy for speed.
+///
+/// # Returns
+/// The selected eigenvalues in ascending order, and when `job` is `ValuesAndVectors`, the matching
+/// eigenvectors as the columns of a tightly packed `n x m` matrix stored in `order`, where `m` is the
+/// number of eigenvalues found.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n`, [`BlasError::BufferTooSmall`] if `a` is too
+/// short, [`BlasError::IndexOutOfBounds`] if an index range reaches past `n`, and
+/// [`BlasError::NoConvergence`] if `ssyevr` reports an internal error.
+///
+/// # Discussion
+/// Unlike a full `ssyev`, `ssyevr` only computes the eigenpairs asked for, after reducing `A` to
+/// tridiagonal form. For the few smallest or largest eigenpairs of a large matrix, as in spectral
+/// clustering or the lowest vibration modes, that is much less work than computing all `n`. A row-major
+/// symmetric matrix is read as the column-major storage of its transpose, which is the same matrix with the
+/// other triangle filled, so `tri` is flipped for LAPACK instead of copying into column-major order. An
+/// empty index range or a value interval with `upper <= lower` returns no eigenvalues without calling
+/// LAPACK.
+#[allow(clippy::too_many_arguments)]
+pub fn ssyevr(
+    job: EigenJob,
+    tri: UpOrLowTriangle,
+    a: &[f32],
+    n: usize,
+    lda: usize,
+    order: RowColMajor,
+    range: EigenRange<f32>,
+    abstol: Option<f32>,
+) -> Result<(Vec<f32>, Option<Vec<f32>>), BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    let vectors = matches!(job, EigenJob::ValuesAndVectors);
+    let empty = |vectors: bool| (Vec::new(), if vectors { Some(Vec::new()) } else { None });
+    let (vl, vu, il, iu) = match &range {
+        EigenRange::All => (0.0, 0.0, 1, 1),
+        EigenRange::Values(lower, upper) => {
+            if lower.is_nan() || upper.is_nan() || lower >= upper {
+                return Ok(empty(vectors));
+            }
+            (*lower, *upper, 1, 1)
+        }
+        EigenRange::Indices(indices) => {
+            if indices.start >= indices.end {
+                return Ok(empty(vectors));
+            }
+            check_indices("range", &[indices.end - 1], n)?;
+            (
+                0.0,
+                0.0,
+                to_c_int(indices.start + 1, "il")?,
+                to_c_int(indices.end, "iu")?,
+            )
+        }
+    };
+    if n == 0 {
+        return Ok(empty(vectors));
+    }
+    let uplo = match (tri, order) {
+        (UpOrLowTriangle::Upper, RowColMajor::ColMajor)
+        | (UpOrLowTriangle::Lower, RowColMajor::RowMajor) => b'U',
+        _ => b'L',
+    } as c_char;
+    let mut a = a.to_vec();
+    let (n_c, lda_c) = (to_c_int(n, "n")?, to_c_int(lda, "lda")?);
+    let ldz = if vectors { n } else { 1 };
+    let ldz_c = to_c_int(ldz, "ldz")?;
+    let abstol = abstol.unwrap_or(2.0 * f32::MIN_POSITIVE);
+    let (jobz, range_c) = (job.to_lapack(), range.to_lapack());
+    let mut w = vec![0.0; n];
+    let mut z = vec![0.0; if vectors { n * n } else { 1 }];
+    let mut isuppz = vec![0; 2 * n];
+    let mut found: c_int = 0;
+    let mut info: c_int = 0;
+    let (mut work_size, mut iwork_size) = (0.0, 0);
+    unsafe {
+        traced!(
+            "ssyevr_",
+            [n],
+            symmetric_eigen_range(
+                &jobz,
+                &range_c,
+                &uplo,
+                &n_c,
+                a.as_mut_ptr(),
+                &lda_c,
+                &vl,
+                &vu,
+                &il,
+                &iu,
+                &abstol,
+                &mut found,
+                w.as_mut_ptr(),
+                z.as_mut_ptr(),
+                &ldz_c,
+                isuppz.as_mut_ptr(),
+                &mut work_size,
+                &-1,
+                &mut iwork_size,
+                &-1,
+                &mut info,
+            )
+        );
+    }
+    let mut work = vec![0.0; (work_size as usize).max(1)];
+    let mut iwork = vec![0; (iwork_size as usize).max(1)];
+    let (lwork, liwork) = (
+        to_c_int(work.len(), "lwork")?,
+        to_c_int(iwork.len(), "liwork")?,
+    );
+    unsafe {
+        traced!(
+            "ssyevr_",
+            [n],
+            symmetric_eigen_range(
+                &jobz,
+                &range_c,
+                &uplo,
+                &n_c,
+                a.as_mut_ptr(),
+                &lda_c,
+                &vl,
+                &vu,
+                &il,
+                &iu,
+                &abstol,
+                &mut found,
+                w.as_mut_ptr(),
+                z.as_mut_ptr(),
+                &ldz_c,
+                isuppz.as_mut_ptr(),
+                work.as_mut_ptr(),
+                &lwork,
+                iwork.as_mut_ptr(),
+                &liwork,
+                &mut info,
+            )
+        );
+    }
+    // Every argument was validated above, so only an internal failure (info > 0) can be reported.
+    if info > 0 {
+        return Err(BlasError::NoConvergence {
+            unconverged: info as usize,
+        });
+    }
+    let m = found as usize;
+    w.truncate(m);
+    if !vectors {
+        return Ok((w, None));
+    }
+    z.truncate(n * m);
+    if let RowColMajor::RowMajor = order {
+        // LAPACK returns the eigenvectors column-major; lay them out row-major as columns of an n x m matrix.
+        let mut row_major = vec![0.0; n * m];
+        for j in 0..m {
+            for i in 0..n {
+                row_major[i * m + j] = z[j * n + i];
+            }
+        }
+        z = row_major;
+    }
+    Ok((w, Some(z)))
+}
+
+/// The `min(m, n)` singular values of the row-major `m x n` matrix `a`, in descending order, computed with
+/// `sgesvd` on a copy of `a`.
+fn singular_values(a: &[f32], m: usize, n: usize) -> Result<Vec<f32>, BlasError> {
+    check_shape("a", m * n, a.len())?;
+    let k = m.min(n);
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+    let mut a = a.to_vec();
+    let mut s = vec![0.0; k];
+    // The row-major `m x n` buffer is the column-major `n x m` transpose, which is what LAPACK reads. A
+    // matrix and its transpose share their singular values.
+    let job = SvdJob::None.to_lapack();
+    let (m_c, n_c, one) = (to_c_int(n, "n")?, to_c_int(m, "m")?, 1);
+    let mut gesvd = |work: *mut f32, lwork: c_int| {
+        let mut info: c_int = 0;
+        unsafe {
+            traced!(
+                "sgesvd_",
+                [n, m],
+                singular_value_decomposition(
+                    &job,
+                    &job,
+                    &m_c,
+                    &n_c,
+                    a.as_mut_ptr(),
+                    &m_c,
+                    s.as_mut_ptr(),
+                    std::ptr::null_mut(),
+                    &one,
+                    std::ptr::null_mut(),
+                    &one,
+                    work,
+                    &lwork,
+                    &mut info,
+                )
+            );
+        }
+        info
+    };
+    let mut query = 0.0;
+    gesvd(&mut query, -1);
+    let mut work = vec![0.0; (query as usize).max(1)];
+    let info = gesvd(work.as_mut_ptr(), to_c_int(work.len(), "lwork")?);
+    // Every argument was validated above, so only a convergence failure (info > 0) can be reported.
+    if info > 0 {
+        return Err(BlasError::NoConvergence {
+            unconverged: info as usize,
+        });
+    }
+    Ok(s)
+}
+
+/// Computes the numerical rank of an `m x n` matrix: the number of singular values above a tolerance.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `a`: The matrix, row-major and tightly packed. A column-major buffer can be passed with `m` and `n`
+///   swapped, since it then holds the transpose, which has the same singular values.
+/// - `m`, `n`: The shape of the matrix.
+/// - `tol`: The threshold a singular value must exceed to count. `None` selects
+///   `max(m, n) * f32::EPSILON * sigma_max`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `a` does not hold `m * n` elements, and
+/// [`BlasError::NoConvergence`] if [`singular_value_decomposition`] (`sgesvd`) fails to converge.
+///
+/// # Discussion
+/// See [`matrix_f64::matrix_rank`](crate::matrix_f64::matrix_rank) for how the default tolerance is chosen.
+pub fn matrix_rank(a: &[f32], m: usize, n: usize, tol: Option<f32>) -> Result<usize, BlasError> {
+    let s = singular_values(a, m, n)?;
+    let tol = match (tol, s.first()) {
+        (Some(tol), _) => tol,
+        (None, Some(&max)) => m.max(n) as f32 * f32::EPSILON * max,
+        (None, None) => return Ok(0),
+    };
+    Ok(s.iter().filter(|&&sigma| sigma > tol).count())
 }
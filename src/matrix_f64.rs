@@ -1,5 +1,15 @@
-use crate::constants::{IsDiagUnit, MultiplyOrder, RowColMajor, TransposeMode, UpOrLowTriangle};
-use std::ffi::{c_double, c_int};
+use crate::constants::{
+    gemm_shapes, EigenJob, EigenRange, IsDiagUnit, MultiplyOrder, RowColMajor, SvdJob,
+    TransposeMode, UpOrLowTriangle,
+};
+use crate::error::BlasError;
+use crate::givens;
+use crate::validate::{
+    check_inc, check_indices, check_ld, check_len, check_shape, strided_len, to_c_int, vec_len,
+};
+use crate::vector_f64;
+use crate::workspace::{LapackRoutine, LapackWorkspace};
+use std::ffi::{c_char, c_double, c_int};
 
 #[link(name = "Accelerate", kind = "framework")]
 extern "C" {
@@ -768,4 +778,1848 @@ extern "C" {
         x: *mut c_double,       // Pointer to vector X
         inc_x: c_int,           // Increment for vector X
     );
+
+    /// Computes the Cholesky factorization of a symmetric positive-definite band matrix (LAPACK `dpbtrf`).
+    ///
+    /// # Precision
+    /// This function operates on double-precision (`f64`) numbers.
+    ///
+    /// # Parameters
+    /// - `uplo`: `b'U'` if `ab` holds the upper triangle of the band, `b'L'` for the lower.
+    /// - `n`: The order of the matrix `A`.
+    /// - `kd`: The number of super-diagonals (`'U'`) or sub-diagonals (`'L'`) of `A`.
+    /// - `ab`: The band of `A` in LAPACK band storage, column-major: `A(i, j)` is at `ab[kd + i - j + j * ldab]`
+    ///   for `'U'` and at `ab[i - j + j * ldab]` for `'L'`. Overwritten with the Cholesky factor.
+    /// - `ldab`: The leading dimension of `ab`, at least `kd + 1`.
+    /// - `info`: Set to `0` on success, `-i` if argument `i` was invalid, and `i` if the leading minor of
+    ///   order `i` is not positive definite.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function using the Fortran calling convention: every argument is passed by
+    /// pointer. The caller must ensure that `ab` is valid for `ldab * n` elements.
+    #[link_name = "dpbtrf_"]
+    pub fn band_cholesky(
+        uplo: *const c_char,
+        n: *const c_int,
+        kd: *const c_int,
+        ab: *mut c_double,
+        ldab: *const c_int,
+        info: *mut c_int,
+    );
+
+    /// Solves `A * X = B` using the band Cholesky factor computed by [`band_cholesky`] (LAPACK `dpbtrs`).
+    ///
+    /// # Precision
+    /// This function operates on double-precision (`f64`) numbers.
+    ///
+    /// # Parameters
+    /// - `uplo`, `n`, `kd`, `ab`, `ldab`: As passed to, and returned by, [`band_cholesky`].
+    /// - `nrhs`: The number of right-hand sides, i.e. columns of `B`.
+    /// - `b`: The right-hand sides, column-major `n x nrhs`. Overwritten with the solution `X`.
+    /// - `ldb`: The leading dimension of `b`, at least `n`.
+    /// - `info`: Set to `0` on success and `-i` if argument `i` was invalid.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function using the Fortran calling convention: every argument is passed by
+    /// pointer. The caller must ensure that `ab` and `b` are valid for `ldab * n` and `ldb * nrhs` elements.
+    #[link_name = "dpbtrs_"]
+    pub fn band_cholesky_solve(
+        uplo: *const c_char,
+        n: *const c_int,
+        kd: *const c_int,
+        nrhs: *const c_int,
+        ab: *const c_double,
+        ldab: *const c_int,
+        b: *mut c_double,
+        ldb: *const c_int,
+        info: *mut c_int,
+    );
+
+    /// Computes the singular value decomposition `A = U * S * V^T` of a general matrix (LAPACK `dgesvd`).
+    ///
+    /// # Precision
+    /// This function operates on double-precision (`f64`) numbers.
+    ///
+    /// # Parameters
+    /// - `jobu`, `jobvt`: `b'A'`, `b'S'`, `b'O'` or `b'N'`, selecting all, the leading `min(m, n)`, an
+    ///   overwrite of `a`, or none of the columns of `U` (rows of `V^T`). Use `SvdJob::to_lapack`.
+    /// - `m`, `n`: The number of rows and columns of `A`.
+    /// - `a`: The matrix `A`, column-major `m x n`. Destroyed on return (or overwritten with `U` or `V^T`
+    ///   for `'O'`).
+    /// - `lda`: The leading dimension of `a`, at least `m`.
+    /// - `s`: The `min(m, n)` singular values, returned in descending order.
+    /// - `u`, `ldu`: The left singular vectors, not referenced when `jobu` is `'N'` or `'O'`.
+    /// - `vt`, `ldvt`: The right singular vectors, not referenced when `jobvt` is `'N'` or `'O'`.
+    /// - `work`, `lwork`: Workspace of `lwork` elements. With `lwork = -1` only the optimal size is
+    ///   written to `work[0]`.
+    /// - `info`: Set to `0` on success, `-i` if argument `i` was invalid, and `i` if `i` superdiagonals of
+    ///   the intermediate bidiagonal form did not converge.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function using the Fortran calling convention: every argument is passed by
+    /// pointer. The caller must ensure that every buffer is valid for the sizes the job flags imply.
+    #[link_name = "dgesvd_"]
+    pub fn singular_value_decomposition(
+        jobu: *const c_char,
+        jobvt: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        a: *mut c_double,
+        lda: *const c_int,
+        s: *mut c_double,
+        u: *mut c_double,
+        ldu: *const c_int,
+        vt: *mut c_double,
+        ldvt: *const c_int,
+        work: *mut c_double,
+        lwork: *const c_int,
+        info: *mut c_int,
+    );
+
+    /// Computes selected eigenvalues and, optionally, eigenvectors of a symmetric matrix using the Relatively
+    /// Robust Representations algorithm (LAPACK `dsyevr`).
+    ///
+    /// # Precision
+    /// This function operates on double-precision (`f64`) numbers.
+    ///
+    /// # Parameters
+    /// - `jobz`: `b'N'` for eigenvalues only, `b'V'` for eigenvectors too. Use `EigenJob::to_lapack`.
+    /// - `range`: `b'A'` for all eigenvalues, `b'V'` for those in `(vl, vu]`, `b'I'` for the `il`-th through
+    ///   `iu`-th. Use `EigenRange::to_lapack`.
+    /// - `uplo`: `b'U'` or `b'L'`, the triangle of `a` that is read.
+    /// - `n`: The order of the matrix `A`.
+    /// - `a`: The symmetric matrix `A`, column-major. The `uplo` triangle is destroyed on return.
+    /// - `lda`: The leading dimension of `a`, at least `n`.
+    /// - `vl`, `vu`: The value bounds, used only when `range` is `'V'`.
+    /// - `il`, `iu`: The one-based, inclusive index bounds, used only when `range` is `'I'`.
+    /// - `abstol`: The absolute error tolerance for the eigenvalues. `2 * dlamch('S')` gives the most
+    ///   accurate results.
+    /// - `m`: Set to the number of eigenvalues found.
+    /// - `w`: The `m` eigenvalues, in ascending order, in the first `m` of its `n` elements.
+    /// - `z`, `ldz`: The `m` eigenvectors as the columns of a column-major `n x m` matrix, not referenced
+    ///   when `jobz` is `'N'`. `ldz` is at least `n` when they are computed and at least `1` otherwise.
+    /// - `isuppz`: The `2 * max(1, m)` support indices of the eigenvectors.
+    /// - `work`, `lwork`, `iwork`, `liwork`: Real and integer workspace. With `lwork = -1` or `liwork = -1`
+    ///   only the optimal sizes are written to `work[0]` and `iwork[0]`.
+    /// - `info`: Set to `0` on success, `-i` if argument `i` was invalid, and a positive value on an internal
+    ///   error.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function using the Fortran calling convention: every argument is passed by
+    /// pointer. The caller must ensure that every buffer is valid for the sizes described above.
+    #[link_name = "dsyevr_"]
+    pub fn symmetric_eigen_range(
+        jobz: *const c_char,
+        range: *const c_char,
+        uplo: *const c_char,
+        n: *const c_int,
+        a: *mut c_double,
+        lda: *const c_int,
+        vl: *const c_double,
+        vu: *const c_double,
+        il: *const c_int,
+        iu: *const c_int,
+        abstol: *const c_double,
+        m: *mut c_int,
+        w: *mut c_double,
+        z: *mut c_double,
+        ldz: *const c_int,
+        isuppz: *mut c_int,
+        work: *mut c_double,
+        lwork: *const c_int,
+        iwork: *mut c_int,
+        liwork: *const c_int,
+        info: *mut c_int,
+    );
+}
+
+/// Solves a triangular banded system `op(A) * x = b` in place, after validating the band layout.
+///
+/// This is the checked counterpart of [`tri_band_solve`] (`cblas_dtbsv`).
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `tri`: Specifies whether `A` is upper or lower triangular. Use `UpOrLowTriangle`.
+/// - `trans_a`: Specifies whether to use matrix `A` ('N') or the transpose of `A` ('T'). Use `TransposeMode`.
+/// - `diag`: Specifies whether `A` is unit triangular ('U') or not ('N'). Use `IsDiagUnit`.
+/// - `n`: The order of the matrix `A`.
+/// - `k`: The number of super-diagonals (upper) or sub-diagonals (lower) of `A`.
+/// - `a`: The band storage of `A`: `n` columns (column-major) or rows (row-major) of `lda` elements each.
+/// - `lda`: The leading dimension of the band storage, must be at least `k + 1`.
+/// - `x`: On entry, the right-hand side `b`. On return, overwritten with the solution `x`.
+/// - `inc_x`: The stride within `x`. For example, if `inc_x` is 7, every 7th element is used.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < k + 1`, and [`BlasError::BufferTooSmall`]
+/// if `a` or `x` is shorter than `n`, `lda` and `inc_x` require.
+/// Returns [`BlasError::ZeroIncrement`] if `inc_x` is zero.
+#[allow(clippy::too_many_arguments)]
+pub fn dtbsv(
+    major: RowColMajor,
+    tri: UpOrLowTriangle,
+    trans_a: TransposeMode,
+    diag: IsDiagUnit,
+    n: usize,
+    k: usize,
+    a: &[f64],
+    lda: usize,
+    x: &mut [f64],
+    inc_x: i32,
+) -> Result<(), BlasError> {
+    check_ld("lda", k + 1, lda)?;
+    check_len("a", strided_len(n, k + 1, lda), a.len())?;
+    check_inc("inc_x", inc_x)?;
+    check_len("x", vec_len(n, inc_x), x.len())?;
+    unsafe {
+        traced!(
+            "cblas_dtbsv",
+            [n, k],
+            tri_band_solve(
+                major,
+                tri,
+                trans_a,
+                diag,
+                to_c_int(n, "n")?,
+                to_c_int(k, "k")?,
+                a.as_ptr(),
+                to_c_int(lda, "lda")?,
+                x.as_mut_ptr(),
+                inc_x,
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Solves the tridiagonal system `A * x = b` in place.
+///
+/// `A` is factored as `L * U` without pivoting (the Thomas algorithm), where `L` is unit lower
+/// bidiagonal and `U` is upper bidiagonal. Both factors are assembled in band storage
+/// (`k = 1`, column-major) and applied with two calls to [`dtbsv`].
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `lower`: The `n - 1` sub-diagonal elements of `A`.
+/// - `diag`: The `n` diagonal elements of `A`.
+/// - `upper`: The `n - 1` super-diagonal elements of `A`.
+/// - `b`: On entry, the right-hand side `b`. On return, overwritten with the solution `x`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `lower`, `upper` or `b` does not match the length of `diag`.
+///
+/// # Discussion
+/// Since no pivoting is performed, `A` should be diagonally dominant or positive definite, as is the case
+/// for most 1-D PDE discretizations and cubic spline systems. A zero pivot produces non-finite results.
+pub fn solve_tridiagonal(
+    lower: &[f64],
+    diag: &[f64],
+    upper: &[f64],
+    b: &mut [f64],
+) -> Result<(), BlasError> {
+    let n = diag.len();
+    check_shape("lower", n.saturating_sub(1), lower.len())?;
+    check_shape("upper", n.saturating_sub(1), upper.len())?;
+    check_shape("b", n, b.len())?;
+    if n == 0 {
+        return Ok(());
+    }
+
+    // Column j of each band holds (A[j - 1][j], A[j][j]) for `U` and (A[j][j], A[j + 1][j]) for `L`.
+    let mut l_band = vec![0.0; 2 * n];
+    let mut u_band = vec![0.0; 2 * n];
+    u_band[1] = diag[0];
+    for j in 0..n {
+        l_band[2 * j] = 1.0;
+        if j > 0 {
+            let l = lower[j - 1] / u_band[2 * (j - 1) + 1];
+            l_band[2 * (j - 1) + 1] = l;
+            u_band[2 * j] = upper[j - 1];
+            u_band[2 * j + 1] = diag[j] - l * upper[j - 1];
+        }
+    }
+
+    dtbsv(
+        RowColMajor::ColMajor,
+        UpOrLowTriangle::Lower,
+        TransposeMode::NoTrans,
+        IsDiagUnit::Unit,
+        n,
+        1,
+        &l_band,
+        2,
+        b,
+        1,
+    )?;
+    dtbsv(
+        RowColMajor::ColMajor,
+        UpOrLowTriangle::Upper,
+        TransposeMode::NoTrans,
+        IsDiagUnit::NonUnit,
+        n,
+        1,
+        &u_band,
+        2,
+        b,
+        1,
+    )
+}
+
+/// Computes the quadratic form `x^T * A * x` for a general square matrix `A`.
+///
+/// `y = A * x` is formed with [`mat_vec_mul`] (`cblas_dgemv`) and reduced with
+/// [`vector_f64::dot`] (`cblas_ddot`).
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `n`: The order of the matrix `A` and the length of `x`.
+/// - `a`: The `n x n` matrix `A`.
+/// - `lda`: The leading dimension of `A`, must be at least `n`.
+/// - `x`: The vector `x`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n`, [`BlasError::BufferTooSmall`] if `a` is too
+/// short, and [`BlasError::DimensionMismatch`] if `x` does not hold `n` elements.
+pub fn quadratic_form(
+    major: RowColMajor,
+    n: usize,
+    a: &[f64],
+    lda: usize,
+    x: &[f64],
+) -> Result<f64, BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    check_shape("x", n, x.len())?;
+    let mut y = vec![0.0; n];
+    unsafe {
+        traced!(
+            "cblas_dgemv",
+            [n],
+            mat_vec_mul(
+                major,
+                TransposeMode::NoTrans,
+                to_c_int(n, "n")?,
+                to_c_int(n, "n")?,
+                1.0,
+                a.as_ptr(),
+                to_c_int(lda, "lda")?,
+                x.as_ptr(),
+                1,
+                0.0,
+                y.as_mut_ptr(),
+                1,
+            )
+        );
+        Ok(traced!(
+            "cblas_ddot",
+            [n],
+            vector_f64::dot(to_c_int(n, "n")?, x.as_ptr(), 1, y.as_ptr(), 1)
+        ))
+    }
+}
+
+/// Computes the quadratic form `x^T * A * x` for a symmetric matrix `A`, reading only one triangle.
+///
+/// `y = A * x` is formed with [`sym_mat_vec_mul`] (`cblas_dsymv`) and reduced with
+/// [`vector_f64::dot`] (`cblas_ddot`).
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `tri`: Specifies whether the upper or lower triangle of `A` is referenced. Use `UpOrLowTriangle`.
+/// - `n`: The order of the matrix `A` and the length of `x`.
+/// - `a`: The symmetric `n x n` matrix `A`.
+/// - `lda`: The leading dimension of `A`, must be at least `n`.
+/// - `x`: The vector `x`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n`, [`BlasError::BufferTooSmall`] if `a` is too
+/// short, and [`BlasError::DimensionMismatch`] if `x` does not hold `n` elements.
+pub fn quadratic_form_sym(
+    major: RowColMajor,
+    tri: UpOrLowTriangle,
+    n: usize,
+    a: &[f64],
+    lda: usize,
+    x: &[f64],
+) -> Result<f64, BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    check_shape("x", n, x.len())?;
+    let mut y = vec![0.0; n];
+    unsafe {
+        traced!(
+            "cblas_dsymv",
+            [n],
+            sym_mat_vec_mul(
+                major,
+                tri,
+                to_c_int(n, "n")?,
+                1.0,
+                a.as_ptr(),
+                to_c_int(lda, "lda")?,
+                x.as_ptr(),
+                1,
+                0.0,
+                y.as_mut_ptr(),
+                1,
+            )
+        );
+        Ok(traced!(
+            "cblas_ddot",
+            [n],
+            vector_f64::dot(to_c_int(n, "n")?, x.as_ptr(), 1, y.as_ptr(), 1)
+        ))
+    }
+}
+
+/// Computes the biased (population) covariance matrix `(1 / n) * Xc^T * Xc` of a data matrix.
+///
+/// `Xc` is the data matrix with each feature (column) centered on its mean. The product is formed
+/// with [`sym_rank_k_update`] (`cblas_dsyrk`), which only writes the upper triangle, and the lower
+/// triangle is then mirrored so the result is a full symmetric matrix.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `major`: The storage order of `data` and of the returned matrix. Use `RowColMajor`.
+/// - `n_samples`: The number of samples (rows of the data matrix).
+/// - `n_features`: The number of features (columns of the data matrix).
+/// - `data`: The tightly packed `n_samples x n_features` data matrix.
+///
+/// # Returns
+/// The `n_features x n_features` covariance matrix, stored in `major` order.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `data` does not hold exactly `n_samples * n_features` elements.
+/// Returns it with `which = "n_samples"` if there are no samples, since the mean is then undefined.
+///
+/// # Discussion
+/// Dividing by `n` gives the maximum-likelihood estimate, which is biased for small samples. Use
+/// [`covariance_unbiased`] for the sample covariance that divides by `n - 1`.
+pub fn covariance(
+    major: RowColMajor,
+    n_samples: usize,
+    n_features: usize,
+    data: &[f64],
+) -> Result<Vec<f64>, BlasError> {
+    if n_samples == 0 {
+        return Err(BlasError::DimensionMismatch {
+            which: "n_samples",
+            expected: 1,
+            got: 0,
+        });
+    }
+    covariance_scaled(major, n_samples, n_features, data, 1.0 / n_samples as f64)
+}
+
+/// Computes the unbiased sample covariance matrix `(1 / (n - 1)) * Xc^T * Xc` of a data matrix.
+///
+/// Identical to [`covariance`] except for the `n - 1` divisor (Bessel's correction), which makes the
+/// estimate unbiased. It needs at least two samples.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `data` does not hold exactly `n_samples * n_features` elements.
+/// Returns it with `which = "n_samples"` if there are fewer than two samples, since the `n - 1` divisor
+/// is then zero or negative.
+pub fn covariance_unbiased(
+    major: RowColMajor,
+    n_samples: usize,
+    n_features: usize,
+    data: &[f64],
+) -> Result<Vec<f64>, BlasError> {
+    if n_samples < 2 {
+        return Err(BlasError::DimensionMismatch {
+            which: "n_samples",
+            expected: 2,
+            got: n_samples,
+        });
+    }
+    covariance_scaled(
+        major,
+        n_samples,
+        n_features,
+        data,
+        1.0 / (n_samples as f64 - 1.0),
+    )
+}
+
+fn covariance_scaled(
+    major: RowColMajor,
+    n_samples: usize,
+    n_features: usize,
+    data: &[f64],
+    scale: f64,
+) -> Result<Vec<f64>, BlasError> {
+    check_shape("data", n_samples * n_features, data.len())?;
+    let (lda, sample_stride, feature_stride) = match major {
+        RowColMajor::RowMajor => (n_features, n_features, 1),
+        RowColMajor::ColMajor => (n_samples, 1, n_samples),
+    };
+
+    let mut centered = data.to_vec();
+    for j in 0..n_features {
+        let column = (0..n_samples).map(|i| i * sample_stride + j * feature_stride);
+        let mean = column.clone().map(|idx| data[idx]).sum::<f64>() / n_samples as f64;
+        for idx in column {
+            centered[idx] -= mean;
+        }
+    }
+
+    let mut cov = vec![0.0; n_features * n_features];
+    unsafe {
+        traced!(
+            "cblas_dsyrk",
+            [n_features, n_samples],
+            sym_rank_k_update(
+                major,
+                UpOrLowTriangle::Upper,
+                TransposeMode::Trans,
+                to_c_int(n_features, "n_features")?,
+                to_c_int(n_samples, "n_samples")?,
+                scale,
+                centered.as_ptr(),
+                to_c_int(lda.max(1), "lda")?,
+                0.0,
+                cov.as_mut_ptr(),
+                to_c_int(n_features.max(1), "n_features")?,
+            )
+        );
+    }
+    symmetrize(
+        major,
+        UpOrLowTriangle::Upper,
+        n_features,
+        &mut cov,
+        n_features,
+    )?;
+    Ok(cov)
+}
+
+/// Running mean and covariance of a stream of samples, updated one sample at a time.
+///
+/// Each [`update`](CovarianceAccumulator::update) applies Welford's recurrence: the sample is centered on
+/// the mean *before* the update, and its outer product is added with [`sym_rank_1_update`] (`cblas_dsyr`)
+/// scaled by `count / (count + 1)`. Centering on the running mean rather than accumulating raw sums of
+/// `x * x^T` avoids the catastrophic cancellation of the textbook `E[x x^T] - mean * mean^T` formula, and
+/// the result matches [`covariance`] on the same data without ever storing it.
+///
+/// # Precision
+/// This type operates on double-precision (`f64`) numbers.
+#[derive(Clone, Debug)]
+pub struct CovarianceAccumulator {
+    mean: Vec<f64>,
+    /// The sum of centered outer products (the co-moment), upper triangle only, row-major `n x n`.
+    cov: Vec<f64>,
+    count: usize,
+    n: usize,
+}
+
+impl CovarianceAccumulator {
+    /// Creates an empty accumulator for samples of `n` features.
+    pub fn new(n: usize) -> Self {
+        CovarianceAccumulator {
+            mean: vec![0.0; n],
+            cov: vec![0.0; n * n],
+            count: 0,
+            n,
+        }
+    }
+
+    /// The number of features per sample.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// The number of samples seen so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The running mean, all zeros before the first sample.
+    pub fn mean(&self) -> &[f64] {
+        &self.mean
+    }
+
+    /// Adds one sample.
+    ///
+    /// # Errors
+    /// Returns [`BlasError::DimensionMismatch`] if `x` does not have `n` features.
+    pub fn update(&mut self, x: &[f64]) -> Result<(), BlasError> {
+        let n = self.n;
+        check_shape("x", n, x.len())?;
+        let k = self.count as f64;
+        let delta: Vec<f64> = x.iter().zip(&self.mean).map(|(x, m)| x - m).collect();
+        for (m, d) in self.mean.iter_mut().zip(&delta) {
+            *m += d / (k + 1.0);
+        }
+        if self.count > 0 && n > 0 {
+            unsafe {
+                traced!(
+                    "cblas_dsyr",
+                    [n],
+                    sym_rank_1_update(
+                        RowColMajor::RowMajor,
+                        UpOrLowTriangle::Upper,
+                        to_c_int(n, "n")?,
+                        k / (k + 1.0),
+                        delta.as_ptr(),
+                        1,
+                        self.cov.as_mut_ptr(),
+                        to_c_int(n, "n")?,
+                    )
+                );
+            }
+        }
+        self.count += 1;
+        Ok(())
+    }
+
+    /// The biased (population) covariance of the samples so far, dividing by `count`, as a full symmetric
+    /// `n x n` matrix. Returns `None` before the first sample.
+    pub fn covariance(&self) -> Option<Vec<f64>> {
+        self.scaled(self.count)
+    }
+
+    /// The unbiased sample covariance of the samples so far, dividing by `count - 1`, as a full symmetric
+    /// `n x n` matrix. Returns `None` until two samples have been seen.
+    pub fn covariance_unbiased(&self) -> Option<Vec<f64>> {
+        self.scaled(self.count.checked_sub(1)?)
+    }
+
+    fn scaled(&self, divisor: usize) -> Option<Vec<f64>> {
+        if divisor == 0 {
+            return None;
+        }
+        let mut cov: Vec<f64> = self.cov.iter().map(|v| v / divisor as f64).collect();
+        // The buffer is exactly n x n, so this cannot fail.
+        symmetrize(
+            RowColMajor::RowMajor,
+            UpOrLowTriangle::Upper,
+            self.n,
+            &mut cov,
+            self.n.max(1),
+        )
+        .ok()?;
+        Some(cov)
+    }
+}
+
+/// Forms the Gram matrix of `A` on its smaller side: `A^T * A` (`n x n`) when `n <= m`, otherwise
+/// `A * A^T` (`m x m`).
+///
+/// The product is computed with [`sym_rank_k_update`] (`cblas_dsyrk`), which only writes the upper
+/// triangle, and the lower triangle is then mirrored so the result is a full symmetric matrix.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `major`: The storage order of `a` and of the returned matrix. Use `RowColMajor`.
+/// - `m`: The number of rows of `A`.
+/// - `n`: The number of columns of `A`.
+/// - `a`: The tightly packed `m x n` matrix `A`.
+///
+/// # Returns
+/// The tightly packed Gram matrix, and `true` if it is `A * A^T` rather than `A^T * A`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `a` does not hold exactly `m * n` elements.
+///
+/// # Discussion
+/// For a tall-skinny `A` (overdetermined least squares) `A^T * A` gives the normal equations
+/// `A^T * A * x = A^T * b`; for a short-fat `A` (minimum-norm solutions) `A * A^T` gives
+/// `A * A^T * y = b` with `x = A^T * y`. Either way the smaller Gram matrix costs
+/// `min(m, n)^2 * max(m, n)` multiply-adds instead of `max(m, n)^2 * min(m, n)`.
+pub fn normal_equations(
+    major: RowColMajor,
+    m: usize,
+    n: usize,
+    a: &[f64],
+) -> Result<(Vec<f64>, bool), BlasError> {
+    check_shape("a", m * n, a.len())?;
+    let lda = match major {
+        RowColMajor::RowMajor => n,
+        RowColMajor::ColMajor => m,
+    };
+    let transposed = n > m;
+    let (order, depth, trans) = if transposed {
+        (m, n, TransposeMode::NoTrans)
+    } else {
+        (n, m, TransposeMode::Trans)
+    };
+
+    let mut gram = vec![0.0; order * order];
+    unsafe {
+        traced!(
+            "cblas_dsyrk",
+            [order, depth],
+            sym_rank_k_update(
+                major,
+                UpOrLowTriangle::Upper,
+                trans,
+                to_c_int(order, "order")?,
+                to_c_int(depth, "depth")?,
+                1.0,
+                a.as_ptr(),
+                to_c_int(lda.max(1), "lda")?,
+                0.0,
+                gram.as_mut_ptr(),
+                to_c_int(order.max(1), "order")?,
+            )
+        );
+    }
+    symmetrize(major, UpOrLowTriangle::Upper, order, &mut gram, order)?;
+    Ok((gram, transposed))
+}
+
+/// Fills the opposite triangle of a symmetric matrix from the populated one, so `a[j][i] = a[i][j]`.
+///
+/// Routines such as `cblas_dsyrk` only write one triangle; this materializes the full matrix for code that expects a dense one. The diagonal is left untouched.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `tri`: The triangle of `A` that is populated and is copied from. Use `UpOrLowTriangle`.
+/// - `n`: The order of the matrix `A`.
+/// - `a`: The `n x n` matrix `A`, completed in place.
+/// - `lda`: The leading dimension of `A`, must be at least `n`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n` and [`BlasError::BufferTooSmall`] if `a` is too short.
+pub fn symmetrize(
+    major: RowColMajor,
+    tri: UpOrLowTriangle,
+    n: usize,
+    a: &mut [f64],
+    lda: usize,
+) -> Result<(), BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    for i in 0..n {
+        for j in (i + 1)..n {
+            // (i, j) lies in the upper triangle and (j, i) in the lower one.
+            let (upper, lower) = match major {
+                RowColMajor::RowMajor => (i * lda + j, j * lda + i),
+                RowColMajor::ColMajor => (j * lda + i, i * lda + j),
+            };
+            match tri {
+                UpOrLowTriangle::Upper => a[lower] = a[upper],
+                UpOrLowTriangle::Lower => a[upper] = a[lower],
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns whether the `n x n` matrix `A` is symmetric to within `tol`, i.e. `|a[i][j] - a[j][i]| <= tol` for
+/// every `i != j`.
+///
+/// Symmetric routines such as `cblas_dsymv` read only one triangle, so a matrix that is not actually symmetric
+/// gives a wrong result without any error. This predicate lets that assumption be checked, for instance in a
+/// `debug_assert!`, before the call.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `a`: The `n x n` matrix `A`.
+/// - `n`: The order of the matrix `A`.
+/// - `lda`: The leading dimension of `A`, must be at least `n`.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `tol`: The largest absolute difference accepted between mirrored entries. `0` demands exact symmetry.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n` and [`BlasError::BufferTooSmall`] if `a` is too short.
+pub fn is_symmetric(
+    a: &[f64],
+    n: usize,
+    lda: usize,
+    order: RowColMajor,
+    tol: f64,
+) -> Result<bool, BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let (upper, lower) = match order {
+                RowColMajor::RowMajor => (i * lda + j, j * lda + i),
+                RowColMajor::ColMajor => (j * lda + i, i * lda + j),
+            };
+            let diff = (a[upper] - a[lower]).abs();
+            if diff.is_nan() || diff > tol {
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// Builds a dense, tightly packed `n x n` matrix with `d` on its diagonal and zeros elsewhere.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `d`: The `n` diagonal entries.
+///
+/// # Returns
+/// The `n x n` matrix with leading dimension `n`. The diagonal occupies the same positions in row- and
+/// column-major storage, so the result can be passed with either `RowColMajor`.
+pub fn from_diagonal(d: &[f64]) -> Vec<f64> {
+    let n = d.len();
+    let mut a = vec![0.0; n * n];
+    for (i, &v) in d.iter().enumerate() {
+        a[i * (n + 1)] = v;
+    }
+    a
+}
+
+/// Extracts the diagonal of an `n x n` matrix.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `n`: The order of the matrix `A`.
+/// - `a`: The matrix `A`, in either `RowColMajor` order; element `(i, i)` sits at `i * (lda + 1)` in both.
+/// - `lda`: The leading dimension of `A`, must be at least `n`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n` and [`BlasError::BufferTooSmall`] if `a` is too short.
+pub fn diagonal(n: usize, a: &[f64], lda: usize) -> Result<Vec<f64>, BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    Ok((0..n).map(|i| a[i * (lda + 1)]).collect())
+}
+
+/// Copies the unit lower-triangular factor `L` out of an LU factorization stored in place.
+///
+/// LAPACK `dgetrf` overwrites `A` with `L` below the diagonal and `U` on and above it; the unit diagonal of
+/// `L` is implied and not stored. This returns `L` as its own dense matrix, with ones on the diagonal and
+/// zeros above it.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `lu`: The `n x n` combined factors.
+/// - `n`: The order of the matrix.
+/// - `lda`: The leading dimension of `lu`, must be at least `n`.
+/// - `order`: The storage order of `lu` and of the returned matrix. Use `RowColMajor`.
+///
+/// # Returns
+/// The tightly packed `n x n` factor `L`, stored in `order`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n` and [`BlasError::BufferTooSmall`] if `lu` is too short.
+pub fn extract_l(
+    lu: &[f64],
+    n: usize,
+    lda: usize,
+    order: RowColMajor,
+) -> Result<Vec<f64>, BlasError> {
+    extract_triangle(lu, n, lda, order, UpOrLowTriangle::Lower)
+}
+
+/// Copies the upper-triangular factor `U`, diagonal included, out of an LU factorization stored in place.
+///
+/// The counterpart of [`extract_l`]: the result holds `lu` on and above the diagonal and zeros below it.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n` and [`BlasError::BufferTooSmall`] if `lu` is too short.
+pub fn extract_u(
+    lu: &[f64],
+    n: usize,
+    lda: usize,
+    order: RowColMajor,
+) -> Result<Vec<f64>, BlasError> {
+    extract_triangle(lu, n, lda, order, UpOrLowTriangle::Upper)
+}
+
+fn extract_triangle(
+    lu: &[f64],
+    n: usize,
+    lda: usize,
+    order: RowColMajor,
+    tri: UpOrLowTriangle,
+) -> Result<Vec<f64>, BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("lu", strided_len(n, n, lda), lu.len())?;
+    let mut out = vec![0.0; n * n];
+    for i in 0..n {
+        for j in 0..n {
+            // The same (i, j) position in the source (stride lda) and in the tight result (stride n).
+            let (src, dst) = match order {
+                RowColMajor::RowMajor => (i * lda + j, i * n + j),
+                RowColMajor::ColMajor => (j * lda + i, j * n + i),
+            };
+            out[dst] = match tri {
+                UpOrLowTriangle::Lower if i > j => lu[src],
+                UpOrLowTriangle::Lower if i == j => 1.0,
+                UpOrLowTriangle::Upper if i <= j => lu[src],
+                _ => 0.0,
+            };
+        }
+    }
+    Ok(out)
+}
+
+/// Computes the Kronecker product `A ⊗ B` of an `ma x na` matrix `A` and an `mb x nb` matrix `B`.
+///
+/// The result is the `(ma * mb) x (na * nb)` block matrix whose `(i, j)` block is `a[i][j] * B`. Each of its
+/// rows is an outer product of a row of `A` with a row of `B`, formed with [`mat_rank1_update`] (`cblas_dger`).
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `major`: The storage order of `a`, `b` and the returned matrix. Use `RowColMajor`.
+/// - `a`: The tightly packed matrix `A`.
+/// - `(ma, na)`: The number of rows and columns of `A`.
+/// - `b`: The tightly packed matrix `B`.
+/// - `(mb, nb)`: The number of rows and columns of `B`.
+///
+/// # Returns
+/// The tightly packed `(ma * mb) x (na * nb)` product, stored in `major` order.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `a` or `b` does not hold exactly the number of elements its
+/// shape requires.
+pub fn kron(
+    major: RowColMajor,
+    a: &[f64],
+    (ma, na): (usize, usize),
+    b: &[f64],
+    (mb, nb): (usize, usize),
+) -> Result<Vec<f64>, BlasError> {
+    check_shape("a", ma * na, a.len())?;
+    check_shape("b", mb * nb, b.len())?;
+    // Column-major storage of a matrix is the row-major storage of its transpose, and
+    // (A ⊗ B)^T = A^T ⊗ B^T, so both orders reduce to the row-major case.
+    let ((ma, na), (mb, nb)) = match major {
+        RowColMajor::RowMajor => ((ma, na), (mb, nb)),
+        RowColMajor::ColMajor => ((na, ma), (nb, mb)),
+    };
+    let cols = na * nb;
+    let mut out = vec![0.0; ma * mb * cols];
+    if out.is_empty() {
+        return Ok(out);
+    }
+    let na_c = to_c_int(na, "na")?;
+    let nb_c = to_c_int(nb, "nb")?;
+    for i in 0..ma {
+        for p in 0..mb {
+            // Row (i, p) of the result is the na x nb outer product of row i of A and row p of B.
+            let row = &mut out[(i * mb + p) * cols..][..cols];
+            unsafe {
+                traced!(
+                    "cblas_dger",
+                    [na, nb],
+                    mat_rank1_update(
+                        RowColMajor::RowMajor,
+                        na_c,
+                        nb_c,
+                        1.0,
+                        a[i * na..].as_ptr(),
+                        1,
+                        b[p * nb..].as_ptr(),
+                        1,
+                        row.as_mut_ptr(),
+                        nb_c,
+                    )
+                );
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Applies a sequence of plane rotations to pairs of rows of a matrix, in order.
+///
+/// Each `(i, j, c, s)` replaces rows `i` and `j` of `M` by `c * row_i + s * row_j` and `c * row_j - s * row_i`,
+/// using [`givens::givens_rot_f64`] (`cblas_drot`) on the two row views. In row-major order a row is contiguous; in
+/// column-major order its elements are `rows` apart, so the row views use that stride instead. This is the
+/// update QR-by-Givens and bidiagonalization codes apply when they zero one subdiagonal entry at a time.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers with real `f64` rotation coefficients.
+///
+/// # Parameters
+/// - `rotations`: The `(i, j, c, s)` rotations, applied first to last. `i` and `j` must differ.
+/// - `m`: The tightly packed `rows x cols` matrix `M`, updated in place.
+/// - `rows`: The number of rows of `M`.
+/// - `cols`: The number of columns of `M`.
+/// - `major`: The storage order of `m`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `m` does not hold exactly `rows * cols` elements and
+/// [`BlasError::IndexOutOfBounds`] if a rotation names a row `>= rows`, and [`BlasError::RepeatedIndex`] if
+/// a rotation pairs a row with itself. All rotations are validated before any is applied, so `m` is left
+/// untouched on error.
+pub fn apply_givens_sequence(
+    rotations: &[(usize, usize, f64, f64)],
+    m: &mut [f64],
+    rows: usize,
+    cols: usize,
+    major: RowColMajor,
+) -> Result<(), BlasError> {
+    check_shape("m", rows * cols, m.len())?;
+    for &(i, j, _, _) in rotations {
+        check_indices("rotations", &[i, j], rows)?;
+        if i == j {
+            return Err(BlasError::RepeatedIndex {
+                which: "rotations",
+                index: i,
+            });
+        }
+    }
+    let (row_stride, inc) = match major {
+        RowColMajor::RowMajor => (cols, 1),
+        RowColMajor::ColMajor => (1, rows),
+    };
+    let n = to_c_int(cols, "cols")?;
+    let inc = to_c_int(inc, "rows")?;
+    let ptr = m.as_mut_ptr();
+    for &(i, j, c, s) in rotations {
+        unsafe {
+            traced!(
+                "cblas_drot",
+                [cols],
+                givens::givens_rot_f64(
+                    n,
+                    ptr.add(i * row_stride),
+                    inc,
+                    ptr.add(j * row_stride),
+                    inc,
+                    c,
+                    s,
+                )
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Band widths up to `n / BAND_FRACTION` are multiplied with the banded kernel by [`gemv_banded_auto`];
+/// wider bands fall back to the dense one, since the band kernel would do nearly as much work.
+const BAND_FRACTION: usize = 4;
+
+/// Computes `y = A * x` for a densely stored `m x n` matrix, using the banded kernel when `A` turns out to
+/// be banded.
+///
+/// # Precision
+/// This function operates on double-precision floating-point (`f64`) numbers.
+///
+/// # Parameters
+/// - `a`: The matrix `A`, stored densely.
+/// - `m`, `n`: The number of rows and columns of `A`.
+/// - `lda`: The leading dimension of `a`.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `x`: The input vector, of length `n`.
+///
+/// # Returns
+/// The product `A * x`, of length `m`.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is below its minimum,
+/// [`BlasError::BufferTooSmall`] if `a` is too short, and [`BlasError::DimensionMismatch`] if `x` is not of
+/// length `n`.
+///
+/// # Discussion
+/// The matrix is scanned once for its lower and upper bandwidths `kl` and `ku`, the distance of the
+/// furthest nonzero below and above the diagonal. If the band `kl + ku + 1` spans at most a quarter of the
+/// columns, it is packed into BLAS band storage and multiplied with `cblas_dgbmv`, which touches only the
+/// band. Otherwise `a` is passed straight to `cblas_dgemv`.
+///
+/// This is a convenience for matrices whose structure is not known in advance, not an optimization. Nothing
+/// is cached between calls, and the scan and the packing read all `m * n` elements, which is already as much
+/// memory traffic as one dense `cblas_dgemv`. A call is never faster than calling `cblas_dgemv` directly.
+pub fn gemv_banded_auto(
+    a: &[f64],
+    m: usize,
+    n: usize,
+    lda: usize,
+    order: RowColMajor,
+    x: &[f64],
+) -> Result<Vec<f64>, BlasError> {
+    let (outer, inner) = match order {
+        RowColMajor::RowMajor => (m, n),
+        RowColMajor::ColMajor => (n, m),
+    };
+    check_ld("lda", inner.max(1), lda)?;
+    check_len("a", strided_len(outer, inner, lda), a.len())?;
+    check_shape("x", n, x.len())?;
+    let mut y = vec![0.0; m];
+    if m == 0 || n == 0 {
+        return Ok(y);
+    }
+    let at = |i: usize, j: usize| match order {
+        RowColMajor::RowMajor => a[i * lda + j],
+        RowColMajor::ColMajor => a[j * lda + i],
+    };
+    let (mut kl, mut ku) = (0, 0);
+    for i in 0..m {
+        for j in 0..n {
+            if at(i, j) != 0.0 {
+                if i > j {
+                    kl = kl.max(i - j);
+                } else {
+                    ku = ku.max(j - i);
+                }
+            }
+        }
+    }
+    let band = kl + ku + 1;
+    if band * BAND_FRACTION <= n {
+        // Row-major band storage keeps row i at offset kl - i, column-major keeps column j at ku - j.
+        let mut ab = vec![0.0; band * outer];
+        for i in 0..m {
+            for j in i.saturating_sub(kl)..n.min(i + ku + 1) {
+                let idx = match order {
+                    RowColMajor::RowMajor => i * band + kl + j - i,
+                    RowColMajor::ColMajor => j * band + ku + i - j,
+                };
+                ab[idx] = at(i, j);
+            }
+        }
+        unsafe {
+            traced!(
+                "cblas_dgbmv",
+                [m, n, kl, ku],
+                band_mat_mul_vec(
+                    order,
+                    TransposeMode::NoTrans,
+                    to_c_int(m, "m")?,
+                    to_c_int(n, "n")?,
+                    to_c_int(kl, "kl")?,
+                    to_c_int(ku, "ku")?,
+                    1.0,
+                    ab.as_ptr(),
+                    to_c_int(band, "band")?,
+                    x.as_ptr(),
+                    1,
+                    0.0,
+                    y.as_mut_ptr(),
+                    1,
+                )
+            );
+        }
+    } else {
+        unsafe {
+            traced!(
+                "cblas_dgemv",
+                [m, n],
+                mat_vec_mul(
+                    order,
+                    TransposeMode::NoTrans,
+                    to_c_int(m, "m")?,
+                    to_c_int(n, "n")?,
+                    1.0,
+                    a.as_ptr(),
+                    to_c_int(lda, "lda")?,
+                    x.as_ptr(),
+                    1,
+                    0.0,
+                    y.as_mut_ptr(),
+                    1,
+                )
+            );
+        }
+    }
+    Ok(y)
+}
+
+/// Standard accuracy diagnostics for a computed solution `x` of `A * x = b`, from [`solve_diagnostics`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolveDiagnostics {
+    /// `||A * x - b||`, the Euclidean norm of the residual.
+    pub residual_norm: f64,
+    /// `||A * x - b|| / ||b||`. When `b` is zero this is `0` for a zero residual and infinity otherwise.
+    pub relative_residual: f64,
+    /// `||b||`, the Euclidean norm of the right-hand side.
+    pub rhs_norm: f64,
+}
+
+/// Measures how well `x` solves `A * x = b` by forming the residual `A * x - b` with `cblas_dgemv` and
+/// taking norms with `cblas_dnrm2`.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `a`: The `n x n` matrix `A`, row-major and tightly packed.
+/// - `n`: The order of `A`.
+/// - `x`: The computed solution.
+/// - `b`: The right-hand side.
+///
+/// # Errors
+/// Returns [`BlasError::BufferTooSmall`] if `a` is too short and [`BlasError::DimensionMismatch`] if `x` or
+/// `b` does not hold `n` elements.
+///
+/// # Discussion
+/// A relative residual within a small multiple of machine epsilon (about `1e-16`) means the solve was
+/// backward stable. The relative error in `x` itself can still be up to the condition number of `A` times
+/// larger, so a tiny residual does not by itself make an ill-conditioned solution accurate.
+pub fn solve_diagnostics(
+    a: &[f64],
+    n: usize,
+    x: &[f64],
+    b: &[f64],
+) -> Result<SolveDiagnostics, BlasError> {
+    check_len("a", n * n, a.len())?;
+    check_shape("x", n, x.len())?;
+    check_shape("b", n, b.len())?;
+    let n_c = to_c_int(n, "n")?;
+    let mut residual = b.to_vec();
+    let (residual_norm, rhs_norm) = unsafe {
+        traced!(
+            "cblas_dgemv",
+            [n],
+            mat_vec_mul(
+                RowColMajor::RowMajor,
+                TransposeMode::NoTrans,
+                n_c,
+                n_c,
+                1.0,
+                a.as_ptr(),
+                n_c.max(1),
+                x.as_ptr(),
+                1,
+                -1.0,
+                residual.as_mut_ptr(),
+                1,
+            )
+        );
+        (
+            traced!(
+                "cblas_dnrm2",
+                [n],
+                vector_f64::norm2(n_c, residual.as_ptr(), 1)
+            ),
+            traced!("cblas_dnrm2", [n], vector_f64::norm2(n_c, b.as_ptr(), 1)),
+        )
+    };
+    let relative_residual = if rhs_norm > 0.0 {
+        residual_norm / rhs_norm
+    } else if residual_norm == 0.0 {
+        0.0
+    } else {
+        f64::INFINITY
+    };
+    Ok(SolveDiagnostics {
+        residual_norm,
+        relative_residual,
+        rhs_norm,
+    })
+}
+
+/// Solves `A * X = B` for a symmetric positive-definite band matrix `A`, factoring it in place with
+/// [`band_cholesky`] (`dpbtrf`) and solving with [`band_cholesky_solve`] (`dpbtrs`).
+///
+/// Banded SPD systems, such as finite-difference discretizations of 1-D and 2-D diffusion problems, cost
+/// `O(n * kd^2)` to factor this way instead of `O(n^3)` for a dense Cholesky.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `tri`: Which triangle of the band `ab` holds. Use `UpOrLowTriangle`.
+/// - `ab`: The band of `A` in tight LAPACK band storage: `n` columns of `kd + 1` elements, column-major. For
+///   the upper triangle `A(i, j)` is at `ab[kd + i - j + j * (kd + 1)]` (so the diagonal is the last row);
+///   for the lower triangle it is at `ab[i - j + j * (kd + 1)]` (the diagonal is the first row).
+///   Overwritten with the Cholesky factor.
+/// - `n`: The order of `A`.
+/// - `kd`: The number of super-diagonals (upper) or sub-diagonals (lower) of `A`.
+/// - `b`: The right-hand sides, column-major `n x nrhs` with `nrhs = b.len() / n`. Overwritten with `X`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `ab` does not hold exactly `(kd + 1) * n` elements or `b` is
+/// not a whole number of columns of length `n`, and [`BlasError::NotPositiveDefinite`] if the factorization
+/// breaks down, in which case `ab` is left partially factored and `b` is untouched.
+pub fn solve_banded_spd(
+    tri: UpOrLowTriangle,
+    ab: &mut [f64],
+    n: usize,
+    kd: usize,
+    b: &mut [f64],
+) -> Result<(), BlasError> {
+    let ldab = kd + 1;
+    check_shape("ab", ldab * n, ab.len())?;
+    if n == 0 {
+        return Ok(());
+    }
+    let nrhs = b.len() / n;
+    check_shape("b", nrhs * n, b.len())?;
+    let uplo = match tri {
+        UpOrLowTriangle::Upper => b'U',
+        UpOrLowTriangle::Lower => b'L',
+    } as c_char;
+    let (n_c, kd_c, ldab_c) = (
+        to_c_int(n, "n")?,
+        to_c_int(kd, "kd")?,
+        to_c_int(ldab, "ldab")?,
+    );
+    let nrhs_c = to_c_int(nrhs, "nrhs")?;
+    let mut info: c_int = 0;
+    unsafe {
+        traced!(
+            "dpbtrf_",
+            [n, kd],
+            band_cholesky(&uplo, &n_c, &kd_c, ab.as_mut_ptr(), &ldab_c, &mut info)
+        );
+    }
+    // Every argument was validated above, so only a breakdown (info > 0) can be reported.
+    if info > 0 {
+        return Err(BlasError::NotPositiveDefinite {
+            minor: info as usize,
+        });
+    }
+    unsafe {
+        traced!(
+            "dpbtrs_",
+            [n, kd, nrhs],
+            band_cholesky_solve(
+                &uplo,
+                &n_c,
+                &kd_c,
+                &nrhs_c,
+                ab.as_ptr(),
+                &ldab_c,
+                b.as_mut_ptr(),
+                &n_c,
+                &mut info,
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Swaps rows `i` and `j` of a `rows x cols` matrix in place with `cblas_dswap`.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `a`: The matrix.
+/// - `rows`, `cols`: The shape of the matrix.
+/// - `lda`: The leading dimension of `a`.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `i`, `j`: The rows to exchange. Swapping a row with itself does nothing.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is below its minimum,
+/// [`BlasError::BufferTooSmall`] if `a` is too short, and [`BlasError::IndexOutOfBounds`] if `i` or `j` is
+/// not less than `rows`.
+///
+/// # Discussion
+/// In row-major storage a row is contiguous; in column-major storage its elements are `lda` apart. The
+/// strided swap handles both without copying, which makes this the building block for row pivoting.
+pub fn swap_rows(
+    a: &mut [f64],
+    rows: usize,
+    cols: usize,
+    lda: usize,
+    order: RowColMajor,
+    i: usize,
+    j: usize,
+) -> Result<(), BlasError> {
+    let (outer, inner) = match order {
+        RowColMajor::RowMajor => (rows, cols),
+        RowColMajor::ColMajor => (cols, rows),
+    };
+    check_ld("lda", inner.max(1), lda)?;
+    check_len("a", strided_len(outer, inner, lda), a.len())?;
+    check_indices("i", &[i], rows)?;
+    check_indices("j", &[j], rows)?;
+    let (start_i, start_j, inc) = match order {
+        RowColMajor::RowMajor => (i * lda, j * lda, 1),
+        RowColMajor::ColMajor => (i, j, lda),
+    };
+    swap_strided(a, cols, start_i, start_j, inc)
+}
+
+/// Swaps columns `i` and `j` of a `rows x cols` matrix in place with `cblas_dswap`.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `a`: The matrix.
+/// - `rows`, `cols`: The shape of the matrix.
+/// - `lda`: The leading dimension of `a`.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `i`, `j`: The columns to exchange. Swapping a column with itself does nothing.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is below its minimum,
+/// [`BlasError::BufferTooSmall`] if `a` is too short, and [`BlasError::IndexOutOfBounds`] if `i` or `j` is
+/// not less than `cols`.
+pub fn swap_columns(
+    a: &mut [f64],
+    rows: usize,
+    cols: usize,
+    lda: usize,
+    order: RowColMajor,
+    i: usize,
+    j: usize,
+) -> Result<(), BlasError> {
+    let (outer, inner) = match order {
+        RowColMajor::RowMajor => (rows, cols),
+        RowColMajor::ColMajor => (cols, rows),
+    };
+    check_ld("lda", inner.max(1), lda)?;
+    check_len("a", strided_len(outer, inner, lda), a.len())?;
+    check_indices("i", &[i], cols)?;
+    check_indices("j", &[j], cols)?;
+    let (start_i, start_j, inc) = match order {
+        RowColMajor::RowMajor => (i, j, lda),
+        RowColMajor::ColMajor => (i * lda, j * lda, 1),
+    };
+    swap_strided(a, rows, start_i, start_j, inc)
+}
+
+/// Swaps the `len`-element lines of `a` starting at `start_i` and `start_j`, both with stride `inc`.
+/// Shared by [`swap_rows`] and [`swap_columns`], which have already checked that both lines lie in `a`.
+fn swap_strided(
+    a: &mut [f64],
+    len: usize,
+    start_i: usize,
+    start_j: usize,
+    inc: usize,
+) -> Result<(), BlasError> {
+    if start_i == start_j || len == 0 {
+        return Ok(());
+    }
+    let ptr = a.as_mut_ptr();
+    let inc = to_c_int(inc, "lda")?;
+    unsafe {
+        traced!(
+            "cblas_dswap",
+            [len],
+            vector_f64::swap(
+                to_c_int(len, "len")?,
+                ptr.add(start_i),
+                inc,
+                ptr.add(start_j),
+                inc
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Computes the 2-norm condition number `sigma_max / sigma_min` of an `m x n` matrix from its singular
+/// values.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `a`: The matrix, row-major and tightly packed. A column-major buffer can be passed with `m` and `n`
+///   swapped, since it then holds the transpose and a matrix shares its singular values with its transpose.
+/// - `m`, `n`: The shape of the matrix.
+///
+/// # Returns
+/// `sigma_max / sigma_min` over the `min(m, n)` singular values, `f64::INFINITY` if the smallest one is
+/// zero (the matrix is rank deficient), and `1` for an empty matrix.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `a` does not hold `m * n` elements, and
+/// [`BlasError::NoConvergence`] if [`singular_value_decomposition`] (`dgesvd`) fails to converge.
+///
+/// # Discussion
+/// This is the exact condition number, at the cost of a full SVD (about `4 m n^2` flops for `m >= n`). No
+/// singular vectors are formed. A LAPACK `gecon` estimate is far cheaper but only bounds the 1-norm or
+/// infinity-norm condition number to within a factor of a few.
+pub fn cond_2norm(a: &[f64], m: usize, n: usize) -> Result<f64, BlasError> {
+    cond_2norm_with(&mut LapackWorkspace::new(), a, m, n)
+}
+
+/// Computes the same condition number as [`cond_2norm`], taking the `dgesvd` scratch space from a reusable
+/// [`LapackWorkspace`].
+///
+/// The workspace is grown to fit on the first call for a given size and reused afterwards, so checking the
+/// conditioning of many matrices of one shape skips the workspace query and allocation after the first
+/// (see [`LapackWorkspace::reserve_for`]).
+/// The copy of `a` that `dgesvd` destroys is still made on every call.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `a` does not hold `m * n` elements, and
+/// [`BlasError::NoConvergence`] if [`singular_value_decomposition`] (`dgesvd`) fails to converge.
+pub fn cond_2norm_with(
+    workspace: &mut LapackWorkspace,
+    a: &[f64],
+    m: usize,
+    n: usize,
+) -> Result<f64, BlasError> {
+    let s = singular_values(workspace, a, m, n)?;
+    Ok(match (s.first(), s.last()) {
+        (Some(&max), Some(&min)) if min > 0.0 => max / min,
+        (Some(_), Some(_)) => f64::INFINITY,
+        // An empty matrix.
+        _ => 1.0,
+    })
+}
+
+/// The `min(m, n)` singular values of the row-major `m x n` matrix `a`, in descending order, computed with
+/// `dgesvd` on a copy of `a`.
+fn singular_values(
+    workspace: &mut LapackWorkspace,
+    a: &[f64],
+    m: usize,
+    n: usize,
+) -> Result<Vec<f64>, BlasError> {
+    check_shape("a", m * n, a.len())?;
+    let k = m.min(n);
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+    let mut a = a.to_vec();
+    let mut s = vec![0.0; k];
+    // The row-major `m x n` buffer is the column-major `n x m` transpose, which is what LAPACK reads.
+    workspace.reserve_for(LapackRoutine::Dgesvd(SvdJob::None, SvdJob::None), (n, m))?;
+    let work = workspace.work_mut();
+    let job = SvdJob::None.to_lapack();
+    let (m_c, n_c, one) = (to_c_int(n, "n")?, to_c_int(m, "m")?, 1);
+    let lwork = to_c_int(work.len(), "lwork")?;
+    let mut info: c_int = 0;
+    unsafe {
+        traced!(
+            "dgesvd_",
+            [n, m],
+            singular_value_decomposition(
+                &job,
+                &job,
+                &m_c,
+                &n_c,
+                a.as_mut_ptr(),
+                &m_c,
+                s.as_mut_ptr(),
+                std::ptr::null_mut(),
+                &one,
+                std::ptr::null_mut(),
+                &one,
+                work.as_mut_ptr(),
+                &lwork,
+                &mut info,
+            )
+        );
+    }
+    // Every argument was validated above, so only a convergence failure (info > 0) can be reported.
+    if info > 0 {
+        return Err(BlasError::NoConvergence {
+            unconverged: info as usize,
+        });
+    }
+    Ok(s)
+}
+
+/// Computes the numerical rank of an `m x n` matrix: the number of singular values above a tolerance.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `a`: The matrix, row-major and tightly packed. As with [`cond_2norm`], a column-major buffer can be
+///   passed with `m` and `n` swapped.
+/// - `m`, `n`: The shape of the matrix.
+/// - `tol`: The threshold a singular value must exceed to count. `None` selects
+///   `max(m, n) * f64::EPSILON * sigma_max`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `a` does not hold `m * n` elements, and
+/// [`BlasError::NoConvergence`] if [`singular_value_decomposition`] (`dgesvd`) fails to converge.
+///
+/// # Discussion
+/// In floating point a rank-deficient matrix has no exactly zero singular values, only ones at the level of
+/// rounding error, so the rank depends on where the line is drawn. The default is the rule used by LAPACK's
+/// least-squares drivers, MATLAB and NumPy: the SVD is backward stable, so each computed singular value is
+/// accurate to about `max(m, n) * eps * ||A||_2`, and `||A||_2 = sigma_max`. Anything below that bound is
+/// indistinguishable from zero. Because the default scales with `sigma_max`, the rank does not change when
+/// `A` is scaled. Pass an explicit `tol` when the data carry a known absolute noise level larger than
+/// rounding error.
+pub fn matrix_rank(a: &[f64], m: usize, n: usize, tol: Option<f64>) -> Result<usize, BlasError> {
+    let s = singular_values(&mut LapackWorkspace::new(), a, m, n)?;
+    let tol = match (tol, s.first()) {
+        (Some(tol), _) => tol,
+        (None, Some(&max)) => m.max(n) as f64 * f64::EPSILON * max,
+        (None, None) => return Ok(0),
+    };
+    Ok(s.iter().filter(|&&sigma| sigma > tol).count())
+}
+
+/// Computes selected eigenvalues, and optionally eigenvectors, of a symmetric matrix with `dsyevr`.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `job`: Whether eigenvectors are computed. Use `EigenJob`.
+/// - `tri`: The triangle of `a` that holds the matrix; the other one is not read. Use `UpOrLowTriangle`.
+/// - `a`: The `n x n` symmetric matrix. It is copied, so the caller's buffer is left intact.
+/// - `n`: The order of the matrix.
+/// - `lda`: The leading dimension of `a`, at least `n`.
+/// - `order`: The storage order of `a` and of the returned eigenvectors. Use `RowColMajor`.
+/// - `range`: Which eigenvalues to compute. Use `EigenRange`.
+/// - `abstol`: The absolute tolerance for the eigenvalues. `None` selects `2 * f64::MIN_POSITIVE`, which
+///   LAPACK recommends for the most accurate results; a larger value trades accuracy for speed.
+///
+/// # Returns
+/// The selected eigenvalues in ascending order, and when `job` is `ValuesAndVectors`, the matching
+/// eigenvectors as the columns of a tightly packed `n x m` matrix stored in `order`, where `m` is the
+/// number of eigenvalues found.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n`, [`BlasError::BufferTooSmall`] if `a` is too
+/// short, [`BlasError::IndexOutOfBounds`] if an index range reaches past `n`, and
+/// [`BlasError::NoConvergence`] if `dsyevr` reports an internal error.
+///
+/// # Discussion
+/// Unlike a full `dsyev`, `dsyevr` only computes the eigenpairs asked for, after reducing `A` to
+/// tridiagonal form. For the few smallest or largest eigenpairs of a large matrix, as in spectral
+/// clustering or the lowest vibration modes, that is much less work than computing all `n`. A row-major
+/// symmetric matrix is read as the column-major storage of its transpose, which is the same matrix with the
+/// other triangle filled, so `tri` is flipped for LAPACK instead of copying into column-major order. An
+/// empty index range or a value interval with `upper <= lower` returns no eigenvalues without calling
+/// LAPACK.
+#[allow(clippy::too_many_arguments)]
+pub fn dsyevr(
+    job: EigenJob,
+    tri: UpOrLowTriangle,
+    a: &[f64],
+    n: usize,
+    lda: usize,
+    order: RowColMajor,
+    range: EigenRange<f64>,
+    abstol: Option<f64>,
+) -> Result<(Vec<f64>, Option<Vec<f64>>), BlasError> {
+    check_ld("lda", n.max(1), lda)?;
+    check_len("a", strided_len(n, n, lda), a.len())?;
+    let vectors = matches!(job, EigenJob::ValuesAndVectors);
+    let empty = |vectors: bool| (Vec::new(), if vectors { Some(Vec::new()) } else { None });
+    let (vl, vu, il, iu) = match &range {
+        EigenRange::All => (0.0, 0.0, 1, 1),
+        EigenRange::Values(lower, upper) => {
+            if lower.is_nan() || upper.is_nan() || lower >= upper {
+                return Ok(empty(vectors));
+            }
+            (*lower, *upper, 1, 1)
+        }
+        EigenRange::Indices(indices) => {
+            if indices.start >= indices.end {
+                return Ok(empty(vectors));
+            }
+            check_indices("range", &[indices.end - 1], n)?;
+            (
+                0.0,
+                0.0,
+                to_c_int(indices.start + 1, "il")?,
+                to_c_int(indices.end, "iu")?,
+            )
+        }
+    };
+    if n == 0 {
+        return Ok(empty(vectors));
+    }
+    let uplo = match (tri, order) {
+        (UpOrLowTriangle::Upper, RowColMajor::ColMajor)
+        | (UpOrLowTriangle::Lower, RowColMajor::RowMajor) => b'U',
+        _ => b'L',
+    } as c_char;
+    let mut a = a.to_vec();
+    let (n_c, lda_c) = (to_c_int(n, "n")?, to_c_int(lda, "lda")?);
+    let ldz = if vectors { n } else { 1 };
+    let ldz_c = to_c_int(ldz, "ldz")?;
+    let abstol = abstol.unwrap_or(2.0 * f64::MIN_POSITIVE);
+    let (jobz, range_c) = (job.to_lapack(), range.to_lapack());
+    let mut w = vec![0.0; n];
+    let mut z = vec![0.0; if vectors { n * n } else { 1 }];
+    let mut isuppz = vec![0; 2 * n];
+    let mut found: c_int = 0;
+    let mut info: c_int = 0;
+    let (mut work_size, mut iwork_size) = (0.0, 0);
+    unsafe {
+        traced!(
+            "dsyevr_",
+            [n],
+            symmetric_eigen_range(
+                &jobz,
+                &range_c,
+                &uplo,
+                &n_c,
+                a.as_mut_ptr(),
+                &lda_c,
+                &vl,
+                &vu,
+                &il,
+                &iu,
+                &abstol,
+                &mut found,
+                w.as_mut_ptr(),
+                z.as_mut_ptr(),
+                &ldz_c,
+                isuppz.as_mut_ptr(),
+                &mut work_size,
+                &-1,
+                &mut iwork_size,
+                &-1,
+                &mut info,
+            )
+        );
+    }
+    let mut work = vec![0.0; (work_size as usize).max(1)];
+    let mut iwork = vec![0; (iwork_size as usize).max(1)];
+    let (lwork, liwork) = (
+        to_c_int(work.len(), "lwork")?,
+        to_c_int(iwork.len(), "liwork")?,
+    );
+    unsafe {
+        traced!(
+            "dsyevr_",
+            [n],
+            symmetric_eigen_range(
+                &jobz,
+                &range_c,
+                &uplo,
+                &n_c,
+                a.as_mut_ptr(),
+                &lda_c,
+                &vl,
+                &vu,
+                &il,
+                &iu,
+                &abstol,
+                &mut found,
+                w.as_mut_ptr(),
+                z.as_mut_ptr(),
+                &ldz_c,
+                isuppz.as_mut_ptr(),
+                work.as_mut_ptr(),
+                &lwork,
+                iwork.as_mut_ptr(),
+                &liwork,
+                &mut info,
+            )
+        );
+    }
+    // Every argument was validated above, so only an internal failure (info > 0) can be reported.
+    if info > 0 {
+        return Err(BlasError::NoConvergence {
+            unconverged: info as usize,
+        });
+    }
+    let m = found as usize;
+    w.truncate(m);
+    if !vectors {
+        return Ok((w, None));
+    }
+    z.truncate(n * m);
+    if let RowColMajor::RowMajor = order {
+        // LAPACK returns the eigenvectors column-major; lay them out row-major as columns of an n x m matrix.
+        let mut row_major = vec![0.0; n * m];
+        for j in 0..m {
+            for i in 0..n {
+                row_major[i * m + j] = z[j * n + i];
+            }
+        }
+        z = row_major;
+    }
+    Ok((w, Some(z)))
+}
+
+/// Computes `C = alpha * op(A) * op(B) + beta * C` with `cblas_dgemm`, after validating every operand.
+///
+/// This is the checked counterpart of [`mat_mul`].
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `trans_a`, `trans_b`: Whether `A` and `B` are used as stored or transposed. Use `TransposeMode`.
+/// - `m`, `n`, `k`: `op(A)` is `m x k`, `op(B)` is `k x n` and `C` is `m x n`.
+/// - `alpha`: The scalar applied to the product.
+/// - `a`, `lda`: The matrix `A` and its leading dimension.
+/// - `b`, `ldb`: The matrix `B` and its leading dimension.
+/// - `beta`: The scalar applied to `C` before accumulation. With `beta = 0`, `C` need not be initialized.
+/// - `c`, `ldc`: The matrix `C`, overwritten with the result, and its leading dimension.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda`, `ldb` or `ldc` is below its minimum and
+/// [`BlasError::BufferTooSmall`] if `a`, `b` or `c` is too short.
+#[allow(clippy::too_many_arguments)]
+pub fn dgemm(
+    major: RowColMajor,
+    trans_a: TransposeMode,
+    trans_b: TransposeMode,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: f64,
+    a: &[f64],
+    lda: usize,
+    b: &[f64],
+    ldb: usize,
+    beta: f64,
+    c: &mut [f64],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let shapes = gemm_shapes(trans_a, trans_b, m, n, k);
+    for (which_ld, which, (rows, cols), ld, len) in [
+        ("lda", "a", (shapes.a_rows, shapes.a_cols), lda, a.len()),
+        ("ldb", "b", (shapes.b_rows, shapes.b_cols), ldb, b.len()),
+        ("ldc", "c", (shapes.c_rows, shapes.c_cols), ldc, c.len()),
+    ] {
+        let (outer, inner) = match major {
+            RowColMajor::RowMajor => (rows, cols),
+            RowColMajor::ColMajor => (cols, rows),
+        };
+        check_ld(which_ld, inner.max(1), ld)?;
+        check_len(which, strided_len(outer, inner, ld), len)?;
+    }
+    unsafe {
+        traced!(
+            "cblas_dgemm",
+            [m, n, k],
+            mat_mul(
+                major,
+                trans_a,
+                trans_b,
+                to_c_int(m, "m")?,
+                to_c_int(n, "n")?,
+                to_c_int(k, "k")?,
+                alpha,
+                a.as_ptr(),
+                to_c_int(lda, "lda")?,
+                b.as_ptr(),
+                to_c_int(ldb, "ldb")?,
+                beta,
+                c.as_mut_ptr(),
+                to_c_int(ldc, "ldc")?,
+            )
+        );
+    }
+    Ok(())
 }
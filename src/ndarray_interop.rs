@@ -0,0 +1,116 @@
+use crate::constants::{RowColMajor, TransposeMode};
+use crate::error::BlasError;
+use crate::scalar::{self, BlasScalar};
+use crate::validate::check_shape;
+use ndarray::{Array1, Array2, ArrayView1, ArrayView2, CowArray, Ix2};
+
+/// Computes the matrix product `a * b` of two `ndarray` matrices with `cblas_?gemm`.
+///
+/// # Parameters
+/// - `a`: The `m x k` left-hand factor.
+/// - `b`: The `k x n` right-hand factor.
+///
+/// # Returns
+/// The `m x n` product in standard (row-major) layout.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `a` has a different number of columns than `b` has rows.
+///
+/// # Discussion
+/// Each operand's layout is read from the array itself: a standard-layout (C order) array is passed as
+/// stored, and a Fortran-order array, such as the result of `.t()` or `.reversed_axes()`, is passed as the
+/// transpose of its buffer, so neither is copied. Any other view (a strided slice, or one with negative
+/// strides) has no leading dimension BLAS could use and is first copied with `.to_owned()`.
+pub fn gemm<T: BlasScalar>(
+    a: ArrayView2<'_, T>,
+    b: ArrayView2<'_, T>,
+) -> Result<Array2<T>, BlasError> {
+    let (m, k) = a.dim();
+    let (k_b, n) = b.dim();
+    check_shape("b", k, k_b)?;
+    let (a, trans_a, lda) = contiguous(a);
+    let (b, trans_b, ldb) = contiguous(b);
+    let mut c = Array2::from_elem((m, n), T::ZERO);
+    scalar::gemm(
+        RowColMajor::RowMajor,
+        trans_a,
+        trans_b,
+        m,
+        n,
+        k,
+        T::ONE,
+        memory_order(&a),
+        lda,
+        memory_order(&b),
+        ldb,
+        T::ZERO,
+        c.as_slice_mut().expect("a new array is in standard layout"),
+        n.max(1),
+    )?;
+    Ok(c)
+}
+
+/// Computes the matrix-vector product `a * x` with `cblas_?gemv`.
+///
+/// # Parameters
+/// - `a`: The `m x n` matrix.
+/// - `x`: The vector of length `n`.
+///
+/// # Returns
+/// The product, of length `m`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `x` does not have `n` elements.
+///
+/// # Discussion
+/// `a` is handled as in [`gemm`]: C- and Fortran-order arrays are used in place, and anything else is
+/// copied first. A strided `x` is copied into a contiguous buffer.
+pub fn gemv<T: BlasScalar>(
+    a: ArrayView2<'_, T>,
+    x: ArrayView1<'_, T>,
+) -> Result<Array1<T>, BlasError> {
+    let (m, n) = a.dim();
+    check_shape("x", n, x.len())?;
+    let (a, trans, lda) = contiguous(a);
+    // A Fortran-order `a` is stored as the row-major `n x m` matrix `a^T`.
+    let (rows, cols) = match trans {
+        TransposeMode::NoTrans => (m, n),
+        _ => (n, m),
+    };
+    let x = x.as_standard_layout();
+    let mut y = Array1::from_elem(m, T::ZERO);
+    scalar::gemv(
+        RowColMajor::RowMajor,
+        trans,
+        rows,
+        cols,
+        T::ONE,
+        memory_order(&a),
+        lda,
+        x.as_slice()
+            .expect("a standard-layout vector is contiguous"),
+        T::ZERO,
+        y.as_slice_mut().expect("a new array is in standard layout"),
+    )?;
+    Ok(y)
+}
+
+/// Returns `view` (copied if it is neither C- nor Fortran-contiguous) with the transpose flag and leading
+/// dimension under which a row-major BLAS call reads it as the original matrix.
+fn contiguous<T: Clone>(view: ArrayView2<'_, T>) -> (CowArray<'_, T, Ix2>, TransposeMode, usize) {
+    let (rows, cols) = view.dim();
+    if view.is_standard_layout() {
+        (view.into(), TransposeMode::NoTrans, cols.max(1))
+    } else if view.t().is_standard_layout() {
+        (view.into(), TransposeMode::Trans, rows.max(1))
+    } else {
+        (view.to_owned().into(), TransposeMode::NoTrans, cols.max(1))
+    }
+}
+
+/// The elements of a contiguous array in memory order.
+fn memory_order<'a, T>(array: &'a CowArray<'_, T, Ix2>) -> &'a [T] {
+    array
+        .as_slice_memory_order()
+        .expect("contiguous() only returns C- or Fortran-contiguous arrays")
+}
@@ -0,0 +1,335 @@
+use crate::constants::{gemm_shapes, RowColMajor, TransposeMode};
+use crate::error::BlasError;
+use crate::validate::{check_ld, check_len, strided_len, to_c_int};
+use crate::{matrix_c32, matrix_c64, matrix_f32, matrix_f64};
+use num_complex::Complex;
+use std::mem::{align_of, size_of};
+
+/// The four element types Accelerate's BLAS supports.
+///
+/// The discriminants follow the BLAS precision prefixes in order: `s`, `d`, `c`, `z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Precision {
+    /// Single-precision real (`f32`), BLAS prefix `s`.
+    F32,
+    /// Double-precision real (`f64`), BLAS prefix `d`.
+    F64,
+    /// Single-precision complex (`Complex<f32>`), BLAS prefix `c`.
+    C32,
+    /// Double-precision complex (`Complex<f64>`), BLAS prefix `z`.
+    C64,
+}
+
+impl Precision {
+    /// Size in bytes of one element of this precision.
+    pub fn element_size(self) -> usize {
+        match self {
+            Precision::F32 => size_of::<f32>(),
+            Precision::F64 => size_of::<f64>(),
+            Precision::C32 => size_of::<Complex<f32>>(),
+            Precision::C64 => size_of::<Complex<f64>>(),
+        }
+    }
+
+    /// Required alignment in bytes of one element of this precision.
+    pub fn element_align(self) -> usize {
+        match self {
+            Precision::F32 => align_of::<f32>(),
+            Precision::F64 => align_of::<f64>(),
+            Precision::C32 => align_of::<Complex<f32>>(),
+            Precision::C64 => align_of::<Complex<f64>>(),
+        }
+    }
+}
+
+/// Returns the exact Accelerate symbol behind a BLAS operation at a given precision.
+///
+/// `op` is the precision-free BLAS name (`"gemm"`, `"nrm2"`, `"iamax"`, ...) and the result is the symbol the
+/// crate's friendly binding links against, as named in its `#[link_name]` attribute. For example
+/// `("gemm", Precision::C64)` gives `"cblas_zgemm"`, the symbol behind `matrix_c64::mat_mul`, and
+/// `("nrm2", Precision::C32)` gives `"cblas_scnrm2"`.
+///
+/// Operations that only exist for some precisions follow BLAS: `dot`, `ger` and the `sy*`/`sp*`/`sbmv`
+/// level-2 routines are real-only, while `dotc`/`dotu`, `geru`/`gerc` and the Hermitian `he*`/`hp*`/`hbmv`
+/// routines are complex-only. `axpby` and `set` map to Accelerate's `catlas_` extensions.
+///
+/// # Returns
+/// `None` if `op` is unknown or has no variant at `prec`.
+pub fn symbol_name(op: &str, prec: Precision) -> Option<&'static str> {
+    let symbols: [&'static str; 4] = match op {
+        "axpby" => [
+            "catlas_saxpby",
+            "catlas_daxpby",
+            "catlas_caxpby",
+            "catlas_zaxpby",
+        ],
+        "set" => ["catlas_sset", "catlas_dset", "catlas_cset", "catlas_zset"],
+        "axpy" => ["cblas_saxpy", "cblas_daxpy", "cblas_caxpy", "cblas_zaxpy"],
+        "copy" => ["cblas_scopy", "cblas_dcopy", "cblas_ccopy", "cblas_zcopy"],
+        "swap" => ["cblas_sswap", "cblas_dswap", "cblas_cswap", "cblas_zswap"],
+        "scal" => ["cblas_sscal", "cblas_dscal", "cblas_cscal", "cblas_zscal"],
+        "dot" => ["cblas_sdot", "cblas_ddot", "", ""],
+        "dotc" => ["", "", "cblas_cdotc_sub", "cblas_zdotc_sub"],
+        "dotu" => ["", "", "cblas_cdotu_sub", "cblas_zdotu_sub"],
+        "nrm2" => ["cblas_snrm2", "cblas_dnrm2", "cblas_scnrm2", "cblas_dznrm2"],
+        "asum" => ["cblas_sasum", "cblas_dasum", "cblas_scasum", "cblas_dzasum"],
+        "iamax" => [
+            "cblas_isamax",
+            "cblas_idamax",
+            "cblas_icamax",
+            "cblas_izamax",
+        ],
+        "rot" => ["cblas_srot", "cblas_drot", "cblas_csrot", "cblas_zsrot"],
+        "rotg" => ["cblas_srotg", "cblas_drotg", "cblas_crotg", "cblas_zrotg"],
+        "rotm" => ["cblas_srotm", "cblas_drotm", "", ""],
+        "rotmg" => ["cblas_srotmg", "cblas_drotmg", "", ""],
+        "gemv" => ["cblas_sgemv", "cblas_dgemv", "cblas_cgemv", "cblas_zgemv"],
+        "gbmv" => ["cblas_sgbmv", "cblas_dgbmv", "cblas_cgbmv", "cblas_zgbmv"],
+        "trmv" => ["cblas_strmv", "cblas_dtrmv", "cblas_ctrmv", "cblas_ztrmv"],
+        "trsv" => ["cblas_strsv", "cblas_dtrsv", "cblas_ctrsv", "cblas_ztrsv"],
+        "tbmv" => ["cblas_stbmv", "cblas_dtbmv", "cblas_ctbmv", "cblas_ztbmv"],
+        "tbsv" => ["cblas_stbsv", "cblas_dtbsv", "cblas_ctbsv", "cblas_ztbsv"],
+        "tpmv" => ["cblas_stpmv", "cblas_dtpmv", "cblas_ctpmv", "cblas_ztpmv"],
+        "tpsv" => ["cblas_stpsv", "cblas_dtpsv", "cblas_ctpsv", "cblas_ztpsv"],
+        "ger" => ["cblas_sger", "cblas_dger", "", ""],
+        "geru" => ["", "", "cblas_cgeru", "cblas_zgeru"],
+        "gerc" => ["", "", "cblas_cgerc", "cblas_zgerc"],
+        "symv" => ["cblas_ssymv", "cblas_dsymv", "", ""],
+        "sbmv" => ["cblas_ssbmv", "cblas_dsbmv", "", ""],
+        "spmv" => ["cblas_sspmv", "cblas_dspmv", "", ""],
+        "syr" => ["cblas_ssyr", "cblas_dsyr", "", ""],
+        "syr2" => ["cblas_ssyr2", "cblas_dsyr2", "", ""],
+        "spr" => ["cblas_sspr", "cblas_dspr", "", ""],
+        "spr2" => ["cblas_sspr2", "cblas_dspr2", "", ""],
+        "hemv" => ["", "", "cblas_chemv", "cblas_zhemv"],
+        "hbmv" => ["", "", "cblas_chbmv", "cblas_zhbmv"],
+        "hpmv" => ["", "", "cblas_chpmv", "cblas_zhpmv"],
+        "her" => ["", "", "cblas_cher", "cblas_zher"],
+        "her2" => ["", "", "cblas_cher2", "cblas_zher2"],
+        "hpr" => ["", "", "cblas_chpr", "cblas_zhpr"],
+        "hpr2" => ["", "", "cblas_chpr2", "cblas_zhpr2"],
+        "gemm" => ["cblas_sgemm", "cblas_dgemm", "cblas_cgemm", "cblas_zgemm"],
+        "symm" => ["cblas_ssymm", "cblas_dsymm", "cblas_csymm", "cblas_zsymm"],
+        "syrk" => ["cblas_ssyrk", "cblas_dsyrk", "cblas_csyrk", "cblas_zsyrk"],
+        "syr2k" => [
+            "cblas_ssyr2k",
+            "cblas_dsyr2k",
+            "cblas_csyr2k",
+            "cblas_zsyr2k",
+        ],
+        "trmm" => ["cblas_strmm", "cblas_dtrmm", "cblas_ctrmm", "cblas_ztrmm"],
+        "trsm" => ["cblas_strsm", "cblas_dtrsm", "cblas_ctrsm", "cblas_ztrsm"],
+        "hemm" => ["", "", "cblas_chemm", "cblas_zhemm"],
+        "herk" => ["", "", "cblas_cherk", "cblas_zherk"],
+        "her2k" => ["", "", "cblas_cher2k", "cblas_zher2k"],
+        _ => return None,
+    };
+    Some(symbols[prec as usize]).filter(|symbol| !symbol.is_empty())
+}
+
+/// Computes `C = alpha * op(A) * op(B) + beta * C` on type-erased byte buffers, choosing the `cblas_?gemm`
+/// kernel from `prec` at runtime.
+///
+/// This is for callers that only learn the element type at runtime, such as interpreters or loaders reading
+/// a dtype from a file header. The buffers hold native-endian elements of `prec`; code that knows its element
+/// type at compile time should call the typed bindings instead.
+///
+/// # Parameters
+/// - `prec`: The element type of `a`, `b` and `c`.
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `trans_a`, `trans_b`: Whether `A` and `B` are used as stored, transposed or conjugate-transposed.
+/// - `m`, `n`, `k`: `op(A)` is `m x k`, `op(B)` is `k x n` and `C` is `m x n`.
+/// - `alpha`, `beta`: The scalars, given as `Complex<f64>` and narrowed to `prec`. The imaginary parts are
+///   ignored for the real precisions.
+/// - `a`, `b`, `c`: The matrices as raw bytes; `c` is overwritten with the result.
+/// - `lda`, `ldb`, `ldc`: The leading dimensions, in elements.
+///
+/// # Errors
+/// Returns [`BlasError::Misaligned`] if a buffer is not aligned for `prec`,
+/// [`BlasError::InvalidLeadingDimension`] if a leading dimension is below its minimum, and
+/// [`BlasError::BufferTooSmall`] (counted in elements) if a buffer is too short.
+#[allow(clippy::too_many_arguments)]
+pub fn gemm_dynamic(
+    prec: Precision,
+    major: RowColMajor,
+    trans_a: TransposeMode,
+    trans_b: TransposeMode,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: Complex<f64>,
+    a: &[u8],
+    lda: usize,
+    b: &[u8],
+    ldb: usize,
+    beta: Complex<f64>,
+    c: &mut [u8],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let shapes = gemm_shapes(trans_a, trans_b, m, n, k);
+    let (a_rows, a_cols) = (shapes.a_rows, shapes.a_cols);
+    let (b_rows, b_cols) = (shapes.b_rows, shapes.b_cols);
+    check_erased(prec, major, "a", a_rows, a_cols, lda, a.as_ptr(), a.len())?;
+    check_erased(prec, major, "b", b_rows, b_cols, ldb, b.as_ptr(), b.len())?;
+    check_erased(prec, major, "c", m, n, ldc, c.as_ptr(), c.len())?;
+    let (m_c, n_c, k_c) = (to_c_int(m, "m")?, to_c_int(n, "n")?, to_c_int(k, "k")?);
+    let (lda, ldb, ldc) = (
+        to_c_int(lda, "lda")?,
+        to_c_int(ldb, "ldb")?,
+        to_c_int(ldc, "ldc")?,
+    );
+    unsafe {
+        match prec {
+            Precision::F32 => traced!(
+                "cblas_sgemm",
+                [m, n, k],
+                matrix_f32::mat_mul(
+                    major,
+                    trans_a,
+                    trans_b,
+                    m_c,
+                    n_c,
+                    k_c,
+                    alpha.re as f32,
+                    a.as_ptr().cast(),
+                    lda,
+                    b.as_ptr().cast(),
+                    ldb,
+                    beta.re as f32,
+                    c.as_mut_ptr().cast(),
+                    ldc,
+                )
+            ),
+            Precision::F64 => traced!(
+                "cblas_dgemm",
+                [m, n, k],
+                matrix_f64::mat_mul(
+                    major,
+                    trans_a,
+                    trans_b,
+                    m_c,
+                    n_c,
+                    k_c,
+                    alpha.re,
+                    a.as_ptr().cast(),
+                    lda,
+                    b.as_ptr().cast(),
+                    ldb,
+                    beta.re,
+                    c.as_mut_ptr().cast(),
+                    ldc,
+                )
+            ),
+            Precision::C32 => {
+                let alpha = Complex::new(alpha.re as f32, alpha.im as f32);
+                let beta = Complex::new(beta.re as f32, beta.im as f32);
+                traced!(
+                    "cblas_cgemm",
+                    [m, n, k],
+                    matrix_c32::mat_mul_add(
+                        major,
+                        trans_a,
+                        trans_b,
+                        m_c,
+                        n_c,
+                        k_c,
+                        &alpha,
+                        a.as_ptr().cast(),
+                        lda,
+                        b.as_ptr().cast(),
+                        ldb,
+                        &beta,
+                        c.as_mut_ptr().cast(),
+                        ldc,
+                    )
+                )
+            }
+            Precision::C64 => traced!(
+                "cblas_zgemm",
+                [m, n, k],
+                matrix_c64::mat_mul_add(
+                    major,
+                    trans_a,
+                    trans_b,
+                    m_c,
+                    n_c,
+                    k_c,
+                    &alpha,
+                    a.as_ptr().cast(),
+                    lda,
+                    b.as_ptr().cast(),
+                    ldb,
+                    &beta,
+                    c.as_mut_ptr().cast(),
+                    ldc,
+                )
+            ),
+        }
+    }
+    Ok(())
+}
+
+/// Validates a type-erased `rows x cols` matrix: alignment, leading dimension and length in elements.
+#[allow(clippy::too_many_arguments)]
+fn check_erased(
+    prec: Precision,
+    major: RowColMajor,
+    which: &'static str,
+    rows: usize,
+    cols: usize,
+    ld: usize,
+    ptr: *const u8,
+    bytes: usize,
+) -> Result<(), BlasError> {
+    let align = prec.element_align();
+    if ptr as usize % align != 0 {
+        return Err(BlasError::Misaligned { which, align });
+    }
+    let (outer, inner) = match major {
+        RowColMajor::RowMajor => (rows, cols),
+        RowColMajor::ColMajor => (cols, rows),
+    };
+    check_ld(which, inner.max(1), ld)?;
+    check_len(
+        which,
+        strided_len(outer, inner, ld),
+        bytes / prec.element_size(),
+    )
+}
+
+/// Converts an `f32` matrix (or vector) buffer to `f64`, element by element, keeping its layout.
+///
+/// Every `f32` is exactly representable as an `f64`, so this is lossless. The result has the same shape,
+/// leading dimension and storage order as `a`, padding included.
+pub fn widen_matrix(a: &[f32]) -> Vec<f64> {
+    a.iter().map(|&v| f64::from(v)).collect()
+}
+
+/// Converts an `f64` matrix (or vector) buffer to `f32`, element by element, keeping its layout.
+///
+/// # Discussion
+/// This loses precision: every value is rounded to the nearest `f32`, keeping about 7 significant decimal
+/// digits of the 16 an `f64` carries. Values beyond `f32::MAX` in magnitude become infinite, and values
+/// below `f32::MIN_POSITIVE` become subnormal or zero. Narrow only results whose accuracy `f32` can hold,
+/// typically after computing in `f64` for stability.
+pub fn narrow_matrix(a: &[f64]) -> Vec<f32> {
+    a.iter().map(|&v| v as f32).collect()
+}
+
+/// Converts a `Complex<f32>` buffer to `Complex<f64>`, losslessly, keeping its layout.
+pub fn widen_complex(a: &[Complex<f32>]) -> Vec<Complex<f64>> {
+    a.iter()
+        .map(|z| Complex::new(f64::from(z.re), f64::from(z.im)))
+        .collect()
+}
+
+/// Converts a `Complex<f64>` buffer to `Complex<f32>`, keeping its layout.
+///
+/// # Discussion
+/// The real and imaginary parts are each rounded as by [`narrow_matrix`], with the same loss of precision
+/// and range.
+pub fn narrow_complex(a: &[Complex<f64>]) -> Vec<Complex<f32>> {
+    a.iter()
+        .map(|z| Complex::new(z.re as f32, z.im as f32))
+        .collect()
+}
@@ -0,0 +1,17 @@
+//! The commonly used types and wrappers, for a single glob import.
+//!
+//! The raw per-precision bindings are deliberately left out, since the same names exist in every
+//! precision module; call them through their module, as in `matrix_f32::mat_mul`.
+
+pub use crate::constants::{
+    CblasDiag, CblasOrder, CblasSide, CblasTranspose, CblasUpLow, EigenJob, EigenRange, IsDiagUnit,
+    MultiplyOrder, RowColMajor, SvdJob, TransposeMode, UpOrLowTriangle,
+};
+pub use crate::dense::DenseMatrix;
+pub use crate::error::BlasError;
+pub use crate::matrix_f32::sgemm;
+pub use crate::matrix_f64::dgemm;
+pub use crate::scalar::{axpy, dot, gemm, gemm_views, gemv, nrm2, BlasScalar};
+pub use crate::vector_f32::sdot;
+pub use crate::vector_f64::ddot;
+pub use crate::view::{MatrixView, MatrixViewMut};
@@ -0,0 +1,79 @@
+use crate::constants::{RowColMajor, TransposeMode, UpOrLowTriangle};
+use crate::matrix_f32;
+use crate::validate::to_c_int;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Returns an `m x n` matrix of independent values drawn uniformly from `[-1, 1)`, tightly packed in `order`.
+///
+/// The same `seed` always yields the same matrix, and the logical matrix does not depend on `order`: only
+/// its layout in the returned buffer does. This makes the output usable as a fixed fixture in tests and
+/// benchmarks.
+///
+/// # Parameters
+/// - `m`, `n`: The number of rows and columns.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `seed`: Seed for the generator.
+pub fn random_matrix(m: usize, n: usize, order: RowColMajor, seed: u64) -> Vec<f32> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut out = vec![0.0; m * n];
+    // Draw in row-major order so the values land on the same (i, j) whatever the layout.
+    for i in 0..m {
+        for j in 0..n {
+            let index = match order {
+                RowColMajor::RowMajor => i * n + j,
+                RowColMajor::ColMajor => j * m + i,
+            };
+            out[index] = rng.gen_range(-1.0..1.0);
+        }
+    }
+    out
+}
+
+/// Returns a symmetric positive-definite `n x n` matrix `X^T X + n * I`, where `X` is
+/// [`random_matrix(n, n, order, seed)`](random_matrix).
+///
+/// `X^T X` is positive semi-definite with eigenvalues below `n^2`, so the shift by `n` puts every eigenvalue
+/// of the result in `[n, n + n^2)` and bounds its condition number by `n + 1`. Both triangles are filled,
+/// making it a ready input for Cholesky factorizations and symmetric solves.
+///
+/// # Parameters
+/// - `n`: The order of the matrix.
+/// - `order`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `seed`: Seed for the generator.
+///
+/// # Panics
+/// Panics if `n` does not fit in a C `int`.
+pub fn random_spd(n: usize, order: RowColMajor, seed: u64) -> Vec<f32> {
+    let x = random_matrix(n, n, order, seed);
+    let mut out = vec![0.0; n * n];
+    if n == 0 {
+        return out;
+    }
+    let n_c = to_c_int(n, "n").unwrap();
+    unsafe {
+        traced!(
+            "cblas_ssyrk",
+            [n],
+            matrix_f32::sym_rank_k_update(
+                order,
+                UpOrLowTriangle::Upper,
+                TransposeMode::Trans,
+                n_c,
+                n_c,
+                1.0,
+                x.as_ptr(),
+                n_c,
+                0.0,
+                out.as_mut_ptr(),
+                n_c,
+            )
+        );
+    }
+    // `ssyrk` only writes the upper triangle; the buffer is n x n with lda = n, so this cannot fail.
+    matrix_f32::symmetrize(order, UpOrLowTriangle::Upper, n, &mut out, n).unwrap();
+    for i in 0..n {
+        out[i * n + i] += n as f32;
+    }
+    out
+}
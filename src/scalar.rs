@@ -0,0 +1,639 @@
+use crate::constants::{gemm_shapes, RowColMajor, TransposeMode};
+use crate::error::BlasError;
+use crate::validate::{
+    check_inc, check_ld, check_len, check_shape, strided_len, to_c_int, vec_len,
+};
+use crate::view::{outer_inner, MatrixView, MatrixViewMut};
+use crate::{matrix_c32, matrix_c64, matrix_f32, matrix_f64};
+use crate::{vector_c32, vector_c64, vector_f32, vector_f64};
+use num_complex::Complex;
+use std::ffi::c_int;
+
+/// Element types accepted by the generic wrappers in this module and by
+/// [`DenseMatrix`](crate::dense::DenseMatrix), each dispatching to its own `cblas_?` routine.
+///
+/// Code written against `T: BlasScalar` runs unchanged in all four precisions: the trait picks
+/// `cblas_sgemm`, `cblas_dgemm`, `cblas_cgemm` or `cblas_zgemm` (and likewise for the other routines) from
+/// the element type, and passes complex scalars by pointer as CBLAS expects.
+pub trait BlasScalar: Copy {
+    /// The real type of the same precision, returned by norms.
+    type Real: Copy;
+
+    /// The additive identity, used to fill freshly allocated results.
+    const ZERO: Self;
+    /// The multiplicative identity, used as `alpha` by the allocating products.
+    const ONE: Self;
+
+    /// Forwards to the matching `cblas_?gemm` binding.
+    ///
+    /// # Safety
+    /// Same contract as the underlying `cblas_?gemm`: `a`, `b` and `c` must be valid for the given
+    /// dimensions and leading dimensions.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn gemm(
+        major: RowColMajor,
+        trans_a: TransposeMode,
+        trans_b: TransposeMode,
+        m: c_int,
+        n: c_int,
+        k: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        b: *const Self,
+        ldb: c_int,
+        beta: Self,
+        c: *mut Self,
+        ldc: c_int,
+    );
+
+    /// Forwards to the matching `cblas_?gemv` binding.
+    ///
+    /// # Safety
+    /// Same contract as the underlying `cblas_?gemv`: `a` must be valid for the given dimensions and
+    /// leading dimension, and `x` and `y` for their lengths and increments.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn gemv(
+        major: RowColMajor,
+        trans: TransposeMode,
+        m: c_int,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        x: *const Self,
+        inc_x: c_int,
+        beta: Self,
+        y: *mut Self,
+        inc_y: c_int,
+    );
+
+    /// Forwards to the matching `cblas_?axpy` binding.
+    ///
+    /// # Safety
+    /// `x` and `y` must be valid for `n` elements at their increments.
+    unsafe fn axpy(n: c_int, alpha: Self, x: *const Self, inc_x: c_int, y: *mut Self, inc_y: c_int);
+
+    /// Forwards to `cblas_sdot` or `cblas_ddot`, or to `cblas_cdotc_sub` or `cblas_zdotc_sub` for complex
+    /// types, so the first vector is conjugated.
+    ///
+    /// # Safety
+    /// `x` and `y` must be valid for `n` elements at their increments.
+    unsafe fn dot(n: c_int, x: *const Self, inc_x: c_int, y: *const Self, inc_y: c_int) -> Self;
+
+    /// Forwards to the matching `cblas_?nrm2` binding (`cblas_scnrm2` or `cblas_dznrm2` for complex types).
+    ///
+    /// # Safety
+    /// `x` must be valid for `n` elements at increment `inc_x`.
+    unsafe fn nrm2(n: c_int, x: *const Self, inc_x: c_int) -> Self::Real;
+}
+
+impl BlasScalar for f32 {
+    type Real = f32;
+
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    unsafe fn gemm(
+        major: RowColMajor,
+        trans_a: TransposeMode,
+        trans_b: TransposeMode,
+        m: c_int,
+        n: c_int,
+        k: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        b: *const Self,
+        ldb: c_int,
+        beta: Self,
+        c: *mut Self,
+        ldc: c_int,
+    ) {
+        traced!(
+            "cblas_sgemm",
+            [m, n, k],
+            matrix_f32::mat_mul(
+                major, trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc
+            )
+        )
+    }
+
+    unsafe fn gemv(
+        major: RowColMajor,
+        trans: TransposeMode,
+        m: c_int,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        x: *const Self,
+        inc_x: c_int,
+        beta: Self,
+        y: *mut Self,
+        inc_y: c_int,
+    ) {
+        traced!(
+            "cblas_sgemv",
+            [m, n],
+            matrix_f32::mat_vec_mul(major, trans, m, n, alpha, a, lda, x, inc_x, beta, y, inc_y)
+        )
+    }
+
+    unsafe fn axpy(
+        n: c_int,
+        alpha: Self,
+        x: *const Self,
+        inc_x: c_int,
+        y: *mut Self,
+        inc_y: c_int,
+    ) {
+        traced!(
+            "cblas_saxpy",
+            [n],
+            vector_f32::scale_plus(n, alpha, x, inc_x, y, inc_y)
+        )
+    }
+
+    unsafe fn dot(n: c_int, x: *const Self, inc_x: c_int, y: *const Self, inc_y: c_int) -> Self {
+        traced!("cblas_sdot", [n], vector_f32::dot(n, x, inc_x, y, inc_y))
+    }
+
+    unsafe fn nrm2(n: c_int, x: *const Self, inc_x: c_int) -> Self::Real {
+        traced!("cblas_snrm2", [n], vector_f32::norm2(n, x, inc_x))
+    }
+}
+
+impl BlasScalar for f64 {
+    type Real = f64;
+
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    unsafe fn gemm(
+        major: RowColMajor,
+        trans_a: TransposeMode,
+        trans_b: TransposeMode,
+        m: c_int,
+        n: c_int,
+        k: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        b: *const Self,
+        ldb: c_int,
+        beta: Self,
+        c: *mut Self,
+        ldc: c_int,
+    ) {
+        traced!(
+            "cblas_dgemm",
+            [m, n, k],
+            matrix_f64::mat_mul(
+                major, trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc
+            )
+        )
+    }
+
+    unsafe fn gemv(
+        major: RowColMajor,
+        trans: TransposeMode,
+        m: c_int,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        x: *const Self,
+        inc_x: c_int,
+        beta: Self,
+        y: *mut Self,
+        inc_y: c_int,
+    ) {
+        traced!(
+            "cblas_dgemv",
+            [m, n],
+            matrix_f64::mat_vec_mul(major, trans, m, n, alpha, a, lda, x, inc_x, beta, y, inc_y)
+        )
+    }
+
+    unsafe fn axpy(
+        n: c_int,
+        alpha: Self,
+        x: *const Self,
+        inc_x: c_int,
+        y: *mut Self,
+        inc_y: c_int,
+    ) {
+        traced!(
+            "cblas_daxpy",
+            [n],
+            vector_f64::lin_comb(n, alpha, x, inc_x, y, inc_y)
+        )
+    }
+
+    unsafe fn dot(n: c_int, x: *const Self, inc_x: c_int, y: *const Self, inc_y: c_int) -> Self {
+        traced!("cblas_ddot", [n], vector_f64::dot(n, x, inc_x, y, inc_y))
+    }
+
+    unsafe fn nrm2(n: c_int, x: *const Self, inc_x: c_int) -> Self::Real {
+        traced!("cblas_dnrm2", [n], vector_f64::norm2(n, x, inc_x))
+    }
+}
+
+impl BlasScalar for Complex<f32> {
+    type Real = f32;
+
+    const ZERO: Self = Complex::new(0.0, 0.0);
+    const ONE: Self = Complex::new(1.0, 0.0);
+
+    unsafe fn gemm(
+        major: RowColMajor,
+        trans_a: TransposeMode,
+        trans_b: TransposeMode,
+        m: c_int,
+        n: c_int,
+        k: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        b: *const Self,
+        ldb: c_int,
+        beta: Self,
+        c: *mut Self,
+        ldc: c_int,
+    ) {
+        traced!(
+            "cblas_cgemm",
+            [m, n, k],
+            matrix_c32::mat_mul_add(
+                major, trans_a, trans_b, m, n, k, &alpha, a, lda, b, ldb, &beta, c, ldc
+            )
+        )
+    }
+
+    unsafe fn gemv(
+        major: RowColMajor,
+        trans: TransposeMode,
+        m: c_int,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        x: *const Self,
+        inc_x: c_int,
+        beta: Self,
+        y: *mut Self,
+        inc_y: c_int,
+    ) {
+        traced!(
+            "cblas_cgemv",
+            [m, n],
+            matrix_c32::mat_vec_mul(major, trans, m, n, &alpha, a, lda, x, inc_x, &beta, y, inc_y)
+        )
+    }
+
+    unsafe fn axpy(
+        n: c_int,
+        alpha: Self,
+        x: *const Self,
+        inc_x: c_int,
+        y: *mut Self,
+        inc_y: c_int,
+    ) {
+        traced!(
+            "cblas_caxpy",
+            [n],
+            vector_c32::scaled_plus(n, &alpha, x, inc_x, y, inc_y)
+        )
+    }
+
+    unsafe fn dot(n: c_int, x: *const Self, inc_x: c_int, y: *const Self, inc_y: c_int) -> Self {
+        let mut dotc = Self::ZERO;
+        traced!(
+            "cblas_cdotc_sub",
+            [n],
+            vector_c32::dot_conj_plus(n, x, inc_x, y, inc_y, &mut dotc)
+        );
+        dotc
+    }
+
+    unsafe fn nrm2(n: c_int, x: *const Self, inc_x: c_int) -> Self::Real {
+        traced!("cblas_scnrm2", [n], vector_c32::norm2(n, x, inc_x))
+    }
+}
+
+impl BlasScalar for Complex<f64> {
+    type Real = f64;
+
+    const ZERO: Self = Complex::new(0.0, 0.0);
+    const ONE: Self = Complex::new(1.0, 0.0);
+
+    unsafe fn gemm(
+        major: RowColMajor,
+        trans_a: TransposeMode,
+        trans_b: TransposeMode,
+        m: c_int,
+        n: c_int,
+        k: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        b: *const Self,
+        ldb: c_int,
+        beta: Self,
+        c: *mut Self,
+        ldc: c_int,
+    ) {
+        traced!(
+            "cblas_zgemm",
+            [m, n, k],
+            matrix_c64::mat_mul_add(
+                major, trans_a, trans_b, m, n, k, &alpha, a, lda, b, ldb, &beta, c, ldc
+            )
+        )
+    }
+
+    unsafe fn gemv(
+        major: RowColMajor,
+        trans: TransposeMode,
+        m: c_int,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        x: *const Self,
+        inc_x: c_int,
+        beta: Self,
+        y: *mut Self,
+        inc_y: c_int,
+    ) {
+        traced!(
+            "cblas_zgemv",
+            [m, n],
+            matrix_c64::mat_vec_mul(major, trans, m, n, &alpha, a, lda, x, inc_x, &beta, y, inc_y)
+        )
+    }
+
+    unsafe fn axpy(
+        n: c_int,
+        alpha: Self,
+        x: *const Self,
+        inc_x: c_int,
+        y: *mut Self,
+        inc_y: c_int,
+    ) {
+        traced!(
+            "cblas_zaxpy",
+            [n],
+            vector_c64::scaled_plus(n, &alpha, x, inc_x, y, inc_y)
+        )
+    }
+
+    unsafe fn dot(n: c_int, x: *const Self, inc_x: c_int, y: *const Self, inc_y: c_int) -> Self {
+        let mut dotc = Self::ZERO;
+        traced!(
+            "cblas_zdotc_sub",
+            [n],
+            vector_c64::dot_conj(n, x, inc_x, y, inc_y, &mut dotc)
+        );
+        dotc
+    }
+
+    unsafe fn nrm2(n: c_int, x: *const Self, inc_x: c_int) -> Self::Real {
+        traced!("cblas_dznrm2", [n], vector_c64::norm2(n, x, inc_x))
+    }
+}
+
+/// Computes `C = alpha * op(A) * op(B) + beta * C` in any of the four precisions.
+///
+/// This is the checked, generic counterpart of [`sgemm`](crate::matrix_f32::sgemm) and
+/// [`dgemm`](crate::matrix_f64::dgemm): the element type selects `cblas_sgemm`, `cblas_dgemm`,
+/// `cblas_cgemm` or `cblas_zgemm`.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering. Use `RowColMajor`.
+/// - `trans_a`, `trans_b`: Whether `A` and `B` are used as stored, transposed or conjugate-transposed.
+/// - `m`, `n`, `k`: `op(A)` is `m x k`, `op(B)` is `k x n` and `C` is `m x n`.
+/// - `alpha`: The scalar applied to the product.
+/// - `a`, `lda`: The matrix `A` and its leading dimension.
+/// - `b`, `ldb`: The matrix `B` and its leading dimension.
+/// - `beta`: The scalar applied to `C` before accumulation. With `beta = 0` `c` need not be initialized.
+/// - `c`, `ldc`: The matrix `C` and its leading dimension, overwritten with the result.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if a leading dimension is below its minimum for the
+/// stored shape, and [`BlasError::BufferTooSmall`] if `a`, `b` or `c` is too short.
+#[allow(clippy::too_many_arguments)]
+pub fn gemm<T: BlasScalar>(
+    major: RowColMajor,
+    trans_a: TransposeMode,
+    trans_b: TransposeMode,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &[T],
+    ldb: usize,
+    beta: T,
+    c: &mut [T],
+    ldc: usize,
+) -> Result<(), BlasError> {
+    let shapes = gemm_shapes(trans_a, trans_b, m, n, k);
+    for (which_ld, which, (rows, cols), ld, len) in [
+        ("lda", "a", (shapes.a_rows, shapes.a_cols), lda, a.len()),
+        ("ldb", "b", (shapes.b_rows, shapes.b_cols), ldb, b.len()),
+        ("ldc", "c", (shapes.c_rows, shapes.c_cols), ldc, c.len()),
+    ] {
+        let (outer, inner) = outer_inner(rows, cols, major);
+        check_ld(which_ld, inner.max(1), ld)?;
+        check_len(which, strided_len(outer, inner, ld), len)?;
+    }
+    unsafe {
+        T::gemm(
+            major,
+            trans_a,
+            trans_b,
+            to_c_int(m, "m")?,
+            to_c_int(n, "n")?,
+            to_c_int(k, "k")?,
+            alpha,
+            a.as_ptr(),
+            to_c_int(lda, "lda")?,
+            b.as_ptr(),
+            to_c_int(ldb, "ldb")?,
+            beta,
+            c.as_mut_ptr(),
+            to_c_int(ldc, "ldc")?,
+        );
+    }
+    Ok(())
+}
+
+/// Computes `C = alpha * A * B + beta * C` on matrix views, in any of the four precisions.
+///
+/// # Parameters
+/// - `alpha`: The scalar applied to the product.
+/// - `a`: The `m x k` left-hand factor.
+/// - `b`: The `k x n` right-hand factor.
+/// - `beta`: The scalar applied to `c` before accumulation. With `beta = 0` `c` need not be initialized.
+/// - `c`: The `m x n` output, overwritten with the result.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `b` has the wrong number of rows or `c` the wrong shape.
+///
+/// # Discussion
+/// The views carry their own leading dimensions, so sub-matrices of larger buffers are used in place. The
+/// call is made in `c`'s storage order, and an operand stored in the other order, including one obtained
+/// with [`MatrixView::transposed`], is passed as its transpose, which reads the same buffer without
+/// copying.
+pub fn gemm_views<T: BlasScalar>(
+    alpha: T,
+    a: MatrixView<'_, T>,
+    b: MatrixView<'_, T>,
+    beta: T,
+    c: &mut MatrixViewMut<'_, T>,
+) -> Result<(), BlasError> {
+    check_shape("b", a.cols(), b.rows())?;
+    check_shape("c", a.rows(), c.rows())?;
+    check_shape("c", b.cols(), c.cols())?;
+    let order = c.order();
+    let trans = |view: &MatrixView<'_, T>| {
+        if view.order() == order {
+            TransposeMode::NoTrans
+        } else {
+            TransposeMode::Trans
+        }
+    };
+    unsafe {
+        T::gemm(
+            order,
+            trans(&a),
+            trans(&b),
+            to_c_int(c.rows(), "m")?,
+            to_c_int(c.cols(), "n")?,
+            to_c_int(a.cols(), "k")?,
+            alpha,
+            a.as_ptr(),
+            to_c_int(a.lda(), "lda")?,
+            b.as_ptr(),
+            to_c_int(b.lda(), "ldb")?,
+            beta,
+            c.as_mut_ptr(),
+            to_c_int(c.lda(), "ldc")?,
+        );
+    }
+    Ok(())
+}
+
+/// Computes `y = alpha * op(A) * x + beta * y` in any of the four precisions.
+///
+/// # Parameters
+/// - `major`: Specifies row-major (C) or column-major (Fortran) data ordering of `a`. Use `RowColMajor`.
+/// - `trans`: Whether `A` is used as stored, transposed or conjugate-transposed.
+/// - `m`, `n`: The number of rows and columns of `A` as stored.
+/// - `alpha`: The scalar applied to `op(A) * x`.
+/// - `a`, `lda`: The matrix `A` and its leading dimension.
+/// - `x`: The input vector, of length `n` (or `m` when transposed), with unit stride.
+/// - `beta`: The scalar applied to `y`. With `beta = 0` it need not be initialized.
+/// - `y`: The output vector, of length `m` (or `n` when transposed), with unit stride.
+///
+/// # Errors
+/// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is below its minimum,
+/// [`BlasError::BufferTooSmall`] if `a` is too short, and [`BlasError::DimensionMismatch`] if `x` or `y`
+/// has the wrong length.
+#[allow(clippy::too_many_arguments)]
+pub fn gemv<T: BlasScalar>(
+    major: RowColMajor,
+    trans: TransposeMode,
+    m: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    x: &[T],
+    beta: T,
+    y: &mut [T],
+) -> Result<(), BlasError> {
+    let (outer, inner) = outer_inner(m, n, major);
+    check_ld("lda", inner.max(1), lda)?;
+    check_len("a", strided_len(outer, inner, lda), a.len())?;
+    let (x_len, y_len) = match trans {
+        TransposeMode::NoTrans => (n, m),
+        _ => (m, n),
+    };
+    check_shape("x", x_len, x.len())?;
+    check_shape("y", y_len, y.len())?;
+    if y_len == 0 {
+        return Ok(());
+    }
+    unsafe {
+        T::gemv(
+            major,
+            trans,
+            to_c_int(m, "m")?,
+            to_c_int(n, "n")?,
+            alpha,
+            a.as_ptr(),
+            to_c_int(lda, "lda")?,
+            x.as_ptr(),
+            1,
+            beta,
+            y.as_mut_ptr(),
+            1,
+        );
+    }
+    Ok(())
+}
+
+/// Computes `y = alpha * x + y` over `n` strided elements in any of the four precisions.
+///
+/// # Errors
+/// Returns [`BlasError::ZeroIncrement`] if `inc_x` or `inc_y` is zero, and [`BlasError::BufferTooSmall`]
+/// if `x` or `y` is shorter than `(n - 1) * |inc| + 1` elements.
+pub fn axpy<T: BlasScalar>(
+    n: usize,
+    alpha: T,
+    x: &[T],
+    inc_x: i32,
+    y: &mut [T],
+    inc_y: i32,
+) -> Result<(), BlasError> {
+    check_inc("inc_x", inc_x)?;
+    check_len("x", vec_len(n, inc_x), x.len())?;
+    check_inc("inc_y", inc_y)?;
+    check_len("y", vec_len(n, inc_y), y.len())?;
+    unsafe {
+        T::axpy(
+            to_c_int(n, "n")?,
+            alpha,
+            x.as_ptr(),
+            inc_x,
+            y.as_mut_ptr(),
+            inc_y,
+        );
+    }
+    Ok(())
+}
+
+/// Computes the inner product of `x` and `y` in any of the four precisions.
+///
+/// For complex types this is `conj(x) . y` (`cblas_?dotc_sub`), so `dot(x, x)` is the squared norm.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `x` and `y` differ in length.
+pub fn dot<T: BlasScalar>(x: &[T], y: &[T]) -> Result<T, BlasError> {
+    check_shape("y", x.len(), y.len())?;
+    let n = to_c_int(x.len(), "n")?;
+    Ok(unsafe { T::dot(n, x.as_ptr(), 1, y.as_ptr(), 1) })
+}
+
+/// Computes the Euclidean norm of `x` in any of the four precisions, returned in the matching real type.
+///
+/// # Errors
+/// Returns [`BlasError::IntOverflow`] if `x` is longer than a C `int` can count.
+pub fn nrm2<T: BlasScalar>(x: &[T]) -> Result<T::Real, BlasError> {
+    let n = to_c_int(x.len(), "n")?;
+    Ok(unsafe { T::nrm2(n, x.as_ptr(), 1) })
+}
@@ -0,0 +1,153 @@
+use crate::constants::{RowColMajor, TransposeMode};
+use crate::error::BlasError;
+use crate::matrix_f32;
+use crate::validate::check_shape;
+use std::ffi::c_int;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+/// Square sizes probed during calibration, smallest first.
+const CANDIDATE_SIZES: [usize; 6] = [2, 4, 8, 16, 32, 64];
+
+/// Multiply-adds timed per candidate size, so every size is measured over a comparable amount of work.
+const FLOPS_PER_SAMPLE: usize = 1 << 20;
+
+static CALIBRATE: Once = Once::new();
+static THRESHOLD: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns the largest square size `n` at which a plain Rust triple loop beat `cblas_sgemm` on this machine.
+///
+/// [`sgemm_small`] uses its Rust kernel when `m`, `n` and `k` are all at most this value and calls
+/// Accelerate above it. The crossover is measured rather than hard-coded because the per-call overhead of
+/// Accelerate, and so the size where it starts to win, differs noticeably between Apple Silicon generations
+/// and Intel Macs.
+///
+/// # Returns
+/// One of `2, 4, 8, 16, 32, 64`, or `0` if Accelerate was already faster at the smallest size.
+///
+/// # Discussion
+/// The first call runs a short calibration (a few milliseconds) that times both kernels on increasing
+/// square sizes and stops at the first size where Accelerate wins. The result is cached for the life of
+/// the process, so later calls are a single atomic load.
+pub fn small_gemm_threshold() -> usize {
+    CALIBRATE.call_once(|| THRESHOLD.store(calibrate(), Ordering::Relaxed));
+    THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Computes `C = A * B`, using a plain Rust kernel for matrices up to [`small_gemm_threshold`] and
+/// `cblas_sgemm` above it.
+///
+/// # Precision
+/// This function operates on single-precision (`f32`) numbers.
+///
+/// # Parameters
+/// - `a`: The `m x k` matrix `A`, row-major and tightly packed.
+/// - `b`: The `k x n` matrix `B`, row-major and tightly packed.
+/// - `c`: The `m x n` result `C`, row-major and tightly packed, overwritten.
+/// - `dims`: The product's dimensions `(m, n, k)`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `a`, `b` or `c` does not hold the number of elements
+/// `dims` implies.
+///
+/// # Discussion
+/// The Rust kernel is taken only when every one of `m`, `n` and `k` is within the threshold, so a thin but
+/// long product still goes to Accelerate. Both paths compute the same product; they can differ in the last
+/// bits because they sum in different orders.
+pub fn sgemm_small(
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+    dims: (usize, usize, usize),
+) -> Result<(), BlasError> {
+    let (m, n, k) = dims;
+    check_shape("a", m * k, a.len())?;
+    check_shape("b", k * n, b.len())?;
+    check_shape("c", m * n, c.len())?;
+    if m.max(n).max(k) <= small_gemm_threshold() {
+        gemm_naive(m, n, k, a, b, c);
+        return Ok(());
+    }
+    matrix_f32::sgemm(
+        RowColMajor::RowMajor,
+        TransposeMode::NoTrans,
+        TransposeMode::NoTrans,
+        m,
+        n,
+        k,
+        1.0,
+        a,
+        k.max(1),
+        b,
+        n.max(1),
+        0.0,
+        c,
+        n.max(1),
+    )
+}
+
+fn calibrate() -> usize {
+    let mut threshold = 0;
+    for &n in CANDIDATE_SIZES.iter() {
+        let a: Vec<f32> = (0..n * n).map(|i| (i % 7) as f32 - 3.0).collect();
+        let b: Vec<f32> = (0..n * n).map(|i| (i % 5) as f32 - 2.0).collect();
+        let mut c = vec![0.0; n * n];
+        let reps = (FLOPS_PER_SAMPLE / (n * n * n)).max(1);
+
+        let rust = time(reps, || gemm_naive(n, n, n, &a, &b, &mut c));
+        // The candidate sizes are tiny, so the narrowing cannot overflow.
+        let n_c = n as c_int;
+        let accelerate = time(reps, || unsafe {
+            traced!(
+                "cblas_sgemm",
+                [n_c],
+                matrix_f32::mat_mul(
+                    RowColMajor::RowMajor,
+                    TransposeMode::NoTrans,
+                    TransposeMode::NoTrans,
+                    n_c,
+                    n_c,
+                    n_c,
+                    1.0,
+                    a.as_ptr(),
+                    n_c,
+                    b.as_ptr(),
+                    n_c,
+                    0.0,
+                    c.as_mut_ptr(),
+                    n_c,
+                )
+            )
+        });
+        if accelerate < rust {
+            break;
+        }
+        threshold = n;
+    }
+    threshold
+}
+
+fn time<F: FnMut()>(reps: usize, mut f: F) -> Duration {
+    // One untimed run so neither kernel pays for first-touch page faults or lazy symbol binding.
+    f();
+    let start = Instant::now();
+    for _ in 0..reps {
+        f();
+    }
+    start.elapsed()
+}
+
+/// Row-major `C = A * B` for an `m x k` matrix `A` and a `k x n` matrix `B`, both tightly packed.
+fn gemm_naive(m: usize, n: usize, k: usize, a: &[f32], b: &[f32], c: &mut [f32]) {
+    for i in 0..m {
+        let row = &mut c[i * n..(i + 1) * n];
+        row.iter_mut().for_each(|x| *x = 0.0);
+        for p in 0..k {
+            let aip = a[i * k + p];
+            for (x, &bpj) in row.iter_mut().zip(&b[p * n..(p + 1) * n]) {
+                *x += aip * bpj;
+            }
+        }
+    }
+}
@@ -0,0 +1,624 @@
+use crate::constants::{IsDiagUnit, RowColMajor, TransposeMode, UpOrLowTriangle};
+use crate::error::BlasError;
+use crate::scalar::BlasScalar;
+use crate::validate::{
+    check_inc, check_ld, check_len, check_shape, strided_len, to_c_int, vec_len,
+};
+use crate::view::MatrixView;
+use crate::{matrix_c32, matrix_c64, matrix_f32, matrix_f64};
+use num_complex::Complex;
+use std::convert::TryFrom;
+use std::ffi::c_int;
+use std::slice;
+
+/// Element types accepted by [`SquareMatrix`]'s operations, each dispatching to its own `cblas_?` routines.
+///
+/// The identities used to fill and scale freshly allocated results come from [`BlasScalar`].
+pub trait SquareScalar: BlasScalar {
+    /// Forwards to the matching `tri_mat_vec_mul` binding.
+    ///
+    /// # Safety
+    /// Same contract as the underlying `cblas_?trmv`: `a` and `x` must be valid for the given order,
+    /// leading dimension and increment.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn trmv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        n: c_int,
+        a: *const Self,
+        lda: c_int,
+        x: *mut Self,
+        inc_x: c_int,
+    );
+
+    /// Forwards to the matching `tri_solve` binding.
+    ///
+    /// # Safety
+    /// Same contract as the underlying `cblas_?trsv`: `a` and `x` must be valid for the given order,
+    /// leading dimension and increment.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn trsv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        n: c_int,
+        a: *const Self,
+        lda: c_int,
+        x: *mut Self,
+        inc_x: c_int,
+    );
+
+    /// Forwards to `cblas_?symv` for real types and to the Rust `csymv`/`zsymv` kernels for complex ones, so
+    /// the mirrored triangle is used as stored, without conjugation.
+    ///
+    /// # Safety
+    /// `a` must be valid for an `n x n` matrix with the given order and leading dimension, and `x` and `y`
+    /// for `n` contiguous elements each.
+    ///
+    /// # Errors
+    /// Only the complex kernels validate their arguments, and they fail with the same errors as
+    /// [`matrix_c32::csymv`]; the real implementations always return `Ok`.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn symv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        x: *const Self,
+        beta: Self,
+        y: *mut Self,
+    ) -> Result<(), BlasError>;
+
+    /// Forwards to `cblas_?hemv` for complex types and to `cblas_?symv` for real ones, where Hermitian and
+    /// symmetric coincide.
+    ///
+    /// # Safety
+    /// Same contract as the underlying routine: `a`, `x` and `y` must be valid for the given order,
+    /// leading dimension and increments.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn hemv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        x: *const Self,
+        inc_x: c_int,
+        beta: Self,
+        y: *mut Self,
+        inc_y: c_int,
+    );
+}
+
+impl SquareScalar for f32 {
+    unsafe fn trmv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        n: c_int,
+        a: *const Self,
+        lda: c_int,
+        x: *mut Self,
+        inc_x: c_int,
+    ) {
+        traced!(
+            "cblas_strmv",
+            [n],
+            matrix_f32::tri_mat_vec_mul(major, tri, trans_a, diag, n, a, lda, x, inc_x)
+        )
+    }
+
+    unsafe fn trsv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        n: c_int,
+        a: *const Self,
+        lda: c_int,
+        x: *mut Self,
+        inc_x: c_int,
+    ) {
+        traced!(
+            "cblas_strsv",
+            [n],
+            matrix_f32::tri_solve(major, tri, trans_a, diag, n, a, lda, x, inc_x)
+        )
+    }
+
+    unsafe fn symv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        x: *const Self,
+        beta: Self,
+        y: *mut Self,
+    ) -> Result<(), BlasError> {
+        Self::hemv(major, tri, n, alpha, a, lda, x, 1, beta, y, 1);
+        Ok(())
+    }
+
+    unsafe fn hemv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        x: *const Self,
+        inc_x: c_int,
+        beta: Self,
+        y: *mut Self,
+        inc_y: c_int,
+    ) {
+        traced!(
+            "cblas_ssymv",
+            [n],
+            matrix_f32::sym_mat_vec_mul(major, tri, n, alpha, a, lda, x, inc_x, beta, y, inc_y)
+        )
+    }
+}
+
+impl SquareScalar for f64 {
+    unsafe fn trmv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        n: c_int,
+        a: *const Self,
+        lda: c_int,
+        x: *mut Self,
+        inc_x: c_int,
+    ) {
+        traced!(
+            "cblas_dtrmv",
+            [n],
+            matrix_f64::tri_mat_vec_mul(major, tri, trans_a, diag, n, a, lda, x, inc_x)
+        )
+    }
+
+    unsafe fn trsv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        n: c_int,
+        a: *const Self,
+        lda: c_int,
+        x: *mut Self,
+        inc_x: c_int,
+    ) {
+        traced!(
+            "cblas_dtrsv",
+            [n],
+            matrix_f64::tri_solve(major, tri, trans_a, diag, n, a, lda, x, inc_x)
+        )
+    }
+
+    unsafe fn symv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        x: *const Self,
+        beta: Self,
+        y: *mut Self,
+    ) -> Result<(), BlasError> {
+        Self::hemv(major, tri, n, alpha, a, lda, x, 1, beta, y, 1);
+        Ok(())
+    }
+
+    unsafe fn hemv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        x: *const Self,
+        inc_x: c_int,
+        beta: Self,
+        y: *mut Self,
+        inc_y: c_int,
+    ) {
+        traced!(
+            "cblas_dsymv",
+            [n],
+            matrix_f64::sym_mat_vec_mul(major, tri, n, alpha, a, lda, x, inc_x, beta, y, inc_y)
+        )
+    }
+}
+
+impl SquareScalar for Complex<f32> {
+    unsafe fn trmv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        n: c_int,
+        a: *const Self,
+        lda: c_int,
+        x: *mut Self,
+        inc_x: c_int,
+    ) {
+        traced!(
+            "cblas_ctrmv",
+            [n],
+            matrix_c32::tri_mat_vec_mul(major, tri, trans_a, diag, n, a, lda, x, inc_x)
+        )
+    }
+
+    unsafe fn trsv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        n: c_int,
+        a: *const Self,
+        lda: c_int,
+        x: *mut Self,
+        inc_x: c_int,
+    ) {
+        traced!(
+            "cblas_ctrsv",
+            [n],
+            matrix_c32::tri_solve(major, tri, trans_a, diag, n, a, lda, x, inc_x)
+        )
+    }
+
+    unsafe fn symv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        x: *const Self,
+        beta: Self,
+        y: *mut Self,
+    ) -> Result<(), BlasError> {
+        let (n, lda) = (n as usize, lda as usize);
+        matrix_c32::csymv(
+            major,
+            tri,
+            n,
+            alpha,
+            slice::from_raw_parts(a, strided_len(n, n, lda)),
+            lda,
+            slice::from_raw_parts(x, n),
+            beta,
+            slice::from_raw_parts_mut(y, n),
+        )
+    }
+
+    unsafe fn hemv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        x: *const Self,
+        inc_x: c_int,
+        beta: Self,
+        y: *mut Self,
+        inc_y: c_int,
+    ) {
+        traced!(
+            "cblas_chemv",
+            [n],
+            matrix_c32::herm_mat_vec_mul_add(
+                major, tri, n, &alpha, a, lda, x, inc_x, &beta, y, inc_y
+            )
+        )
+    }
+}
+
+impl SquareScalar for Complex<f64> {
+    unsafe fn trmv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        n: c_int,
+        a: *const Self,
+        lda: c_int,
+        x: *mut Self,
+        inc_x: c_int,
+    ) {
+        traced!(
+            "cblas_ztrmv",
+            [n],
+            matrix_c64::tri_mat_vec_mul(major, tri, trans_a, diag, n, a, lda, x, inc_x)
+        )
+    }
+
+    unsafe fn trsv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        n: c_int,
+        a: *const Self,
+        lda: c_int,
+        x: *mut Self,
+        inc_x: c_int,
+    ) {
+        traced!(
+            "cblas_ztrsv",
+            [n],
+            matrix_c64::tri_solve(major, tri, trans_a, diag, n, a, lda, x, inc_x)
+        )
+    }
+
+    unsafe fn symv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        x: *const Self,
+        beta: Self,
+        y: *mut Self,
+    ) -> Result<(), BlasError> {
+        let (n, lda) = (n as usize, lda as usize);
+        matrix_c64::zsymv(
+            major,
+            tri,
+            n,
+            alpha,
+            slice::from_raw_parts(a, strided_len(n, n, lda)),
+            lda,
+            slice::from_raw_parts(x, n),
+            beta,
+            slice::from_raw_parts_mut(y, n),
+        )
+    }
+
+    unsafe fn hemv(
+        major: RowColMajor,
+        tri: UpOrLowTriangle,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        x: *const Self,
+        inc_x: c_int,
+        beta: Self,
+        y: *mut Self,
+        inc_y: c_int,
+    ) {
+        traced!(
+            "cblas_zhemv",
+            [n],
+            matrix_c64::herm_mat_vec_mul_add(
+                major, tri, n, &alpha, a, lda, x, inc_x, &beta, y, inc_y
+            )
+        )
+    }
+}
+
+/// A borrowed, read-only view of a square `n x n` matrix.
+///
+/// Triangular and symmetric routines take a single order `n`, and handing them a rectangular buffer is a
+/// mistake the raw bindings cannot catch. `SquareMatrix` carries only the one dimension, so once a value
+/// exists it is square by construction, and its methods validate the vectors against `n` before calling
+/// into Accelerate.
+pub struct SquareMatrix<'a, T> {
+    data: &'a [T],
+    n: usize,
+    lda: usize,
+    order: RowColMajor,
+}
+
+impl<'a, T> Clone for SquareMatrix<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for SquareMatrix<'a, T> {}
+
+impl<'a, T> SquareMatrix<'a, T> {
+    /// Creates a view over `data`, checking that it holds an `n x n` matrix with leading dimension `lda`.
+    ///
+    /// # Errors
+    /// Returns [`BlasError::InvalidLeadingDimension`] if `lda < n`, and [`BlasError::BufferTooSmall`] if
+    /// `data` is too short.
+    pub fn new(data: &'a [T], n: usize, lda: usize, order: RowColMajor) -> Result<Self, BlasError> {
+        check_ld("lda", n.max(1), lda)?;
+        check_len("data", strided_len(n, n, lda), data.len())?;
+        Ok(SquareMatrix {
+            data,
+            n,
+            lda,
+            order,
+        })
+    }
+
+    /// The order of the matrix: its number of rows, and of columns.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// The distance, in elements, between consecutive rows (row-major) or columns (column-major).
+    pub fn lda(&self) -> usize {
+        self.lda
+    }
+
+    /// The storage order of the underlying buffer.
+    pub fn order(&self) -> RowColMajor {
+        self.order
+    }
+
+    /// The underlying buffer, starting at element `(0, 0)`.
+    pub fn as_slice(&self) -> &'a [T] {
+        self.data
+    }
+
+    /// A pointer to element `(0, 0)`, suitable for passing to the raw bindings.
+    pub fn as_ptr(&self) -> *const T {
+        self.data.as_ptr()
+    }
+
+    /// Borrows the matrix as a general [`MatrixView`].
+    pub fn view(&self) -> MatrixView<'a, T> {
+        // The shape was validated on construction, so this cannot fail.
+        MatrixView::new(self.data, self.n, self.n, self.lda, self.order).unwrap()
+    }
+}
+
+impl<'a, T: SquareScalar> SquareMatrix<'a, T> {
+    /// Computes `x = op(A) * x` in place, using only the `tri` triangle of the matrix.
+    ///
+    /// # Errors
+    /// Returns [`BlasError::BufferTooSmall`] if `x` is too short for `n` elements at stride `inc_x`.
+    /// Returns [`BlasError::ZeroIncrement`] if `inc_x` is zero.
+    pub fn trmv(
+        &self,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        x: &mut [T],
+        inc_x: i32,
+    ) -> Result<(), BlasError> {
+        check_inc("inc_x", inc_x)?;
+        check_len("x", vec_len(self.n, inc_x), x.len())?;
+        unsafe {
+            T::trmv(
+                self.order,
+                tri,
+                trans_a,
+                diag,
+                to_c_int(self.n, "n")?,
+                self.data.as_ptr(),
+                to_c_int(self.lda, "lda")?,
+                x.as_mut_ptr(),
+                inc_x,
+            );
+        }
+        Ok(())
+    }
+
+    /// Solves `op(A) * x = b` in place, using only the `tri` triangle of the matrix. `x` holds `b` on
+    /// entry and the solution on return.
+    ///
+    /// # Errors
+    /// Returns [`BlasError::BufferTooSmall`] if `x` is too short for `n` elements at stride `inc_x`.
+    /// Returns [`BlasError::ZeroIncrement`] if `inc_x` is zero.
+    pub fn trsv(
+        &self,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        x: &mut [T],
+        inc_x: i32,
+    ) -> Result<(), BlasError> {
+        check_inc("inc_x", inc_x)?;
+        check_len("x", vec_len(self.n, inc_x), x.len())?;
+        unsafe {
+            T::trsv(
+                self.order,
+                tri,
+                trans_a,
+                diag,
+                to_c_int(self.n, "n")?,
+                self.data.as_ptr(),
+                to_c_int(self.lda, "lda")?,
+                x.as_mut_ptr(),
+                inc_x,
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns `A * x`, reading `A` as symmetric from its `tri` triangle. For complex types the other
+    /// triangle mirrors it without conjugation (`A = A^T`); use [`hemv`](SquareMatrix::hemv) for Hermitian
+    /// matrices.
+    ///
+    /// # Errors
+    /// Returns [`BlasError::DimensionMismatch`] if `x` is not of length `n`.
+    pub fn symv(&self, tri: UpOrLowTriangle, x: &[T]) -> Result<Vec<T>, BlasError> {
+        check_shape("x", self.n, x.len())?;
+        let mut y = vec![T::ZERO; self.n];
+        unsafe {
+            T::symv(
+                self.order,
+                tri,
+                to_c_int(self.n, "n")?,
+                T::ONE,
+                self.data.as_ptr(),
+                to_c_int(self.lda, "lda")?,
+                x.as_ptr(),
+                T::ZERO,
+                y.as_mut_ptr(),
+            )?;
+        }
+        Ok(y)
+    }
+
+    /// Returns `A * x`, reading `A` as Hermitian from its `tri` triangle, so the other triangle is its
+    /// conjugate transpose (`A = A^H`). For real types this is the same product as
+    /// [`symv`](SquareMatrix::symv).
+    ///
+    /// # Errors
+    /// Returns [`BlasError::DimensionMismatch`] if `x` is not of length `n`.
+    pub fn hemv(&self, tri: UpOrLowTriangle, x: &[T]) -> Result<Vec<T>, BlasError> {
+        check_shape("x", self.n, x.len())?;
+        let mut y = vec![T::ZERO; self.n];
+        unsafe {
+            T::hemv(
+                self.order,
+                tri,
+                to_c_int(self.n, "n")?,
+                T::ONE,
+                self.data.as_ptr(),
+                to_c_int(self.lda, "lda")?,
+                x.as_ptr(),
+                1,
+                T::ZERO,
+                y.as_mut_ptr(),
+                1,
+            );
+        }
+        Ok(y)
+    }
+}
+
+impl<'a, T> TryFrom<MatrixView<'a, T>> for SquareMatrix<'a, T> {
+    type Error = BlasError;
+
+    /// Narrows a general view to a square one.
+    ///
+    /// # Errors
+    /// Returns [`BlasError::DimensionMismatch`] with `which = "cols"` if the view is not square.
+    fn try_from(view: MatrixView<'a, T>) -> Result<Self, BlasError> {
+        check_shape("cols", view.rows(), view.cols())?;
+        Ok(SquareMatrix {
+            data: view.as_slice(),
+            n: view.rows(),
+            lda: view.lda(),
+            order: view.order(),
+        })
+    }
+}
+
+impl<'a, T> From<SquareMatrix<'a, T>> for MatrixView<'a, T> {
+    fn from(square: SquareMatrix<'a, T>) -> Self {
+        square.view()
+    }
+}
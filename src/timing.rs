@@ -0,0 +1,16 @@
+use std::time::{Duration, Instant};
+
+/// Runs `f` and returns its result together with the wall-clock time it took.
+///
+/// The `*_timed` wrappers, such as [`sgemm_timed`](crate::matrix_f32::sgemm_timed), are built on this, and
+/// any other safe wrapper can be timed the same way by calling it inside the closure.
+///
+/// # Discussion
+/// The measurement is a single `Instant` pair around the call, so it includes validation and any allocation
+/// the wrapper does, and it is as noisy as any single timing. For stable numbers repeat the call, or use a
+/// harness such as `criterion` as the crate's own benchmarks do.
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
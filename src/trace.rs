@@ -0,0 +1,15 @@
+/// Wraps an FFI call made by a safe wrapper, logging the symbol and its dimensions first.
+///
+/// With the `trace` feature enabled, every call is reported through the `log` crate at `trace`
+/// level, e.g. `cblas_sgemv m=512 n=256`. Without the feature the macro expands to the call alone.
+///
+/// ```ignore
+/// unsafe { traced!("cblas_stbsv", [n, k], tri_band_solve(/* ... */)) }
+/// ```
+macro_rules! traced {
+    ($symbol:literal, [$($dim:ident),* $(,)?], $call:expr) => {{
+        #[cfg(feature = "trace")]
+        log::trace!(concat!($symbol $(, " ", stringify!($dim), "={}")*), $($dim),*);
+        $call
+    }};
+}
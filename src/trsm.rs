@@ -0,0 +1,278 @@
+use crate::constants::{IsDiagUnit, MultiplyOrder, RowColMajor, TransposeMode, UpOrLowTriangle};
+use crate::error::BlasError;
+use crate::scalar::BlasScalar;
+use crate::validate::{check_ld, check_len, strided_len, to_c_int};
+use crate::{matrix_c32, matrix_c64, matrix_f32, matrix_f64};
+use num_complex::Complex;
+use std::ffi::c_int;
+
+/// Element types accepted by [`TrsmBuilder`], each dispatching to its own `cblas_?trsm`.
+///
+/// The default `alpha` is the multiplicative identity from [`BlasScalar`].
+pub trait TrsmScalar: BlasScalar {
+    /// Forwards to the matching `tri_solve_multiple` binding.
+    ///
+    /// # Safety
+    /// Same contract as the underlying `cblas_?trsm`: `a` and `b` must be valid for the given
+    /// dimensions and leading dimensions.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn trsm(
+        major: RowColMajor,
+        side: MultiplyOrder,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        m: c_int,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        b: *mut Self,
+        ldb: c_int,
+    );
+}
+
+impl TrsmScalar for f32 {
+    unsafe fn trsm(
+        major: RowColMajor,
+        side: MultiplyOrder,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        m: c_int,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        b: *mut Self,
+        ldb: c_int,
+    ) {
+        traced!(
+            "cblas_strsm",
+            [m, n],
+            matrix_f32::tri_solve_multiple(
+                major, side, tri, trans_a, diag, m, n, alpha, a, lda, b, ldb
+            )
+        )
+    }
+}
+
+impl TrsmScalar for f64 {
+    unsafe fn trsm(
+        major: RowColMajor,
+        side: MultiplyOrder,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        m: c_int,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        b: *mut Self,
+        ldb: c_int,
+    ) {
+        traced!(
+            "cblas_dtrsm",
+            [m, n],
+            matrix_f64::tri_solve_multiple(
+                major, side, tri, trans_a, diag, m, n, alpha, a, lda, b, ldb
+            )
+        )
+    }
+}
+
+impl TrsmScalar for Complex<f32> {
+    unsafe fn trsm(
+        major: RowColMajor,
+        side: MultiplyOrder,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        m: c_int,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        b: *mut Self,
+        ldb: c_int,
+    ) {
+        traced!(
+            "cblas_ctrsm",
+            [m, n],
+            matrix_c32::tri_solve_multiple(
+                major, side, tri, trans_a, diag, m, n, &alpha, a, lda, b, ldb
+            )
+        )
+    }
+}
+
+impl TrsmScalar for Complex<f64> {
+    unsafe fn trsm(
+        major: RowColMajor,
+        side: MultiplyOrder,
+        tri: UpOrLowTriangle,
+        trans_a: TransposeMode,
+        diag: IsDiagUnit,
+        m: c_int,
+        n: c_int,
+        alpha: Self,
+        a: *const Self,
+        lda: c_int,
+        b: *mut Self,
+        ldb: c_int,
+    ) {
+        traced!(
+            "cblas_ztrsm",
+            [m, n],
+            matrix_c64::tri_solve_multiple(
+                major, side, tri, trans_a, diag, m, n, &alpha, a, lda, b, ldb
+            )
+        )
+    }
+}
+
+/// Builder for a triangular solve with multiple right-hand sides, `op(A) * X = alpha * B` or
+/// `X * op(A) = alpha * B`, overwriting the `m x n` matrix `B` with `X`.
+///
+/// Every mode flag starts at the most common choice, so only the ones that differ need to be set:
+///
+/// | setting  | default     |
+/// |----------|-------------|
+/// | `order`  | `RowMajor`  |
+/// | `side`   | `Left`      |
+/// | `uplo`   | `Upper`     |
+/// | `trans`  | `NoTrans`   |
+/// | `diag`   | `NonUnit`   |
+/// | `alpha`  | `1`         |
+/// | `lda`    | tight: the order of `A` |
+/// | `ldb`    | tight: `n` for row-major, `m` for column-major |
+///
+/// # Example
+/// ```no_run
+/// use accelerate_general::constants::CblasUpLow;
+/// use accelerate_general::trsm::TrsmBuilder;
+///
+/// // Solve L * X = B for a 2 x 2 lower-triangular L and a 2 x 3 right-hand side.
+/// let l = [2.0f32, 0.0, 1.0, 4.0];
+/// let mut b = [2.0f32, 4.0, 6.0, 9.0, 10.0, 11.0];
+/// TrsmBuilder::new(2, 3).uplo(CblasUpLow::Lower).run(&l, &mut b).unwrap();
+/// ```
+#[derive(Clone, Copy)]
+pub struct TrsmBuilder<T> {
+    m: usize,
+    n: usize,
+    order: RowColMajor,
+    side: MultiplyOrder,
+    uplo: UpOrLowTriangle,
+    trans: TransposeMode,
+    diag: IsDiagUnit,
+    alpha: T,
+    lda: Option<usize>,
+    ldb: Option<usize>,
+}
+
+impl<T: TrsmScalar> TrsmBuilder<T> {
+    /// Starts a solve for an `m x n` right-hand side `B` with every setting at its default.
+    pub fn new(m: usize, n: usize) -> Self {
+        TrsmBuilder {
+            m,
+            n,
+            order: RowColMajor::RowMajor,
+            side: MultiplyOrder::Left,
+            uplo: UpOrLowTriangle::Upper,
+            trans: TransposeMode::NoTrans,
+            diag: IsDiagUnit::NonUnit,
+            alpha: T::ONE,
+            lda: None,
+            ldb: None,
+        }
+    }
+
+    /// Sets the storage order of both `A` and `B`.
+    pub fn order(mut self, order: RowColMajor) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets whether `A` multiplies `X` from the left (`A` is `m x m`) or the right (`A` is `n x n`).
+    pub fn side(mut self, side: MultiplyOrder) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Sets which triangle of `A` is referenced.
+    pub fn uplo(mut self, uplo: UpOrLowTriangle) -> Self {
+        self.uplo = uplo;
+        self
+    }
+
+    /// Sets whether `A`, its transpose or its conjugate transpose is used.
+    pub fn trans(mut self, trans: TransposeMode) -> Self {
+        self.trans = trans;
+        self
+    }
+
+    /// Sets whether the diagonal of `A` is assumed to be all ones.
+    pub fn diag(mut self, diag: IsDiagUnit) -> Self {
+        self.diag = diag;
+        self
+    }
+
+    /// Sets the scalar applied to `B` before the solve.
+    pub fn alpha(mut self, alpha: T) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Overrides the leading dimension of `A`.
+    pub fn lda(mut self, lda: usize) -> Self {
+        self.lda = Some(lda);
+        self
+    }
+
+    /// Overrides the leading dimension of `B`.
+    pub fn ldb(mut self, ldb: usize) -> Self {
+        self.ldb = Some(ldb);
+        self
+    }
+
+    /// Validates the buffers and runs the solve, overwriting `b` with the solution `X`.
+    ///
+    /// # Errors
+    /// Returns [`BlasError::InvalidLeadingDimension`] if `lda` or `ldb` is below its minimum and
+    /// [`BlasError::BufferTooSmall`] if `a` or `b` is too short for the configured dimensions.
+    pub fn run(self, a: &[T], b: &mut [T]) -> Result<(), BlasError> {
+        let k = match self.side {
+            MultiplyOrder::Left => self.m,
+            MultiplyOrder::Right => self.n,
+        };
+        let (outer, inner) = match self.order {
+            RowColMajor::RowMajor => (self.m, self.n),
+            RowColMajor::ColMajor => (self.n, self.m),
+        };
+        let lda = self.lda.unwrap_or_else(|| k.max(1));
+        let ldb = self.ldb.unwrap_or_else(|| inner.max(1));
+        check_ld("lda", k.max(1), lda)?;
+        check_ld("ldb", inner.max(1), ldb)?;
+        check_len("a", strided_len(k, k, lda), a.len())?;
+        check_len("b", strided_len(outer, inner, ldb), b.len())?;
+        unsafe {
+            T::trsm(
+                self.order,
+                self.side,
+                self.uplo,
+                self.trans,
+                self.diag,
+                to_c_int(self.m, "m")?,
+                to_c_int(self.n, "n")?,
+                self.alpha,
+                a.as_ptr(),
+                to_c_int(lda, "lda")?,
+                b.as_mut_ptr(),
+                to_c_int(ldb, "ldb")?,
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use crate::error::BlasError;
+use std::convert::TryFrom;
+use std::ffi::c_int;
+
+/// Number of elements a strided vector of `n` logical elements spans, i.e. `(n - 1) * |inc| + 1`.
+pub(crate) fn vec_len(n: usize, inc: i32) -> usize {
+    if n == 0 {
+        0
+    } else {
+        (n - 1) * inc.unsigned_abs() as usize + 1
+    }
+}
+
+/// Number of elements spanned by `count` rows (or columns) of length `width` stored `ld` apart.
+pub(crate) fn strided_len(count: usize, width: usize, ld: usize) -> usize {
+    if count == 0 || width == 0 {
+        0
+    } else {
+        (count - 1) * ld + width
+    }
+}
+
+/// Fails with [`BlasError::ZeroIncrement`] when `inc == 0`.
+///
+/// BLAS leaves a zero increment undefined, and [`vec_len`] would count it as a single element, so a
+/// wrapper would otherwise pass a one-element buffer and have the routine read or write it `n` times.
+pub(crate) fn check_inc(which: &'static str, inc: i32) -> Result<(), BlasError> {
+    if inc == 0 {
+        return Err(BlasError::ZeroIncrement { which });
+    }
+    Ok(())
+}
+
+/// Fails with [`BlasError::BufferTooSmall`] when `got < needed`.
+pub(crate) fn check_len(which: &'static str, needed: usize, got: usize) -> Result<(), BlasError> {
+    if got < needed {
+        return Err(BlasError::BufferTooSmall { which, needed, got });
+    }
+    Ok(())
+}
+
+/// Fails with [`BlasError::DimensionMismatch`] when `got != expected`.
+pub(crate) fn check_shape(
+    which: &'static str,
+    expected: usize,
+    got: usize,
+) -> Result<(), BlasError> {
+    if got != expected {
+        return Err(BlasError::DimensionMismatch {
+            which,
+            expected,
+            got,
+        });
+    }
+    Ok(())
+}
+
+/// Fails with [`BlasError::InvalidLeadingDimension`] when `got < min`.
+pub(crate) fn check_ld(which: &'static str, min: usize, got: usize) -> Result<(), BlasError> {
+    if got < min {
+        return Err(BlasError::InvalidLeadingDimension { which, min, got });
+    }
+    Ok(())
+}
+
+/// Fails with [`BlasError::IndexOutOfBounds`] when any of `indices` is `>= len`.
+pub(crate) fn check_indices(
+    which: &'static str,
+    indices: &[usize],
+    len: usize,
+) -> Result<(), BlasError> {
+    match indices.iter().find(|&&index| index >= len) {
+        Some(&index) => Err(BlasError::IndexOutOfBounds { which, index, len }),
+        None => Ok(()),
+    }
+}
+
+/// Converts a Rust length to the C `int` Accelerate takes, failing with [`BlasError::IntOverflow`] instead of
+/// silently truncating when `n > c_int::MAX`.
+pub(crate) fn to_c_int(n: usize, which: &'static str) -> Result<c_int, BlasError> {
+    c_int::try_from(n).map_err(|_| BlasError::IntOverflow { which, value: n })
+}
@@ -1,3 +1,5 @@
+use crate::error::BlasError;
+use crate::validate::{check_inc, check_indices, check_len, check_shape, to_c_int, vec_len};
 use num_complex::Complex;
 use std::ffi::{c_double, c_int};
 
@@ -103,18 +105,150 @@ extern "C" {
         dotu: *mut Complex<c_double>,
     );
 
-    /// Computes the sum of the absolute values of real and imaginary parts of elements in a vector (single-precision complex).
+    /// Computes a constant times a vector plus a vector (double-precision complex).
+    ///
+    /// `Y = alpha * X + Y`
+    ///
+    /// This function modifies the vector `Y` in-place.
+    ///
+    /// # Precision
+    /// This function operates on `f64` complex numbers.
+    ///
+    /// # Parameters
+    /// - `n`: The number of elements in the vectors `X` and `Y`.
+    /// - `alpha`: A pointer to the complex scalar that scales vector `X`.
+    /// - `x`: A pointer to the input vector `X` (of complex numbers).
+    /// - `inc_x`: The stride between elements in vector `X`.
+    /// - `y`: A pointer to the input/output vector `Y` (of complex numbers).
+    /// - `inc_y`: The stride between elements in vector `Y`.
+    ///
+    /// # Safety
+    /// This is an `unsafe` function. The caller must ensure that the memory regions pointed to by `x` and `y` are valid
+    /// and that accessing the data up to `n * inc_x` and `n * inc_y` is safe.
+    ///
+    /// `x` and `y` must not overlap. BLAS reads `x` while it writes `y`, so aliased buffers give unspecified
+    /// results. The safe [`axpy`] and [`copy_into`] wrappers rule this out through the borrow checker.
+    #[link_name = "cblas_zaxpy"]
+    pub fn scaled_plus(
+        n: c_int,                        // Number of elements in vectors
+        alpha: *const Complex<c_double>, // Scaling factor for X (complex scalar)
+        x: *const Complex<c_double>,     // Input vector X
+        inc_x: c_int,                    // Stride within X
+        y: *mut Complex<c_double>,       // Input/output vector Y
+        inc_y: c_int,                    // Stride within Y
+    );
+
+    /// Copies a vector to another vector (double-precision complex).
+    ///
+    /// Copies the elements from the vector `X` to the vector `Y`.
+    ///
+    /// # Precision
+    /// This function operates on `f64` complex numbers.
+    ///
+    /// # Parameters
+    /// - `n`: The number of elements in the vectors `X` and `Y`.
+    /// - `x`: A pointer to the source vector `X` (complex numbers).
+    /// - `inc_x`: The stride between elements in vector `X`.
+    /// - `y`: A pointer to the destination vector `Y` (complex numbers).
+    /// - `inc_y`: The stride between elements in vector `Y`.
+    ///
+    /// # Safety
+    /// This is an `unsafe` function. The caller must ensure that the memory regions pointed to by `x` and `y` are valid
+    /// and that accessing the data up to `n * inc_x` and `n * inc_y` is safe.
+    ///
+    /// `x` and `y` must not overlap. BLAS reads `x` while it writes `y`, so aliased buffers give unspecified
+    /// results. The safe [`axpy`] and [`copy_into`] wrappers rule this out through the borrow checker.
+    #[link_name = "cblas_zcopy"]
+    pub fn copy(
+        n: c_int,                    // Number of elements in vectors
+        x: *const Complex<c_double>, // Source vector X
+        inc_x: c_int,                // Stride within X
+        y: *mut Complex<c_double>,   // Destination vector Y
+        inc_y: c_int,                // Stride within Y
+    );
+
+    /// Exchanges the elements of two complex vectors `X` and `Y`.
+    ///
+    /// # Precision
+    /// This function operates on double-precision complex numbers (`Complex<f64>`).
+    ///
+    /// # Parameters
+    /// - `n`: The number of elements in vectors `X` and `Y`.
+    /// - `x`: A pointer to the first complex vector `X`. On return, contains elements copied from vector `Y`.
+    /// - `inc_x`: The increment (stride) between elements in `X`. For example, if `inc_x` is 7, every 7th element is used.
+    /// - `y`: A pointer to the second complex vector `Y`. On return, contains elements copied from vector `X`.
+    /// - `inc_y`: The increment (stride) between elements in `Y`. For example, if `inc_y` is 7, every 7th element is used.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function. The caller must ensure that the memory regions accessed by `X` and `Y` are valid and within bounds.
+    ///
+    /// # Discussion
+    /// This function swaps the elements between two complex vectors `X` and `Y` in place.
+    #[link_name = "cblas_zswap"]
+    pub fn swap(
+        n: c_int,
+        x: *mut Complex<c_double>,
+        inc_x: c_int,
+        y: *mut Complex<c_double>,
+        inc_y: c_int,
+    );
+
+    /// Multiplies each element of a complex vector by a constant scaling factor.
+    ///
+    /// # Precision
+    /// This function operates on double-precision complex numbers (`Complex<f64>`).
+    ///
+    /// # Parameters
+    /// - `n`: The number of elements in the vector `X`.
+    /// - `alpha`: A pointer to the constant scaling factor.
+    /// - `x`: A pointer to the input/output vector `X`. The result is stored in-place.
+    /// - `inc_x`: The stride between elements in `X`. For example, if `inc_x` is 7, every 7th element of `X` is used.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function. The caller must ensure that the memory regions accessed by `x` are valid and that the stride `inc_x` is correctly set.
+    ///
+    /// # Discussion
+    /// The function scales each element in the vector `X` by a constant value `alpha`, modifying `X` in place.
+    #[link_name = "cblas_zscal"]
+    pub fn scale_by_c64(
+        n: c_int,
+        alpha: *const Complex<c_double>,
+        x: *mut Complex<c_double>,
+        inc_x: c_int,
+    );
+
+    /// Multiplies each element of a complex vector `X` by a real scalar `alpha`.
+    ///
+    /// # Precision
+    /// This function operates on double-precision complex numbers (`Complex<f64>`) with a real `f64` scalar.
+    ///
+    /// # Parameters
+    /// - `n`: The number of elements in vector `X`.
+    /// - `alpha`: The real scalar that scales both the real and imaginary part of each element of `X`.
+    /// - `x`: A pointer to the complex vector `X`, which is modified in place.
+    /// - `inc_x`: The increment (stride) between elements in `X`. For example, if `inc_x` is 7, every 7th element is used.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function. The caller must ensure that the memory regions accessed by `X` are valid.
+    ///
+    /// # Discussion
+    /// Unlike [`scale_by_c64`], `alpha` is passed by value as a plain `c_double`, and no complex multiplication
+    /// is performed.
+    #[link_name = "cblas_zdscal"]
+    pub fn scale_by_f64(n: c_int, alpha: c_double, x: *mut Complex<c_double>, inc_x: c_int);
+
+    /// Computes the sum of the absolute values of real and imaginary parts of elements in a vector (double-precision complex).
     ///
     /// # Precision
-    /// This function operates on single-precision complex numbers (`Complex<f64>`).
+    /// This function operates on double-precision complex numbers (`Complex<f64>`).
     ///
     /// # Parameters
     /// - `n`: The number of elements in the vector `X`.
-    /// - `x`: A pointer to the source vector `X` (stored as single-precision complex numbers).
+    /// - `x`: A pointer to the source vector `X` (stored as double-precision complex numbers).
     /// - `inc_x`: The stride between elements in `X`. For example, if `inc_x = 7`, every 7th element is used.
     ///
     /// # Return Value
-    /// Returns a single floating-point value containing the sum of the absolute values of both the real and imaginary parts of the vector.
+    /// Returns a double-precision value containing the sum of the absolute values of both the real and imaginary parts of the vector.
     ///
     /// # Safety
     /// This is an `unsafe` C function. The caller must ensure that the memory regions accessed by `x` are valid.
@@ -125,14 +259,14 @@ extern "C" {
         inc_x: c_int,                // Stride within vector X
     ) -> c_double;
 
-    /// Computes the unitary norm (Euclidean norm or 2-norm) of a vector (single-precision complex).
+    /// Computes the unitary norm (Euclidean norm or 2-norm) of a vector (double-precision complex).
     ///
     /// # Precision
-    /// This function operates on single-precision complex numbers (`Complex<f64>`).
+    /// This function operates on double-precision complex numbers (`Complex<f64>`).
     ///
     /// # Parameters
     /// - `n`: The length of the vector `X`.
-    /// - `x`: A pointer to the vector `X` (stored as single-precision complex numbers).
+    /// - `x`: A pointer to the vector `X` (stored as double-precision complex numbers).
     /// - `inc_x`: The stride between elements in `X`. For example, if `inc_x = 7`, every 7th element is used.
     ///
     /// # Return Value
@@ -163,6 +297,586 @@ extern "C" {
     /// # Safety
     /// This is an `unsafe` C function. The caller must ensure that the memory region accessed by `x`
     /// (up to `n * inc_x`) is valid and within bounds.
+    ///
+    /// With NaN elements in `x` the returned index is implementation-defined. Use [`izamax_nan_aware`]
+    /// when the input may contain NaN and a predictable answer is needed.
     #[link_name = "cblas_izamax"]
     pub fn argmax_mod(n: c_int, x: *const Complex<c_double>, inc_x: c_int) -> c_int;
 }
+
+/// Computes the linear combination `sum_i coeffs[i] * vectors[i]` into a new vector.
+///
+/// The first vector is copied and scaled with [`scale_by_c64`] (`cblas_zscal`), then every further vector is
+/// accumulated with [`scaled_plus`] (`cblas_zaxpy`). This generalizes the two-vector `axpby` to any number of
+/// terms, as when combining basis functions in spectral or interpolation methods.
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers.
+///
+/// # Parameters
+/// - `coeffs`: One coefficient per vector.
+/// - `vectors`: The vectors to combine, all of the same length.
+///
+/// # Returns
+/// - The combined vector, or an empty vector when there are no terms.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `coeffs` and `vectors` differ in length or the vectors do not
+/// all share the length of the first one.
+pub fn linear_combination(
+    coeffs: &[Complex<f64>],
+    vectors: &[&[Complex<f64>]],
+) -> Result<Vec<Complex<f64>>, BlasError> {
+    check_shape("vectors", coeffs.len(), vectors.len())?;
+    let (first, rest) = match vectors.split_first() {
+        Some(split) => split,
+        None => return Ok(Vec::new()),
+    };
+    let n = first.len();
+    for v in rest {
+        check_shape("vectors", n, v.len())?;
+    }
+    let n_c = to_c_int(n, "n")?;
+    let mut out = first.to_vec();
+    unsafe {
+        traced!(
+            "cblas_zscal",
+            [n],
+            scale_by_c64(n_c, &coeffs[0], out.as_mut_ptr(), 1)
+        );
+        for (alpha, v) in coeffs[1..].iter().zip(rest) {
+            traced!(
+                "cblas_zaxpy",
+                [n],
+                scaled_plus(n_c, alpha, v.as_ptr(), 1, out.as_mut_ptr(), 1)
+            );
+        }
+    }
+    Ok(out)
+}
+
+/// Gathers the elements of `src` at `indices` into a new dense vector, i.e. `out[i] = src[indices[i]]`.
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers.
+///
+/// # Parameters
+/// - `src`: The vector to read from.
+/// - `indices`: The positions in `src` to read, in output order. Indices may repeat.
+///
+/// # Errors
+/// Returns [`BlasError::IndexOutOfBounds`] if any index is not less than `src.len()`.
+pub fn gather(src: &[Complex<f64>], indices: &[usize]) -> Result<Vec<Complex<f64>>, BlasError> {
+    check_indices("src", indices, src.len())?;
+    Ok(indices.iter().map(|&i| src[i]).collect())
+}
+
+/// Adds each of `values` into `dst` at the matching position of `indices`, i.e. `dst[indices[i]] += values[i]`.
+///
+/// Repeated indices accumulate. Together with [`gather`] this applies a dense kernel such as `axpy` to a
+/// selected subset of a vector.
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers.
+///
+/// # Parameters
+/// - `dst`: The vector to update in place.
+/// - `indices`: The positions in `dst` to update.
+/// - `values`: The values to add, one per index.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `values` does not hold one element per index and
+/// [`BlasError::IndexOutOfBounds`] if any index is not less than `dst.len()`. `dst` is left untouched on error.
+pub fn scatter_add(
+    dst: &mut [Complex<f64>],
+    indices: &[usize],
+    values: &[Complex<f64>],
+) -> Result<(), BlasError> {
+    check_shape("values", indices.len(), values.len())?;
+    check_indices("dst", indices, dst.len())?;
+    for (&i, &v) in indices.iter().zip(values) {
+        dst[i] += v;
+    }
+    Ok(())
+}
+
+/// Computes `y = alpha * x + y` via [`scaled_plus`] (`cblas_zaxpy`).
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers.
+///
+/// # Parameters
+/// - `n`: The number of logical elements in `x` and `y`.
+/// - `alpha`: The scalar multiplying `x`, taken by value and passed to BLAS by reference for the call.
+/// - `x`: The vector to add.
+/// - `inc_x`: The increment between elements in `x`.
+/// - `y`: The vector to accumulate into.
+/// - `inc_y`: The increment between elements in `y`.
+///
+/// # Errors
+/// Returns [`BlasError::BufferTooSmall`] if `x` or `y` is too short for `n` elements at its increment.
+/// Returns [`BlasError::ZeroIncrement`] if `inc_x` or `inc_y` is zero.
+///
+/// # Discussion
+/// BLAS requires `x` and `y` not to overlap. Taking `x` as `&[_]` and `y` as `&mut [_]` lets the borrow
+/// checker enforce this, so unlike the raw binding this wrapper cannot be handed aliased buffers.
+pub fn axpy(
+    n: usize,
+    alpha: Complex<f64>,
+    x: &[Complex<f64>],
+    inc_x: i32,
+    y: &mut [Complex<f64>],
+    inc_y: i32,
+) -> Result<(), BlasError> {
+    check_inc("inc_x", inc_x)?;
+    check_len("x", vec_len(n, inc_x), x.len())?;
+    check_inc("inc_y", inc_y)?;
+    check_len("y", vec_len(n, inc_y), y.len())?;
+    unsafe {
+        traced!(
+            "cblas_zaxpy",
+            [n],
+            scaled_plus(
+                to_c_int(n, "n")?,
+                &alpha,
+                x.as_ptr(),
+                inc_x,
+                y.as_mut_ptr(),
+                inc_y
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Computes `y = alpha * x + y` for equal-length, contiguous vectors via [`scaled_plus`] (`cblas_zaxpy`).
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `x` and `y` differ in length.
+///
+/// # Discussion
+/// This is [`axpy`] with `n` taken from the slices and unit strides, for the common `y += a * x` update.
+/// `alpha` is taken by value and passed to BLAS by reference for the call.
+pub fn zaxpy(
+    alpha: Complex<f64>,
+    x: &[Complex<f64>],
+    y: &mut [Complex<f64>],
+) -> Result<(), BlasError> {
+    check_shape("y", x.len(), y.len())?;
+    axpy(x.len(), alpha, x, 1, y, 1)
+}
+
+/// Copies `n` elements of `x` into `y` via [`copy`] (`cblas_zcopy`).
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers.
+///
+/// # Parameters
+/// - `n`: The number of logical elements to copy.
+/// - `x`: The source vector.
+/// - `inc_x`: The increment between elements in `x`.
+/// - `y`: The destination vector.
+/// - `inc_y`: The increment between elements in `y`.
+///
+/// # Errors
+/// Returns [`BlasError::BufferTooSmall`] if `x` or `y` is too short for `n` elements at its increment.
+/// Returns [`BlasError::ZeroIncrement`] if `inc_x` or `inc_y` is zero.
+///
+/// # Discussion
+/// As with [`axpy`], the borrow checker guarantees that `x` and `y` do not overlap.
+pub fn copy_into(
+    n: usize,
+    x: &[Complex<f64>],
+    inc_x: i32,
+    y: &mut [Complex<f64>],
+    inc_y: i32,
+) -> Result<(), BlasError> {
+    check_inc("inc_x", inc_x)?;
+    check_len("x", vec_len(n, inc_x), x.len())?;
+    check_inc("inc_y", inc_y)?;
+    check_len("y", vec_len(n, inc_y), y.len())?;
+    unsafe {
+        traced!(
+            "cblas_zcopy",
+            [n],
+            copy(to_c_int(n, "n")?, x.as_ptr(), inc_x, y.as_mut_ptr(), inc_y)
+        );
+    }
+    Ok(())
+}
+
+/// Finds the index of the element with the largest absolute value in `x`, with a fixed NaN policy: the
+/// first NaN element is always selected.
+///
+/// [`argmax_mod`] (`cblas_izamax`) gives no guarantee about NaN inputs, and in practice a NaN may be
+/// skipped or returned depending on where it sits. Here a NaN is treated as larger than every number, so
+/// corrupted input (for example a bad sensor sample) surfaces as the peak instead of being silently
+/// ignored. Callers that want NaN skipped can test the selected element with `is_nan` and filter first.
+///
+/// Elements are ranked by the BLAS modulus `|re| + |im|`, as in [`argmax_mod`]; an element counts as NaN
+/// when either part is NaN.
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers.
+///
+/// # Parameters
+/// - `x`: The vector to search.
+/// - `inc_x`: The increment between elements in `x`; every element at a multiple of `inc_x` is considered.
+///
+/// # Returns
+/// - The logical (0-based) index of the first NaN element if there is one, otherwise of the first element
+///   with the largest absolute value, or `None` if `x` is empty or `inc_x` is not positive.
+pub fn izamax_nan_aware(x: &[Complex<f64>], inc_x: i32) -> Option<usize> {
+    if inc_x <= 0 {
+        return None;
+    }
+    let mut best: Option<(usize, f64)> = None;
+    for (i, v) in x.iter().step_by(inc_x as usize).enumerate() {
+        if v.re.is_nan() || v.im.is_nan() {
+            return Some(i);
+        }
+        let abs = v.re.abs() + v.im.abs();
+        if best.map_or(true, |(_, max)| abs > max) {
+            best = Some((i, abs));
+        }
+    }
+    best.map(|(i, _)| i)
+}
+
+/// Updates an exponential moving average in place: `y = alpha * x + (1 - alpha) * y`, via [`lin_comb_catlas`]
+/// (`catlas_zaxpby`).
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers with a real `f64` smoothing factor.
+///
+/// # Parameters
+/// - `alpha`: The weight of the new sample, usually in `[0, 1]`. Larger values track `x` more closely;
+///   `1` replaces `y` with `x` and `0` leaves `y` unchanged.
+/// - `x`: The new sample.
+/// - `y`: The running average, updated in place.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `x` and `y` differ in length.
+pub fn ema_update(alpha: f64, x: &[Complex<f64>], y: &mut [Complex<f64>]) -> Result<(), BlasError> {
+    let n = y.len();
+    check_shape("x", n, x.len())?;
+    let alpha = Complex::new(alpha, 0.0);
+    let beta = Complex::new(1.0 - alpha.re, 0.0);
+    unsafe {
+        traced!(
+            "catlas_zaxpby",
+            [n],
+            lin_comb_catlas(
+                to_c_int(n, "n")?,
+                &alpha,
+                x.as_ptr(),
+                1,
+                &beta,
+                y.as_mut_ptr(),
+                1
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Counts the real and imaginary parts that are subnormal, counting each part separately.
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers.
+///
+/// # Discussion
+/// Subnormal parts (non-zero but smaller in magnitude than `f64::MIN_POSITIVE`) can slow BLAS kernels
+/// sharply; see [`vector_f32::count_subnormals`](crate::vector_f32::count_subnormals) for when to check.
+pub fn count_subnormals(x: &[Complex<f64>]) -> usize {
+    x.iter()
+        .map(|z| z.re.is_subnormal() as usize + z.im.is_subnormal() as usize)
+        .sum()
+}
+
+/// Computes the real part of the conjugated dot product, `Re(x^H y) = sum(Re(conj(x[i]) * y[i]))`, via
+/// [`dot_conj`] (`cblas_zdotc_sub`).
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers and returns a real `f64`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `x` and `y` differ in length.
+///
+/// # Discussion
+/// Complex conjugate-gradient and related solvers only ever use the real part of this product: for a
+/// Hermitian positive-definite operator `x^H x` and `p^H A p` are real, and `Re(x^H y)` is the real inner
+/// product that step lengths and residual norms are built on. The order matters: `x` is the conjugated
+/// operand. Taking the real part also discards the rounding noise that leaves a small imaginary part on
+/// products that are real in exact arithmetic.
+pub fn zdotc_real(x: &[Complex<f64>], y: &[Complex<f64>]) -> Result<f64, BlasError> {
+    let n = x.len();
+    check_shape("y", n, y.len())?;
+    let mut dotc = Complex::new(0.0, 0.0);
+    unsafe {
+        traced!(
+            "cblas_zdotc_sub",
+            [n],
+            dot_conj(to_c_int(n, "n")?, x.as_ptr(), 1, y.as_ptr(), 1, &mut dotc)
+        );
+    }
+    Ok(dotc.re)
+}
+
+/// Computes the unconjugated dot product `x^T y = sum(x[i] * y[i])` via [`dot_unconj`] (`cblas_zdotu_sub`),
+/// returning the value instead of writing it through a pointer.
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers.
+///
+/// # Parameters
+/// - `n`: The number of logical elements in `x` and `y`.
+/// - `x`: The first vector.
+/// - `inc_x`: The increment between elements in `x`.
+/// - `y`: The second vector.
+/// - `inc_y`: The increment between elements in `y`.
+///
+/// # Errors
+/// Returns [`BlasError::BufferTooSmall`] if `x` or `y` is too short for `n` elements at its increment.
+/// Returns [`BlasError::ZeroIncrement`] if `inc_x` or `inc_y` is zero.
+///
+/// # Discussion
+/// Neither operand is conjugated, so this is the bilinear form used by complex-symmetric methods, not an
+/// inner product: `zdotu(x, x)` is in general not real. Use [`zdotc`] for the Hermitian inner product.
+pub fn zdotu(
+    n: usize,
+    x: &[Complex<f64>],
+    inc_x: i32,
+    y: &[Complex<f64>],
+    inc_y: i32,
+) -> Result<Complex<f64>, BlasError> {
+    check_inc("inc_x", inc_x)?;
+    check_len("x", vec_len(n, inc_x), x.len())?;
+    check_inc("inc_y", inc_y)?;
+    check_len("y", vec_len(n, inc_y), y.len())?;
+    let mut dotu = Complex::new(0.0, 0.0);
+    unsafe {
+        traced!(
+            "cblas_zdotu_sub",
+            [n],
+            dot_unconj(
+                to_c_int(n, "n")?,
+                x.as_ptr(),
+                inc_x,
+                y.as_ptr(),
+                inc_y,
+                &mut dotu
+            )
+        );
+    }
+    Ok(dotu)
+}
+
+/// Computes the conjugated dot product `x^H y = sum(conj(x[i]) * y[i])` via [`dot_conj`] (`cblas_zdotc_sub`),
+/// returning the value instead of writing it through a pointer.
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers.
+///
+/// # Parameters
+/// - `n`: The number of logical elements in `x` and `y`.
+/// - `x`: The first vector; this is the one conjugated.
+/// - `inc_x`: The increment between elements in `x`.
+/// - `y`: The second vector.
+/// - `inc_y`: The increment between elements in `y`.
+///
+/// # Errors
+/// Returns [`BlasError::BufferTooSmall`] if `x` or `y` is too short for `n` elements at its increment.
+/// Returns [`BlasError::ZeroIncrement`] if `inc_x` or `inc_y` is zero.
+pub fn zdotc(
+    n: usize,
+    x: &[Complex<f64>],
+    inc_x: i32,
+    y: &[Complex<f64>],
+    inc_y: i32,
+) -> Result<Complex<f64>, BlasError> {
+    check_inc("inc_x", inc_x)?;
+    check_len("x", vec_len(n, inc_x), x.len())?;
+    check_inc("inc_y", inc_y)?;
+    check_len("y", vec_len(n, inc_y), y.len())?;
+    let mut dotc = Complex::new(0.0, 0.0);
+    unsafe {
+        traced!(
+            "cblas_zdotc_sub",
+            [n],
+            dot_conj(
+                to_c_int(n, "n")?,
+                x.as_ptr(),
+                inc_x,
+                y.as_ptr(),
+                inc_y,
+                &mut dotc
+            )
+        );
+    }
+    Ok(dotc)
+}
+
+/// Applies a real diagonal preconditioner to a complex vector in place: `x[i] *= d[i]`.
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers with a real `f64` diagonal.
+///
+/// # Parameters
+/// - `d`: The diagonal, usually the reciprocals of the diagonal of the system matrix.
+/// - `x`: The vector to precondition, overwritten with the result.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `d` and `x` differ in length.
+///
+/// # Discussion
+/// Diagonal (Jacobi) preconditioning scales each component by its own real factor, which none of the
+/// BLAS scaling routines express directly: `cblas_zdscal` applies one real scalar to the whole vector,
+/// so doing this with it would take one call per element. A plain loop scales `re` and `im` by `d[i]`
+/// instead, which is the same arithmetic without the per-call overhead.
+pub fn precondition_diagonal(d: &[f64], x: &mut [Complex<f64>]) -> Result<(), BlasError> {
+    check_shape("d", x.len(), d.len())?;
+    for (xi, &di) in x.iter_mut().zip(d) {
+        *xi = xi.scale(di);
+    }
+    Ok(())
+}
+
+/// Returns the 0-based index of the first element with the largest BLAS modulus `|re| + |im|` in `x`, via [`argmax_mod`]
+/// (`cblas_izamax`), or `None` if `x` is empty.
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers.
+///
+/// # Discussion
+/// The reference Fortran `izamax` and most BLAS documentation count from 1, but the CBLAS interface in
+/// Accelerate returns a 0-based index, and that is what this function passes through: `Some(i)` always means
+/// `x[i]`, with no adjustment needed. Ties go to the first, lowest index, as in BLAS. A slice longer than a C
+/// `int` can describe is searched in chunks, and the chunk winners are compared in Rust. NaN handling is left
+/// to BLAS; see [`izamax_nan_aware`] for a fixed NaN policy.
+pub fn argmax(x: &[Complex<f64>]) -> Option<usize> {
+    let mut best: Option<(usize, f64)> = None;
+    for (chunk_index, chunk) in x.chunks(c_int::MAX as usize).enumerate() {
+        // The chunk length fits in a C int by construction.
+        let n = chunk.len() as c_int;
+        let i = unsafe { traced!("cblas_izamax", [n], argmax_mod(n, chunk.as_ptr(), 1)) } as usize;
+        let v = chunk[i];
+        let magnitude = v.re.abs() + v.im.abs();
+        if best.map_or(true, |(_, max)| magnitude > max) {
+            best = Some((chunk_index * c_int::MAX as usize + i, magnitude));
+        }
+    }
+    best.map(|(i, _)| i)
+}
+
+/// Computes the Euclidean norm `||x||_2` of a contiguous vector via [`norm2`] (`cblas_dznrm2`).
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers, returning an `f64`.
+///
+/// # Returns
+/// The norm, or `0.0` for an empty slice.
+///
+/// # Errors
+/// Returns [`BlasError::IntOverflow`] if `x` is longer than a C `int` can count.
+pub fn dznrm2(x: &[Complex<f64>]) -> Result<f64, BlasError> {
+    dznrm2_strided(x.len(), x, 1)
+}
+
+/// Computes the Euclidean norm of `n` strided elements of `x` via [`norm2`] (`cblas_dznrm2`).
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers, returning an `f64`.
+///
+/// # Parameters
+/// - `n`: The number of logical elements in `x`.
+/// - `x`: The vector.
+/// - `inc_x`: The increment between elements in `x`.
+///
+/// # Returns
+/// The norm, or `0.0` when `n` is zero.
+///
+/// # Errors
+/// Returns [`BlasError::BufferTooSmall`] if `x` is shorter than `(n - 1) * |inc_x| + 1` elements.
+/// Returns [`BlasError::ZeroIncrement`] if `inc_x` is zero.
+pub fn dznrm2_strided(n: usize, x: &[Complex<f64>], inc_x: i32) -> Result<f64, BlasError> {
+    check_inc("inc_x", inc_x)?;
+    check_len("x", vec_len(n, inc_x), x.len())?;
+    let norm = unsafe {
+        traced!(
+            "cblas_dznrm2",
+            [n],
+            norm2(to_c_int(n, "n")?, x.as_ptr(), inc_x)
+        )
+    };
+    Ok(norm)
+}
+
+/// Scales a complex vector by a **real** scalar, `x = alpha * x`, via [`scale_by_f64`] (`cblas_zdscal`).
+///
+/// This is the double-precision counterpart of [`csscal`](crate::vector_c32::csscal): both the real and
+/// imaginary parts of every element are multiplied by `alpha`, which is cheaper than [`scale_by_c64`]
+/// (`cblas_zscal`) with `Complex::new(alpha, 0.0)`.
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers with a real `f64` scalar.
+///
+/// # Parameters
+/// - `n`: The number of logical elements in `x`.
+/// - `alpha`: The real scaling factor.
+/// - `x`: The vector to scale in place.
+/// - `inc_x`: The increment between elements in `x`.
+///
+/// # Errors
+/// Returns [`BlasError::BufferTooSmall`] if `x` is too short for `n` elements at its increment.
+/// Returns [`BlasError::ZeroIncrement`] if `inc_x` is zero.
+pub fn zdscal(n: usize, alpha: f64, x: &mut [Complex<f64>], inc_x: i32) -> Result<(), BlasError> {
+    check_inc("inc_x", inc_x)?;
+    check_len("x", vec_len(n, inc_x), x.len())?;
+    unsafe {
+        traced!(
+            "cblas_zdscal",
+            [n],
+            scale_by_f64(to_c_int(n, "n")?, alpha, x.as_mut_ptr(), inc_x)
+        );
+    }
+    Ok(())
+}
+
+/// Scales a contiguous vector in place by a complex scalar, `x = alpha * x`, via [`scale_by_c64`]
+/// (`cblas_zscal`).
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers.
+///
+/// # Errors
+/// Returns [`BlasError::IntOverflow`] if `x` is longer than a C `int` can count.
+///
+/// # Discussion
+/// `alpha` is taken by value and passed to BLAS by reference for the call. For a real factor,
+/// [`zscal_real`] is cheaper.
+pub fn zscal(alpha: Complex<f64>, x: &mut [Complex<f64>]) -> Result<(), BlasError> {
+    let n = x.len();
+    unsafe {
+        traced!(
+            "cblas_zscal",
+            [n],
+            scale_by_c64(to_c_int(n, "n")?, &alpha, x.as_mut_ptr(), 1)
+        );
+    }
+    Ok(())
+}
+
+/// Scales a contiguous vector in place by a **real** scalar via [`zdscal`] (`cblas_zdscal`).
+///
+/// # Precision
+/// This function operates on double-precision complex (`Complex<f64>`) numbers with a real `f64` scalar.
+///
+/// # Errors
+/// Returns [`BlasError::IntOverflow`] if `x` is longer than a C `int` can count.
+pub fn zscal_real(alpha: f64, x: &mut [Complex<f64>]) -> Result<(), BlasError> {
+    zdscal(x.len(), alpha, x, 1)
+}
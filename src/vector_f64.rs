@@ -1,3 +1,6 @@
+use crate::error::BlasError;
+use crate::validate::{check_inc, check_indices, check_len, check_shape, to_c_int, vec_len};
+use crate::vforce;
 use std::ffi::{c_double, c_int};
 
 #[link(name = "Accelerate", kind = "framework")]
@@ -96,6 +99,25 @@ extern "C" {
     #[link_name = "cblas_dasum"]
     pub fn norm1(n: c_int, x: *const c_double, inc_x: c_int) -> c_double;
 
+    /// Computes the L2 norm (Euclidean length) of a double-precision vector `x`.
+    ///
+    /// # Precision
+    /// This function operates on double-precision (`f64`) numbers.
+    ///
+    /// # Parameters
+    /// - `n`: The number of elements in the vector.
+    /// - `x`: A pointer to the input vector `x`.
+    /// - `inc_x`: The increment between elements in `x`.
+    ///
+    /// # Returns
+    /// Returns the L2 norm (Euclidean length) of `x`.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function. The caller must ensure that the memory region accessed by `x`
+    /// (up to `n * inc_x` elements) is valid and within bounds.
+    #[link_name = "cblas_dnrm2"]
+    pub fn norm2(n: c_int, x: *const c_double, inc_x: c_int) -> c_double;
+
     /// Computes `y = alpha * x + y` where `x` and `y` are vectors.
     ///
     /// # Precision
@@ -112,6 +134,9 @@ extern "C" {
     /// # Safety
     /// This is an `unsafe` C function. The caller must ensure that the memory regions for `x` and `y`
     /// (up to `n * inc_x` and `n * inc_y` elements, respectively) are valid and within bounds.
+    ///
+    /// `x` and `y` must not overlap. BLAS reads `x` while it writes `y`, so aliased buffers give unspecified
+    /// results. The safe [`axpy`] and [`copy_into`] wrappers rule this out through the borrow checker.
     #[link_name = "cblas_daxpy"]
     pub fn lin_comb(
         n: c_int,
@@ -122,6 +147,24 @@ extern "C" {
         inc_y: c_int,
     );
 
+    /// Multiplies each element of a vector by a constant.
+    ///
+    /// This function performs the operation `x[i] = alpha * x[i]` for each element in the vector `x`.
+    ///
+    /// # Precision
+    /// This function operates on double-precision (`f64`) numbers.
+    ///
+    /// # Parameters
+    /// - `n`: The number of elements in the vector `x`.
+    /// - `alpha`: The constant to multiply each element of `x` by.
+    /// - `x`: A pointer to the vector `x`. The result is stored in-place.
+    /// - `inc_x`: Stride within `x`. For example, if `inc_x` is 7, every 7th element is scaled by `alpha`.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function. The caller must ensure that the pointer passed to `x` is valid and that accessing `x` up to `n * inc_x` is safe.
+    #[link_name = "cblas_dscal"]
+    pub fn scale(n: c_int, alpha: c_double, x: *mut c_double, inc_x: c_int);
+
     /// Copies vector `x` to vector `y`.
     ///
     /// # Precision
@@ -137,9 +180,29 @@ extern "C" {
     /// # Safety
     /// This is an `unsafe` C function. The caller must ensure that the memory regions for `x` and `y`
     /// (up to `n * inc_x` and `n * inc_y` elements, respectively) are valid and within bounds.
+    ///
+    /// `x` and `y` must not overlap. BLAS reads `x` while it writes `y`, so aliased buffers give unspecified
+    /// results. The safe [`axpy`] and [`copy_into`] wrappers rule this out through the borrow checker.
     #[link_name = "cblas_dcopy"]
     pub fn copy(n: c_int, x: *const c_double, inc_x: c_int, y: *mut c_double, inc_y: c_int);
 
+    /// Exchanges the elements of two double-precision vectors `x` and `y`.
+    ///
+    /// # Precision
+    /// This function operates on double-precision (`f64`) numbers.
+    ///
+    /// # Parameters
+    /// - `n`: The number of elements in vectors `x` and `y`.
+    /// - `x`: A pointer to the first vector `x`. On return, contains elements copied from vector `y`.
+    /// - `inc_x`: The increment between elements in vector `x`.
+    /// - `y`: A pointer to the second vector `y`. On return, contains elements copied from vector `x`.
+    /// - `inc_y`: The increment between elements in vector `y`.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function. The caller must ensure that the pointers passed to `x` and `y` are valid, and that accessing `x` and `y` up to `n * inc_x` and `n * inc_y` is safe.
+    #[link_name = "cblas_dswap"]
+    pub fn swap(n: c_int, x: *mut c_double, inc_x: c_int, y: *mut c_double, inc_y: c_int);
+
     /// Finds the index of the element with the largest absolute value in the double-precision vector `x`.
     ///
     /// # Precision
@@ -156,7 +219,570 @@ extern "C" {
     /// # Safety
     /// This is an `unsafe` C function. The caller must ensure that the memory region accessed by `x`
     /// (up to `n * inc_x`) is valid and within bounds.
+    ///
+    /// With NaN elements in `x` the returned index is implementation-defined. Use [`idamax_nan_aware`]
+    /// when the input may contain NaN and a predictable answer is needed.
     #[link_name = "cblas_idamax"]
     pub fn argmax_mod(n: c_int, x: *const c_double, inc_x: c_int) -> c_int;
 
 }
+
+/// Computes the cosine similarity `x . y / (||x|| * ||y||)` of two vectors.
+///
+/// The numerator is formed with [`dot`] (`cblas_ddot`) and the norms with [`norm2`] (`cblas_dnrm2`).
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `x`: The first vector.
+/// - `y`: The second vector, must have the same length as `x`.
+///
+/// # Returns
+/// - The cosine of the angle between `x` and `y`, in `[-1, 1]` up to rounding. If either vector has a zero
+///   norm (including the empty case) the angle is undefined and `0.0` is returned instead of `NaN`.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `y` does not hold as many elements as `x`.
+pub fn cosine_similarity(x: &[f64], y: &[f64]) -> Result<f64, BlasError> {
+    check_shape("y", x.len(), y.len())?;
+    let n = x.len();
+    let (xy, norm_x, norm_y) = unsafe {
+        (
+            traced!(
+                "cblas_ddot",
+                [n],
+                dot(to_c_int(n, "n")?, x.as_ptr(), 1, y.as_ptr(), 1)
+            ),
+            traced!("cblas_dnrm2", [n], norm2(to_c_int(n, "n")?, x.as_ptr(), 1)),
+            traced!("cblas_dnrm2", [n], norm2(to_c_int(n, "n")?, y.as_ptr(), 1)),
+        )
+    };
+    if norm_x == 0.0 || norm_y == 0.0 {
+        return Ok(0.0);
+    }
+    if (norm_x * norm_y).is_normal() {
+        return Ok(xy / norm_x / norm_y);
+    }
+    // The norms are so small or so large that their product, and with it the dot product, under- or
+    // overflows. Normalizing copies first brings every term back into range.
+    let x: Vec<f64> = x.iter().map(|v| v / norm_x).collect();
+    let y: Vec<f64> = y.iter().map(|v| v / norm_y).collect();
+    let cos = unsafe {
+        traced!(
+            "cblas_ddot",
+            [n],
+            dot(to_c_int(n, "n")?, x.as_ptr(), 1, y.as_ptr(), 1)
+        )
+    };
+    Ok(cos)
+}
+
+/// Computes the linear combination `sum_i coeffs[i] * vectors[i]` into a new vector.
+///
+/// The first vector is copied and scaled with [`scale`] (`cblas_dscal`), then every further vector is
+/// accumulated with [`lin_comb`] (`cblas_daxpy`). This generalizes the two-vector `axpby` to any number of
+/// terms, as when combining basis functions in spectral or interpolation methods.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `coeffs`: One coefficient per vector.
+/// - `vectors`: The vectors to combine, all of the same length.
+///
+/// # Returns
+/// - The combined vector, or an empty vector when there are no terms.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `coeffs` and `vectors` differ in length or the vectors do not
+/// all share the length of the first one.
+pub fn linear_combination(coeffs: &[f64], vectors: &[&[f64]]) -> Result<Vec<f64>, BlasError> {
+    check_shape("vectors", coeffs.len(), vectors.len())?;
+    let (first, rest) = match vectors.split_first() {
+        Some(split) => split,
+        None => return Ok(Vec::new()),
+    };
+    let n = first.len();
+    for v in rest {
+        check_shape("vectors", n, v.len())?;
+    }
+    let n_c = to_c_int(n, "n")?;
+    let mut out = first.to_vec();
+    unsafe {
+        traced!(
+            "cblas_dscal",
+            [n],
+            scale(n_c, coeffs[0], out.as_mut_ptr(), 1)
+        );
+        for (alpha, v) in coeffs[1..].iter().zip(rest) {
+            traced!(
+                "cblas_daxpy",
+                [n],
+                lin_comb(n_c, *alpha, v.as_ptr(), 1, out.as_mut_ptr(), 1)
+            );
+        }
+    }
+    Ok(out)
+}
+
+/// Gathers the elements of `src` at `indices` into a new dense vector, i.e. `out[i] = src[indices[i]]`.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `src`: The vector to read from.
+/// - `indices`: The positions in `src` to read, in output order. Indices may repeat.
+///
+/// # Errors
+/// Returns [`BlasError::IndexOutOfBounds`] if any index is not less than `src.len()`.
+pub fn gather(src: &[f64], indices: &[usize]) -> Result<Vec<f64>, BlasError> {
+    check_indices("src", indices, src.len())?;
+    Ok(indices.iter().map(|&i| src[i]).collect())
+}
+
+/// Adds each of `values` into `dst` at the matching position of `indices`, i.e. `dst[indices[i]] += values[i]`.
+///
+/// Repeated indices accumulate. Together with [`gather`] this applies a dense kernel such as `axpy` to a
+/// selected subset of a vector.
+///
+/// # Precision
+/// This function operates on double-precision (`f64`) numbers.
+///
+/// # Parameters
+/// - `dst`: The vector to update in place.
+/// - `indices`: The positions in `dst` to update.
+/// - `values`: The values to add, one per index.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `values` does not hold one element per index and
+/// [`BlasError::IndexOutOfBounds`] if any index is not less than `dst.len()`. `dst` is left untouched on error.
+pub fn scatter_add(dst: &mut [f64], indices: &[usize], values: &[f64]) -> Result<(), BlasError> {
+    check_shape("values", indices.len(), values.len())?;
+    check_indices("dst", indices, dst.len())?;
+    for (&i, &v) in indices.iter().zip(values) {
+        dst[i] += v;
+    }
+    Ok(())
+}
+
+/// Computes `y = alpha * x + y` via [`lin_comb`] (`cblas_daxpy`).
+///
+/// # Precision
+/// This function operates on double-precision floating-point (`f64`) numbers.
+///
+/// # Parameters
+/// - `n`: The number of logical elements in `x` and `y`.
+/// - `alpha`: The scalar multiplying `x`.
+/// - `x`: The vector to add.
+/// - `inc_x`: The increment between elements in `x`.
+/// - `y`: The vector to accumulate into.
+/// - `inc_y`: The increment between elements in `y`.
+///
+/// # Errors
+/// Returns [`BlasError::BufferTooSmall`] if `x` or `y` is too short for `n` elements at its increment.
+/// Returns [`BlasError::ZeroIncrement`] if `inc_x` or `inc_y` is zero.
+///
+/// # Discussion
+/// BLAS requires `x` and `y` not to overlap. Taking `x` as `&[_]` and `y` as `&mut [_]` lets the borrow
+/// checker enforce this, so unlike the raw binding this wrapper cannot be handed aliased buffers.
+pub fn axpy(
+    n: usize,
+    alpha: f64,
+    x: &[f64],
+    inc_x: i32,
+    y: &mut [f64],
+    inc_y: i32,
+) -> Result<(), BlasError> {
+    check_inc("inc_x", inc_x)?;
+    check_len("x", vec_len(n, inc_x), x.len())?;
+    check_inc("inc_y", inc_y)?;
+    check_len("y", vec_len(n, inc_y), y.len())?;
+    unsafe {
+        traced!(
+            "cblas_daxpy",
+            [n],
+            lin_comb(
+                to_c_int(n, "n")?,
+                alpha,
+                x.as_ptr(),
+                inc_x,
+                y.as_mut_ptr(),
+                inc_y
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Computes `y = alpha * x + y` for equal-length, contiguous vectors via [`lin_comb`] (`cblas_daxpy`).
+///
+/// # Precision
+/// This function operates on double-precision floating-point (`f64`) numbers.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `x` and `y` differ in length.
+///
+/// # Discussion
+/// This is [`axpy`] with `n` taken from the slices and unit strides, for the common `y += a * x` update.
+pub fn daxpy(alpha: f64, x: &[f64], y: &mut [f64]) -> Result<(), BlasError> {
+    check_shape("y", x.len(), y.len())?;
+    axpy(x.len(), alpha, x, 1, y, 1)
+}
+
+/// Copies `n` elements of `x` into `y` via [`copy`] (`cblas_dcopy`).
+///
+/// # Precision
+/// This function operates on double-precision floating-point (`f64`) numbers.
+///
+/// # Parameters
+/// - `n`: The number of logical elements to copy.
+/// - `x`: The source vector.
+/// - `inc_x`: The increment between elements in `x`.
+/// - `y`: The destination vector.
+/// - `inc_y`: The increment between elements in `y`.
+///
+/// # Errors
+/// Returns [`BlasError::BufferTooSmall`] if `x` or `y` is too short for `n` elements at its increment.
+/// Returns [`BlasError::ZeroIncrement`] if `inc_x` or `inc_y` is zero.
+///
+/// # Discussion
+/// As with [`axpy`], the borrow checker guarantees that `x` and `y` do not overlap.
+pub fn copy_into(
+    n: usize,
+    x: &[f64],
+    inc_x: i32,
+    y: &mut [f64],
+    inc_y: i32,
+) -> Result<(), BlasError> {
+    check_inc("inc_x", inc_x)?;
+    check_len("x", vec_len(n, inc_x), x.len())?;
+    check_inc("inc_y", inc_y)?;
+    check_len("y", vec_len(n, inc_y), y.len())?;
+    unsafe {
+        traced!(
+            "cblas_dcopy",
+            [n],
+            copy(to_c_int(n, "n")?, x.as_ptr(), inc_x, y.as_mut_ptr(), inc_y)
+        );
+    }
+    Ok(())
+}
+
+/// Finds the index of the element with the largest absolute value in `x`, with a fixed NaN policy: the
+/// first NaN element is always selected.
+///
+/// [`argmax_mod`] (`cblas_idamax`) gives no guarantee about NaN inputs, and in practice a NaN may be
+/// skipped or returned depending on where it sits. Here a NaN is treated as larger than every number, so
+/// corrupted input (for example a bad sensor sample) surfaces as the peak instead of being silently
+/// ignored. Callers that want NaN skipped can test the selected element with `is_nan` and filter first.
+///
+/// # Precision
+/// This function operates on double-precision floating-point (`f64`) numbers.
+///
+/// # Parameters
+/// - `x`: The vector to search.
+/// - `inc_x`: The increment between elements in `x`; every element at a multiple of `inc_x` is considered.
+///
+/// # Returns
+/// - The logical (0-based) index of the first NaN element if there is one, otherwise of the first element
+///   with the largest absolute value, or `None` if `x` is empty or `inc_x` is not positive.
+pub fn idamax_nan_aware(x: &[f64], inc_x: i32) -> Option<usize> {
+    if inc_x <= 0 {
+        return None;
+    }
+    let mut best: Option<(usize, f64)> = None;
+    for (i, v) in x.iter().step_by(inc_x as usize).enumerate() {
+        if v.is_nan() {
+            return Some(i);
+        }
+        let abs = v.abs();
+        if best.map_or(true, |(_, max)| abs > max) {
+            best = Some((i, abs));
+        }
+    }
+    best.map(|(i, _)| i)
+}
+
+/// Updates an exponential moving average in place: `y = alpha * x + (1 - alpha) * y`, via [`lin_comb_catlas`]
+/// (`catlas_daxpby`).
+///
+/// # Precision
+/// This function operates on double-precision floating-point (`f64`) numbers.
+///
+/// # Parameters
+/// - `alpha`: The weight of the new sample, usually in `[0, 1]`. Larger values track `x` more closely;
+///   `1` replaces `y` with `x` and `0` leaves `y` unchanged.
+/// - `x`: The new sample.
+/// - `y`: The running average, updated in place.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `x` and `y` differ in length.
+pub fn ema_update(alpha: f64, x: &[f64], y: &mut [f64]) -> Result<(), BlasError> {
+    let n = y.len();
+    check_shape("x", n, x.len())?;
+    unsafe {
+        traced!(
+            "catlas_daxpby",
+            [n],
+            lin_comb_catlas(
+                to_c_int(n, "n")?,
+                alpha,
+                x.as_ptr(),
+                1,
+                1.0 - alpha,
+                y.as_mut_ptr(),
+                1
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Clamps every `inc_x`-th element of `x` into `[min, max]` in place.
+///
+/// # Precision
+/// This function operates on double-precision floating-point (`f64`) numbers.
+///
+/// # Parameters
+/// - `x`: The vector, updated in place. Elements `x[0]`, `x[inc_x]`, `x[2 * inc_x]`, ... are clamped and the
+///   ones in between are left alone.
+/// - `inc_x`: The increment between elements of `x`. As with `cblas_dscal`, a negative increment makes
+///   this a no-op.
+/// - `min`, `max`: The bounds of the interval.
+///
+/// # Errors
+/// Returns [`BlasError::ZeroIncrement`] if `inc_x` is zero, and [`BlasError::InvalidBounds`] if either bound
+/// is NaN or `min > max`, the cases in which [`f64::clamp`] panics.
+///
+/// # Discussion
+/// NaN elements are left as NaN rather than pulled to a bound, so a saturation step does not hide an
+/// upstream error.
+pub fn clamp(x: &mut [f64], inc_x: i32, min: f64, max: f64) -> Result<(), BlasError> {
+    check_inc("inc_x", inc_x)?;
+    if max.is_nan() {
+        return Err(BlasError::InvalidBounds { which: "max" });
+    }
+    if min.is_nan() || min > max {
+        return Err(BlasError::InvalidBounds { which: "min" });
+    }
+    if inc_x < 0 {
+        return Ok(());
+    }
+    for v in x.iter_mut().step_by(inc_x as usize) {
+        if *v < min {
+            *v = min;
+        } else if *v > max {
+            *v = max;
+        }
+    }
+    Ok(())
+}
+
+/// Counts the elements of `x` that are subnormal.
+///
+/// # Precision
+/// This function operates on double-precision floating-point (`f64`) numbers.
+///
+/// # Discussion
+/// Subnormal values (non-zero but smaller in magnitude than `f64::MIN_POSITIVE`) can slow BLAS kernels
+/// sharply; see [`vector_f32::count_subnormals`](crate::vector_f32::count_subnormals) for when to check.
+pub fn count_subnormals(x: &[f64]) -> usize {
+    x.iter().filter(|v| v.is_subnormal()).count()
+}
+
+/// Replaces `x` with its softmax, `x[i] = exp(x[i] - max) / sum(exp(x[j] - max))`, in place.
+///
+/// # Precision
+/// This function operates on double-precision floating-point (`f64`) numbers.
+///
+/// # Errors
+/// Returns [`BlasError::IntOverflow`] if `x` is longer than a C `int` can describe.
+///
+/// # Discussion
+/// Subtracting the largest element first keeps every exponent at or below zero, so large logits cannot
+/// overflow to infinity and a finite input always gives a result that sums to one. The maximum is taken
+/// in Rust rather than with `cblas_idamax`, which finds the largest magnitude, not the largest value. The
+/// exponentials come from one in-place `vvexp` call over the whole buffer, the sum from `cblas_dasum`
+/// (every term is positive, so the sum of magnitudes is the sum) and the normalization from `cblas_dscal`.
+///
+/// Infinite logits are handled separately, since `inf - inf` is NaN. If any element is `+inf`, those
+/// elements share the mass equally (the limit of the softmax as they grow together) and every other element
+/// becomes zero. If every element is `-inf` the softmax is undefined and every output is NaN, as it is when
+/// any input is NaN. An empty slice is left alone.
+pub fn softmax(x: &mut [f64]) -> Result<(), BlasError> {
+    if x.is_empty() {
+        return Ok(());
+    }
+    let n = to_c_int(x.len(), "n")?;
+    let max = x.iter().fold(f64::NEG_INFINITY, |max, &v| {
+        if v > max || v.is_nan() {
+            v
+        } else {
+            max
+        }
+    });
+    if max == f64::INFINITY {
+        let count = x.iter().filter(|&&v| v == f64::INFINITY).count() as f64;
+        for v in x.iter_mut() {
+            *v = if *v == f64::INFINITY {
+                1.0 / count
+            } else {
+                0.0
+            };
+        }
+        return Ok(());
+    }
+    if max == f64::NEG_INFINITY {
+        x.fill(f64::NAN);
+        return Ok(());
+    }
+    for v in x.iter_mut() {
+        *v -= max;
+    }
+    let ptr = x.as_mut_ptr();
+    unsafe {
+        traced!("vvexp", [n], vforce::exp_f64(ptr, ptr, &n));
+        let sum = traced!("cblas_dasum", [n], norm1(n, ptr, 1));
+        traced!("cblas_dscal", [n], scale(n, 1.0 / sum, ptr, 1));
+    }
+    Ok(())
+}
+
+/// Returns the 0-based index of the first element with the largest absolute value in `x`, via [`argmax_mod`]
+/// (`cblas_idamax`), or `None` if `x` is empty.
+///
+/// # Precision
+/// This function operates on double-precision floating-point (`f64`) numbers.
+///
+/// # Discussion
+/// The reference Fortran `idamax` and most BLAS documentation count from 1, but the CBLAS interface in
+/// Accelerate returns a 0-based index, and that is what this function passes through: `Some(i)` always means
+/// `x[i]`, with no adjustment needed. Ties go to the first, lowest index, as in BLAS. A slice longer than a C
+/// `int` can describe is searched in chunks, and the chunk winners are compared in Rust. NaN handling is left
+/// to BLAS; see [`idamax_nan_aware`] for a fixed NaN policy.
+pub fn argmax(x: &[f64]) -> Option<usize> {
+    let mut best: Option<(usize, f64)> = None;
+    for (chunk_index, chunk) in x.chunks(c_int::MAX as usize).enumerate() {
+        // The chunk length fits in a C int by construction.
+        let n = chunk.len() as c_int;
+        let i = unsafe { traced!("cblas_idamax", [n], argmax_mod(n, chunk.as_ptr(), 1)) } as usize;
+        let v = chunk[i];
+        let magnitude = v.abs();
+        if best.map_or(true, |(_, max)| magnitude > max) {
+            best = Some((chunk_index * c_int::MAX as usize + i, magnitude));
+        }
+    }
+    best.map(|(i, _)| i)
+}
+
+/// Computes the dot product `x^T y` of two equal-length, contiguous vectors via [`dot`] (`cblas_ddot`).
+///
+/// # Precision
+/// This function operates on double-precision floating-point (`f64`) numbers.
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `x` and `y` differ in length.
+pub fn ddot(x: &[f64], y: &[f64]) -> Result<f64, BlasError> {
+    check_shape("y", x.len(), y.len())?;
+    ddot_strided(x.len(), x, 1, y, 1)
+}
+
+/// Computes the dot product of `n` strided elements of `x` and `y` via [`dot`] (`cblas_ddot`).
+///
+/// # Precision
+/// This function operates on double-precision floating-point (`f64`) numbers.
+///
+/// # Parameters
+/// - `n`: The number of logical elements in `x` and `y`.
+/// - `x`: The first vector.
+/// - `inc_x`: The increment between elements in `x`.
+/// - `y`: The second vector.
+/// - `inc_y`: The increment between elements in `y`.
+///
+/// # Errors
+/// Returns [`BlasError::BufferTooSmall`] if `x` or `y` is shorter than `(n - 1) * |inc| + 1` elements.
+/// Returns [`BlasError::ZeroIncrement`] if `inc_x` or `inc_y` is zero.
+pub fn ddot_strided(
+    n: usize,
+    x: &[f64],
+    inc_x: i32,
+    y: &[f64],
+    inc_y: i32,
+) -> Result<f64, BlasError> {
+    check_inc("inc_x", inc_x)?;
+    check_len("x", vec_len(n, inc_x), x.len())?;
+    check_inc("inc_y", inc_y)?;
+    check_len("y", vec_len(n, inc_y), y.len())?;
+    let dot = unsafe {
+        traced!(
+            "cblas_ddot",
+            [n],
+            dot(to_c_int(n, "n")?, x.as_ptr(), inc_x, y.as_ptr(), inc_y)
+        )
+    };
+    Ok(dot)
+}
+
+/// Computes the Euclidean norm `||x||_2` of a contiguous vector via [`norm2`] (`cblas_dnrm2`).
+///
+/// # Precision
+/// This function operates on double-precision floating-point (`f64`) numbers.
+///
+/// # Returns
+/// The norm, or `0.0` for an empty slice.
+///
+/// # Errors
+/// Returns [`BlasError::IntOverflow`] if `x` is longer than a C `int` can count.
+pub fn dnrm2(x: &[f64]) -> Result<f64, BlasError> {
+    dnrm2_strided(x.len(), x, 1)
+}
+
+/// Computes the Euclidean norm of `n` strided elements of `x` via [`norm2`] (`cblas_dnrm2`).
+///
+/// # Precision
+/// This function operates on double-precision floating-point (`f64`) numbers.
+///
+/// # Parameters
+/// - `n`: The number of logical elements in `x`.
+/// - `x`: The vector.
+/// - `inc_x`: The increment between elements in `x`.
+///
+/// # Returns
+/// The norm, or `0.0` when `n` is zero.
+///
+/// # Errors
+/// Returns [`BlasError::BufferTooSmall`] if `x` is shorter than `(n - 1) * |inc_x| + 1` elements.
+/// Returns [`BlasError::ZeroIncrement`] if `inc_x` is zero.
+pub fn dnrm2_strided(n: usize, x: &[f64], inc_x: i32) -> Result<f64, BlasError> {
+    check_inc("inc_x", inc_x)?;
+    check_len("x", vec_len(n, inc_x), x.len())?;
+    let norm = unsafe {
+        traced!(
+            "cblas_dnrm2",
+            [n],
+            norm2(to_c_int(n, "n")?, x.as_ptr(), inc_x)
+        )
+    };
+    Ok(norm)
+}
+
+/// Scales a contiguous vector in place, `x = alpha * x`, via [`scale`] (`cblas_dscal`).
+///
+/// # Precision
+/// This function operates on double-precision floating-point (`f64`) numbers.
+///
+/// # Errors
+/// Returns [`BlasError::IntOverflow`] if `x` is longer than a C `int` can count.
+pub fn dscal(alpha: f64, x: &mut [f64]) -> Result<(), BlasError> {
+    let n = x.len();
+    unsafe {
+        traced!(
+            "cblas_dscal",
+            [n],
+            scale(to_c_int(n, "n")?, alpha, x.as_mut_ptr(), 1)
+        );
+    }
+    Ok(())
+}
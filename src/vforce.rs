@@ -0,0 +1,170 @@
+use crate::error::BlasError;
+use crate::validate::{check_shape, to_c_int};
+use std::ffi::{c_double, c_float, c_int};
+
+#[link(name = "Accelerate", kind = "framework")]
+extern "C" {
+    /// Computes `y[i] = exp(x[i])` for `n` elements.
+    ///
+    /// # Precision
+    /// This function operates on single-precision (`f32`) numbers.
+    ///
+    /// # Parameters
+    /// - `y`: The output vector, `n` elements long.
+    /// - `x`: The input vector, `n` elements long.
+    /// - `n`: A pointer to the number of elements.
+    ///
+    /// # Safety
+    /// This is an `unsafe` C function. `y` and `x` must be valid for `n` elements; they may be the same
+    /// buffer but must not otherwise overlap.
+    #[link_name = "vvexpf"]
+    pub fn exp_f32(y: *mut c_float, x: *const c_float, n: *const c_int);
+
+    /// Computes `y[i] = exp(x[i])` for `n` elements.
+    ///
+    /// # Precision
+    /// This function operates on double-precision (`f64`) numbers.
+    ///
+    /// # Safety
+    /// Same contract as [`exp_f32`].
+    #[link_name = "vvexp"]
+    pub fn exp_f64(y: *mut c_double, x: *const c_double, n: *const c_int);
+
+    /// Computes the natural logarithm `y[i] = ln(x[i])` for `n` elements.
+    ///
+    /// # Precision
+    /// This function operates on single-precision (`f32`) numbers.
+    ///
+    /// # Safety
+    /// Same contract as [`exp_f32`].
+    #[link_name = "vvlogf"]
+    pub fn log_f32(y: *mut c_float, x: *const c_float, n: *const c_int);
+
+    /// Computes the natural logarithm `y[i] = ln(x[i])` for `n` elements.
+    ///
+    /// # Precision
+    /// This function operates on double-precision (`f64`) numbers.
+    ///
+    /// # Safety
+    /// Same contract as [`exp_f32`].
+    #[link_name = "vvlog"]
+    pub fn log_f64(y: *mut c_double, x: *const c_double, n: *const c_int);
+
+    /// Computes `y[i] = sqrt(x[i])` for `n` elements.
+    ///
+    /// # Precision
+    /// This function operates on single-precision (`f32`) numbers.
+    ///
+    /// # Safety
+    /// Same contract as [`exp_f32`].
+    #[link_name = "vvsqrtf"]
+    pub fn sqrt_f32(y: *mut c_float, x: *const c_float, n: *const c_int);
+
+    /// Computes `y[i] = sqrt(x[i])` for `n` elements.
+    ///
+    /// # Precision
+    /// This function operates on double-precision (`f64`) numbers.
+    ///
+    /// # Safety
+    /// Same contract as [`exp_f32`].
+    #[link_name = "vvsqrt"]
+    pub fn sqrt_f64(y: *mut c_double, x: *const c_double, n: *const c_int);
+}
+
+/// Element types accepted by the vForce wrappers, each dispatching to its own `vv*` or `vv*f` routine.
+pub trait VForceScalar: Copy {
+    /// Forwards to `vvexpf` or `vvexp`.
+    ///
+    /// # Safety
+    /// Same contract as [`exp_f32`].
+    unsafe fn vexp(y: *mut Self, x: *const Self, n: c_int);
+
+    /// Forwards to `vvlogf` or `vvlog`.
+    ///
+    /// # Safety
+    /// Same contract as [`exp_f32`].
+    unsafe fn vlog(y: *mut Self, x: *const Self, n: c_int);
+
+    /// Forwards to `vvsqrtf` or `vvsqrt`.
+    ///
+    /// # Safety
+    /// Same contract as [`exp_f32`].
+    unsafe fn vsqrt(y: *mut Self, x: *const Self, n: c_int);
+}
+
+impl VForceScalar for f32 {
+    unsafe fn vexp(y: *mut Self, x: *const Self, n: c_int) {
+        traced!("vvexpf", [n], exp_f32(y, x, &n))
+    }
+
+    unsafe fn vlog(y: *mut Self, x: *const Self, n: c_int) {
+        traced!("vvlogf", [n], log_f32(y, x, &n))
+    }
+
+    unsafe fn vsqrt(y: *mut Self, x: *const Self, n: c_int) {
+        traced!("vvsqrtf", [n], sqrt_f32(y, x, &n))
+    }
+}
+
+impl VForceScalar for f64 {
+    unsafe fn vexp(y: *mut Self, x: *const Self, n: c_int) {
+        traced!("vvexp", [n], exp_f64(y, x, &n))
+    }
+
+    unsafe fn vlog(y: *mut Self, x: *const Self, n: c_int) {
+        traced!("vvlog", [n], log_f64(y, x, &n))
+    }
+
+    unsafe fn vsqrt(y: *mut Self, x: *const Self, n: c_int) {
+        traced!("vvsqrt", [n], sqrt_f64(y, x, &n))
+    }
+}
+
+/// Computes `dst[i] = exp(src[i])` with vForce (`vvexpf` for `f32`, `vvexp` for `f64`).
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `src` and `dst` differ in length.
+///
+/// # Discussion
+/// vForce evaluates the whole buffer in one vectorized call, which is several times faster than calling
+/// `f32::exp` in a loop. It works on the same contiguous buffers the BLAS vector routines produce, so a
+/// softmax is this call followed by `asum` and `scal`. Like the scalar function, it overflows to infinity
+/// for large inputs; subtract the maximum first when that matters.
+pub fn exp<T: VForceScalar>(src: &[T], dst: &mut [T]) -> Result<(), BlasError> {
+    apply(T::vexp, src, dst)
+}
+
+/// Computes the natural logarithm `dst[i] = ln(src[i])` with vForce (`vvlogf` for `f32`, `vvlog` for
+/// `f64`).
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `src` and `dst` differ in length.
+///
+/// # Discussion
+/// Zero maps to negative infinity and negative inputs to NaN, as with the scalar function.
+pub fn log<T: VForceScalar>(src: &[T], dst: &mut [T]) -> Result<(), BlasError> {
+    apply(T::vlog, src, dst)
+}
+
+/// Computes `dst[i] = sqrt(src[i])` with vForce (`vvsqrtf` for `f32`, `vvsqrt` for `f64`).
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] if `src` and `dst` differ in length.
+///
+/// # Discussion
+/// Negative inputs map to NaN, as with the scalar function.
+pub fn sqrt<T: VForceScalar>(src: &[T], dst: &mut [T]) -> Result<(), BlasError> {
+    apply(T::vsqrt, src, dst)
+}
+
+/// Validates the lengths and runs one vForce routine over the whole buffer.
+fn apply<T: VForceScalar>(
+    f: unsafe fn(*mut T, *const T, c_int),
+    src: &[T],
+    dst: &mut [T],
+) -> Result<(), BlasError> {
+    check_shape("dst", src.len(), dst.len())?;
+    let n = to_c_int(src.len(), "n")?;
+    unsafe { f(dst.as_mut_ptr(), src.as_ptr(), n) };
+    Ok(())
+}
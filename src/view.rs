@@ -0,0 +1,607 @@
+use crate::constants::RowColMajor;
+use crate::error::BlasError;
+use crate::validate::{check_ld, check_len, check_shape, strided_len};
+use std::convert::TryFrom;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// A borrowed, read-only view of a dense matrix stored in a slice.
+///
+/// The view carries the shape, the leading dimension and the storage order, so it can be handed to
+/// the BLAS routines without copying. Consecutive rows (row-major) or columns (column-major) are
+/// `lda` elements apart, which allows views of sub-matrices of a larger buffer.
+pub struct MatrixView<'a, T> {
+    data: &'a [T],
+    rows: usize,
+    cols: usize,
+    lda: usize,
+    order: RowColMajor,
+}
+
+impl<'a, T> Clone for MatrixView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for MatrixView<'a, T> {}
+
+impl<'a, T> MatrixView<'a, T> {
+    /// Creates a view over `data`, checking that it holds a `rows x cols` matrix with leading dimension `lda`.
+    ///
+    /// # Errors
+    /// Returns [`BlasError::InvalidLeadingDimension`] if `lda` is smaller than `cols` (row-major) or
+    /// `rows` (column-major), and [`BlasError::BufferTooSmall`] if `data` is too short.
+    pub fn new(
+        data: &'a [T],
+        rows: usize,
+        cols: usize,
+        lda: usize,
+        order: RowColMajor,
+    ) -> Result<Self, BlasError> {
+        let (outer, inner) = outer_inner(rows, cols, order);
+        check_ld("lda", inner.max(1), lda)?;
+        check_len("data", strided_len(outer, inner, lda), data.len())?;
+        Ok(MatrixView {
+            data,
+            rows,
+            cols,
+            lda,
+            order,
+        })
+    }
+
+    /// Creates a view over memory owned elsewhere, typically a buffer handed over by C code.
+    ///
+    /// No copy is made; the view borrows the memory for the lifetime `'a` chosen by the caller.
+    ///
+    /// # Safety
+    /// The caller must ensure that:
+    /// - `lda` is at least `cols` (row-major) or `rows` (column-major).
+    /// - `ptr` is non-null, aligned, and valid for reads of every element of the matrix, i.e.
+    ///   `(outer - 1) * lda + inner` elements where `outer`/`inner` are the number of rows/columns
+    ///   (row-major) or columns/rows (column-major).
+    /// - The memory is not mutated or freed while the view (or anything derived from it) is alive.
+    pub unsafe fn from_raw(
+        ptr: *const T,
+        rows: usize,
+        cols: usize,
+        lda: usize,
+        order: RowColMajor,
+    ) -> Self {
+        let (outer, inner) = outer_inner(rows, cols, order);
+        let len = strided_len(outer, inner, lda);
+        let data = if len == 0 {
+            &[]
+        } else {
+            std::slice::from_raw_parts(ptr, len)
+        };
+        MatrixView {
+            data,
+            rows,
+            cols,
+            lda,
+            order,
+        }
+    }
+
+    /// The number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The distance, in elements, between consecutive rows (row-major) or columns (column-major).
+    pub fn lda(&self) -> usize {
+        self.lda
+    }
+
+    /// The storage order of the underlying buffer.
+    pub fn order(&self) -> RowColMajor {
+        self.order
+    }
+
+    /// The underlying buffer, starting at element `(0, 0)`.
+    pub fn as_slice(&self) -> &'a [T] {
+        self.data
+    }
+
+    /// A pointer to element `(0, 0)`, suitable for passing to the raw bindings.
+    pub fn as_ptr(&self) -> *const T {
+        self.data.as_ptr()
+    }
+
+    /// Returns element `(row, col)`, or `None` if it is out of range.
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        let index = match self.order {
+            RowColMajor::RowMajor => row * self.lda + col,
+            RowColMajor::ColMajor => col * self.lda + row,
+        };
+        self.data.get(index)
+    }
+
+    /// Returns the transpose of this matrix as a view of the same buffer.
+    ///
+    /// A row-major `rows x cols` matrix with leading dimension `lda` is, read column by column, its
+    /// `cols x rows` transpose, so this only swaps the shape and flips the storage order; no element
+    /// moves. The wrappers that take views, such as [`gemm_views`](crate::scalar::gemm_views), pass an
+    /// operand stored in the other order than the output with `TransposeMode::Trans`, so a transposed view
+    /// costs nothing at the call either.
+    pub fn transposed(&self) -> MatrixView<'a, T> {
+        MatrixView {
+            data: self.data,
+            rows: self.cols,
+            cols: self.rows,
+            lda: self.lda,
+            order: match self.order {
+                RowColMajor::RowMajor => RowColMajor::ColMajor,
+                RowColMajor::ColMajor => RowColMajor::RowMajor,
+            },
+        }
+    }
+
+    /// Iterates over the columns, each as a [`VectorView`] with the stride the storage order implies.
+    ///
+    /// Column-major columns are contiguous (`inc() == 1`); row-major columns step by `lda`. Either way
+    /// the column's `as_ptr()` and `inc()` can be passed to a vector routine such as `cblas_sdot`.
+    pub fn columns(&self) -> impl Iterator<Item = VectorView<'a, T>> + 'a {
+        let (data, rows, lda, order) = (self.data, self.rows, self.lda, self.order);
+        (0..self.cols).map(move |col| {
+            let (start, inc) = column_start_inc(col, lda, order);
+            let data = if rows == 0 {
+                &data[..0]
+            } else {
+                &data[start..start + (rows - 1) * inc + 1]
+            };
+            VectorView {
+                data,
+                len: rows,
+                inc,
+            }
+        })
+    }
+}
+
+/// A borrowed, mutable view of a dense matrix stored in a slice.
+///
+/// This is the mutable counterpart of [`MatrixView`], with the same shape, leading dimension and storage
+/// order rules.
+pub struct MatrixViewMut<'a, T> {
+    data: &'a mut [T],
+    rows: usize,
+    cols: usize,
+    lda: usize,
+    order: RowColMajor,
+}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    /// Creates a mutable view over `data`, checking that it holds a `rows x cols` matrix with leading
+    /// dimension `lda`.
+    ///
+    /// # Errors
+    /// Same as [`MatrixView::new`].
+    pub fn new(
+        data: &'a mut [T],
+        rows: usize,
+        cols: usize,
+        lda: usize,
+        order: RowColMajor,
+    ) -> Result<Self, BlasError> {
+        let (outer, inner) = outer_inner(rows, cols, order);
+        check_ld("lda", inner.max(1), lda)?;
+        check_len("data", strided_len(outer, inner, lda), data.len())?;
+        Ok(MatrixViewMut {
+            data,
+            rows,
+            cols,
+            lda,
+            order,
+        })
+    }
+
+    /// The number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The distance, in elements, between consecutive rows (row-major) or columns (column-major).
+    pub fn lda(&self) -> usize {
+        self.lda
+    }
+
+    /// The storage order of the underlying buffer.
+    pub fn order(&self) -> RowColMajor {
+        self.order
+    }
+
+    /// Reborrows this view as a read-only [`MatrixView`].
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            data: self.data,
+            rows: self.rows,
+            cols: self.cols,
+            lda: self.lda,
+            order: self.order,
+        }
+    }
+
+    /// A mutable pointer to element `(0, 0)`, suitable for passing to the raw bindings.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr()
+    }
+
+    /// Iterates over the columns, each as a [`VectorViewMut`].
+    ///
+    /// In row-major storage the columns interleave in memory, so they cannot be handed out as disjoint
+    /// `&mut [T]` slices. Each [`VectorViewMut`] instead covers exactly the elements of its own column,
+    /// which are disjoint from every other column's, so all of them may be held and written at once.
+    pub fn columns_mut(&mut self) -> impl Iterator<Item = VectorViewMut<'_, T>> + '_ {
+        let (ptr, rows, lda, order) = (self.data.as_mut_ptr(), self.rows, self.lda, self.order);
+        (0..self.cols).map(move |col| {
+            let (start, inc) = column_start_inc(col, lda, order);
+            VectorViewMut {
+                // In bounds: `new` checked that the buffer holds every element of the matrix, and an
+                // empty column never dereferences its pointer.
+                ptr: if rows == 0 {
+                    ptr
+                } else {
+                    unsafe { ptr.add(start) }
+                },
+                len: rows,
+                inc,
+                _marker: PhantomData,
+            }
+        })
+    }
+}
+
+/// A borrowed, read-only view of a strided vector: `len` elements `inc` apart.
+pub struct VectorView<'a, T> {
+    data: &'a [T],
+    len: usize,
+    inc: usize,
+}
+
+impl<'a, T> Clone for VectorView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for VectorView<'a, T> {}
+
+impl<'a, T> VectorView<'a, T> {
+    /// The number of logical elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the view has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The distance, in elements, between consecutive logical elements.
+    pub fn inc(&self) -> usize {
+        self.inc
+    }
+
+    /// The underlying buffer, from the first logical element to the last.
+    pub fn as_slice(&self) -> &'a [T] {
+        self.data
+    }
+
+    /// A pointer to the first logical element, suitable for passing to the raw bindings.
+    pub fn as_ptr(&self) -> *const T {
+        self.data.as_ptr()
+    }
+
+    /// Returns logical element `i`, or `None` if it is out of range.
+    pub fn get(&self, i: usize) -> Option<&'a T> {
+        if i >= self.len {
+            return None;
+        }
+        self.data.get(i * self.inc)
+    }
+
+    /// Iterates over the logical elements.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        self.data.iter().step_by(self.inc.max(1)).take(self.len)
+    }
+}
+
+/// A borrowed, mutable view of a strided vector: `len` elements `inc` apart.
+///
+/// Unlike [`VectorView`] this does not hold a slice, because the elements between the logical ones may
+/// belong to other views (for example the other columns of a row-major matrix) that are borrowed mutably
+/// at the same time. It only ever touches its own `len` elements.
+pub struct VectorViewMut<'a, T> {
+    ptr: *mut T,
+    len: usize,
+    inc: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> VectorViewMut<'a, T> {
+    /// The number of logical elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the view has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The distance, in elements, between consecutive logical elements.
+    pub fn inc(&self) -> usize {
+        self.inc
+    }
+
+    /// A pointer to the first logical element, suitable for passing to the raw bindings.
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+
+    /// A mutable pointer to the first logical element, suitable for passing to the raw bindings.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+
+    /// Returns logical element `i`, or `None` if it is out of range.
+    pub fn get(&self, i: usize) -> Option<&T> {
+        if i >= self.len {
+            return None;
+        }
+        Some(unsafe { &*self.ptr.add(i * self.inc) })
+    }
+
+    /// Returns logical element `i` mutably, or `None` if it is out of range.
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        if i >= self.len {
+            return None;
+        }
+        Some(unsafe { &mut *self.ptr.add(i * self.inc) })
+    }
+
+    /// Iterates mutably over the logical elements.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        let (ptr, inc) = (self.ptr, self.inc);
+        // Distinct indices address distinct elements, so the references never overlap.
+        (0..self.len).map(move |i| unsafe { &mut *ptr.add(i * inc) })
+    }
+}
+
+/// Copies a view out into a tight row-major buffer, returned with its `(rows, cols)`.
+///
+/// Padding between rows or columns is dropped and column-major data is transposed on the way, so the
+/// result is what code working with plain `Vec`s expects: element `(i, j)` at `i * cols + j`.
+impl<'a, T: Copy> From<MatrixView<'a, T>> for (Vec<T>, usize, usize) {
+    fn from(view: MatrixView<'a, T>) -> Self {
+        let mut data = Vec::with_capacity(view.rows * view.cols);
+        for i in 0..view.rows {
+            // In range by construction; `get` only fails outside the shape.
+            data.extend((0..view.cols).map(|j| *view.get(i, j).unwrap()));
+        }
+        (data, view.rows, view.cols)
+    }
+}
+
+/// Views a flat row-major `(data, rows, cols)` tuple as a tight [`MatrixView`].
+///
+/// # Errors
+/// Returns [`BlasError::DimensionMismatch`] unless `data.len() == rows * cols`.
+impl<'a, T> TryFrom<&'a (Vec<T>, usize, usize)> for MatrixView<'a, T> {
+    type Error = BlasError;
+
+    fn try_from(flat: &'a (Vec<T>, usize, usize)) -> Result<Self, Self::Error> {
+        let (data, rows, cols) = flat;
+        check_shape("data", rows * cols, data.len())?;
+        MatrixView::new(data, *rows, *cols, (*cols).max(1), RowColMajor::RowMajor)
+    }
+}
+
+/// An owned, immutable matrix behind an [`Arc`], for sharing one buffer across threads.
+///
+/// Cloning a `SharedMatrix` only bumps the reference count, so each thread (or rayon task) can hold its
+/// own handle and take a [`MatrixView`] of the same data. Accelerate only reads the matrix operand of
+/// routines such as `cblas_sgemv`, so concurrent calls on one shared matrix with separate output vectors
+/// are safe; `SharedMatrix<T>` is `Send + Sync` whenever `T` is.
+///
+/// # Example
+/// ```no_run
+/// use accelerate_general::constants::CblasOrder;
+/// use accelerate_general::view::SharedMatrix;
+///
+/// let weights = SharedMatrix::new(vec![1.0f32; 4 * 3], 4, 3, 3, CblasOrder::RowMajor).unwrap();
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let weights = weights.clone();
+///         std::thread::spawn(move || weights.view().rows())
+///     })
+///     .collect();
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap(), 4);
+/// }
+/// ```
+pub struct SharedMatrix<T> {
+    data: Arc<Vec<T>>,
+    rows: usize,
+    cols: usize,
+    lda: usize,
+    order: RowColMajor,
+}
+
+impl<T> Clone for SharedMatrix<T> {
+    fn clone(&self) -> Self {
+        SharedMatrix {
+            data: Arc::clone(&self.data),
+            rows: self.rows,
+            cols: self.cols,
+            lda: self.lda,
+            order: self.order,
+        }
+    }
+}
+
+impl<T> SharedMatrix<T> {
+    /// Takes ownership of `data`, checking that it holds a `rows x cols` matrix with leading dimension `lda`.
+    ///
+    /// # Errors
+    /// Same as [`MatrixView::new`].
+    pub fn new(
+        data: Vec<T>,
+        rows: usize,
+        cols: usize,
+        lda: usize,
+        order: RowColMajor,
+    ) -> Result<Self, BlasError> {
+        let (outer, inner) = outer_inner(rows, cols, order);
+        check_ld("lda", inner.max(1), lda)?;
+        check_len("data", strided_len(outer, inner, lda), data.len())?;
+        Ok(SharedMatrix {
+            data: Arc::new(data),
+            rows,
+            cols,
+            lda,
+            order,
+        })
+    }
+
+    /// The number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The distance, in elements, between consecutive rows (row-major) or columns (column-major).
+    pub fn lda(&self) -> usize {
+        self.lda
+    }
+
+    /// The storage order of the underlying buffer.
+    pub fn order(&self) -> RowColMajor {
+        self.order
+    }
+
+    /// Borrows the shared data as a [`MatrixView`].
+    pub fn view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            data: &self.data,
+            rows: self.rows,
+            cols: self.cols,
+            lda: self.lda,
+            order: self.order,
+        }
+    }
+}
+
+/// Largest number of rows, columns or vector elements the `Display` impls print before eliding the rest.
+const DISPLAY_LIMIT: usize = 10;
+
+/// Prints the matrix in logical row order, one row per line with right-aligned columns, whatever the
+/// storage order and `lda`.
+///
+/// At most 10 rows and 10 columns are shown; anything beyond is replaced by `...`. A precision given in
+/// the format string is applied to every element, so `{:.3}` works as expected.
+///
+/// ```text
+/// 2x3
+/// 1  -2  3
+/// 4   5  6
+/// ```
+impl<'a, T: fmt::Display> fmt::Display for MatrixView<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}x{}", self.rows, self.cols)?;
+        let rows = self.rows.min(DISPLAY_LIMIT);
+        let cols = self.cols.min(DISPLAY_LIMIT);
+        let mut cells = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                // In range by construction; `get` only fails outside the shape.
+                let value = self.get(i, j).unwrap();
+                cells.push(match f.precision() {
+                    Some(p) => format!("{:.*}", p, value),
+                    None => format!("{}", value),
+                });
+            }
+        }
+        let widths: Vec<usize> = (0..cols)
+            .map(|j| {
+                (0..rows)
+                    .map(|i| cells[i * cols + j].len())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        for i in 0..rows {
+            for (j, width) in widths.iter().enumerate() {
+                if j > 0 {
+                    f.write_str("  ")?;
+                }
+                write!(f, "{:>1$}", cells[i * cols + j], width)?;
+            }
+            if self.cols > cols {
+                f.write_str("  ...")?;
+            }
+            writeln!(f)?;
+        }
+        if self.rows > rows {
+            writeln!(f, "...")?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints the logical elements as `[a, b, c]`, eliding everything after the first 10 with `...`.
+impl<'a, T: fmt::Display> fmt::Display for VectorView<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for (i, value) in self.iter().take(DISPLAY_LIMIT).enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            match f.precision() {
+                Some(p) => write!(f, "{:.*}", p, value)?,
+                None => write!(f, "{}", value)?,
+            }
+        }
+        if self.len > DISPLAY_LIMIT {
+            f.write_str(", ...")?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// Offset of the first element of column `col` and the step between its elements.
+fn column_start_inc(col: usize, lda: usize, order: RowColMajor) -> (usize, usize) {
+    match order {
+        RowColMajor::RowMajor => (col, lda),
+        RowColMajor::ColMajor => (col * lda, 1),
+    }
+}
+
+/// Splits a shape into (number of stored lines, length of each line) for the given order.
+pub(crate) fn outer_inner(rows: usize, cols: usize, order: RowColMajor) -> (usize, usize) {
+    match order {
+        RowColMajor::RowMajor => (rows, cols),
+        RowColMajor::ColMajor => (cols, rows),
+    }
+}
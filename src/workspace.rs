@@ -0,0 +1,151 @@
+use crate::constants::SvdJob;
+use crate::error::BlasError;
+use crate::matrix_f64;
+use crate::validate::to_c_int;
+use std::ffi::c_int;
+
+/// A LAPACK routine, with the arguments that decide how much workspace it needs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LapackRoutine {
+    /// `dgesvd`, with its `jobu` and `jobvt` arguments.
+    Dgesvd(SvdJob, SvdJob),
+}
+
+/// Scratch buffers for the LAPACK wrappers, sized once and reused across calls.
+///
+/// LAPACK routines take their scratch space from the caller, and the optimal size is found by calling the
+/// routine with `lwork = -1`. A wrapper that does this on every call pays for a query and an allocation
+/// each time. A workspace passed to the `*_with` wrappers, such as
+/// [`cond_2norm_with`](crate::matrix_f64::cond_2norm_with), keeps its buffers between calls: once it has
+/// been reserved for the largest problem, solving further problems of that size or smaller allocates
+/// nothing for scratch space. It also remembers the last routine and dimensions it was reserved for, so
+/// repeating a problem of the same shape skips the query as well.
+///
+/// `work` is the real workspace every routine takes. `iwork` (integer workspace, as in `?gesdd`) and
+/// `rwork` (the real workspace of complex routines such as `zgesvd`) are only grown for the routines that
+/// use them.
+#[derive(Clone, Debug, Default)]
+pub struct LapackWorkspace {
+    work: Vec<f64>,
+    iwork: Vec<i32>,
+    rwork: Vec<f64>,
+    /// The arguments of the last successful [`reserve_for`](LapackWorkspace::reserve_for).
+    reserved: Option<(LapackRoutine, (usize, usize))>,
+}
+
+impl LapackWorkspace {
+    /// Creates an empty workspace. The buffers grow on first use.
+    pub fn new() -> Self {
+        LapackWorkspace::default()
+    }
+
+    /// Grows the buffers, if needed, to the optimal size `routine` reports for an `m x n` problem.
+    ///
+    /// Buffers never shrink, so reserving for several routines or sizes leaves room for all of them. Reserving
+    /// again for the same routine and dimensions as the previous call returns at once, without querying
+    /// LAPACK.
+    ///
+    /// # Parameters
+    /// - `routine`: The routine and the job arguments it will be called with.
+    /// - `dims`: The `(m, n)` dimensions of the matrix the routine is called on, as LAPACK sees it
+    ///   (column-major).
+    ///
+    /// # Errors
+    /// Returns [`BlasError::IntOverflow`] if a dimension does not fit in a C `int`, and
+    /// [`BlasError::InvalidFlag`] for a job pair the routine rejects, such as `jobu` and `jobvt` both
+    /// [`SvdJob::Overwrite`] (only one set of vectors can be written over `A`).
+    pub fn reserve_for(
+        &mut self,
+        routine: LapackRoutine,
+        dims: (usize, usize),
+    ) -> Result<(), BlasError> {
+        if self.reserved == Some((routine, dims)) {
+            return Ok(());
+        }
+        let (m, n) = dims;
+        match routine {
+            LapackRoutine::Dgesvd(jobu, jobvt) => {
+                if jobu == SvdJob::Overwrite && jobvt == SvdJob::Overwrite {
+                    return Err(invalid_job("jobvt", jobvt));
+                }
+                // The leading dimensions must be valid even for a query.
+                let ldu = match jobu {
+                    SvdJob::All | SvdJob::Thin => m,
+                    _ => 1,
+                };
+                let ldvt = match jobvt {
+                    SvdJob::All => n,
+                    SvdJob::Thin => m.min(n),
+                    _ => 1,
+                };
+                let (m_c, n_c) = (to_c_int(m, "m")?, to_c_int(n, "n")?);
+                let (lda_c, ldu_c, ldvt_c) = (
+                    to_c_int(m.max(1), "lda")?,
+                    to_c_int(ldu.max(1), "ldu")?,
+                    to_c_int(ldvt.max(1), "ldvt")?,
+                );
+                let mut query = 0.0;
+                let mut info: c_int = 0;
+                unsafe {
+                    traced!(
+                        "dgesvd_",
+                        [m, n],
+                        matrix_f64::singular_value_decomposition(
+                            &jobu.to_lapack(),
+                            &jobvt.to_lapack(),
+                            &m_c,
+                            &n_c,
+                            std::ptr::null_mut(),
+                            &lda_c,
+                            std::ptr::null_mut(),
+                            std::ptr::null_mut(),
+                            &ldu_c,
+                            std::ptr::null_mut(),
+                            &ldvt_c,
+                            &mut query,
+                            &-1,
+                            &mut info,
+                        )
+                    );
+                }
+                // Every other argument is derived from `dims`, so only a job character can be rejected.
+                match info {
+                    0 => {}
+                    -1 => return Err(invalid_job("jobu", jobu)),
+                    _ => return Err(invalid_job("jobvt", jobvt)),
+                }
+                grow(&mut self.work, (query as usize).max(1));
+            }
+        }
+        self.reserved = Some((routine, dims));
+        Ok(())
+    }
+
+    /// The real workspace, with its current length as `lwork`.
+    pub fn work_mut(&mut self) -> &mut [f64] {
+        &mut self.work
+    }
+
+    /// The integer workspace.
+    pub fn iwork_mut(&mut self) -> &mut [i32] {
+        &mut self.iwork
+    }
+
+    /// The real workspace of the complex routines.
+    pub fn rwork_mut(&mut self) -> &mut [f64] {
+        &mut self.rwork
+    }
+}
+
+fn invalid_job(which: &'static str, job: SvdJob) -> BlasError {
+    BlasError::InvalidFlag {
+        which,
+        flag: job.to_lapack() as u8 as char,
+    }
+}
+
+fn grow<T: Copy + Default>(buffer: &mut Vec<T>, len: usize) {
+    if buffer.len() < len {
+        buffer.resize(len, T::default());
+    }
+}
@@ -0,0 +1,10 @@
+//! Compile-fail tests for guarantees the safe API gets from the type system rather than at runtime.
+//!
+//! These build against the crate, so like the property tests they only run on macOS.
+#![cfg(target_os = "macos")]
+
+#[test]
+fn aliasing_is_rejected() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
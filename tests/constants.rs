@@ -0,0 +1,165 @@
+use accelerate_general::constants::{
+    gemm_shapes, is_contiguous, CblasDiag, CblasOrder, CblasSide, CblasTranspose, CblasUpLow,
+    EigenJob, EigenRange, GemmShapes, SvdJob,
+};
+use accelerate_general::error::BlasError;
+use std::convert::TryFrom;
+
+// The discriminants are passed straight through to Accelerate, so they must match the CBLAS ABI.
+
+#[test]
+fn order_matches_cblas_abi() {
+    assert_eq!(CblasOrder::RowMajor as i32, 101);
+    assert_eq!(CblasOrder::ColMajor as i32, 102);
+}
+
+#[test]
+fn transpose_matches_cblas_abi() {
+    assert_eq!(CblasTranspose::NoTrans as i32, 111);
+    assert_eq!(CblasTranspose::Trans as i32, 112);
+    assert_eq!(CblasTranspose::ConjTrans as i32, 113);
+    assert_eq!(CblasTranspose::AtlasConj as i32, 114);
+}
+
+#[test]
+fn uplo_matches_cblas_abi() {
+    assert_eq!(CblasUpLow::Upper as i32, 121);
+    assert_eq!(CblasUpLow::Lower as i32, 122);
+}
+
+#[test]
+fn diag_matches_cblas_abi() {
+    assert_eq!(CblasDiag::NonUnit as i32, 131);
+    assert_eq!(CblasDiag::Unit as i32, 132);
+}
+
+#[test]
+fn side_matches_cblas_abi() {
+    assert_eq!(CblasSide::Left as i32, 141);
+    assert_eq!(CblasSide::Right as i32, 142);
+}
+
+#[test]
+fn enums_are_abi_sized() {
+    assert_eq!(
+        std::mem::size_of::<CblasOrder>(),
+        std::mem::size_of::<i32>()
+    );
+    assert_eq!(
+        std::mem::size_of::<CblasTranspose>(),
+        std::mem::size_of::<i32>()
+    );
+    assert_eq!(
+        std::mem::size_of::<CblasUpLow>(),
+        std::mem::size_of::<i32>()
+    );
+    assert_eq!(std::mem::size_of::<CblasDiag>(), std::mem::size_of::<i32>());
+    assert_eq!(std::mem::size_of::<CblasSide>(), std::mem::size_of::<i32>());
+}
+
+#[test]
+fn leading_dimension_follows_order() {
+    assert_eq!(CblasOrder::RowMajor.leading_dimension(3, 5), 5);
+    assert_eq!(CblasOrder::ColMajor.leading_dimension(3, 5), 3);
+}
+
+#[test]
+fn is_contiguous_detects_padding() {
+    assert!(is_contiguous(3, 5, 5, CblasOrder::RowMajor));
+    assert!(!is_contiguous(3, 5, 6, CblasOrder::RowMajor));
+    assert!(is_contiguous(3, 5, 3, CblasOrder::ColMajor));
+    assert!(!is_contiguous(3, 5, 5, CblasOrder::ColMajor));
+    // A single row (row-major) or column (column-major) is contiguous whatever its leading dimension.
+    assert!(is_contiguous(1, 5, 8, CblasOrder::RowMajor));
+    assert!(is_contiguous(3, 1, 8, CblasOrder::ColMajor));
+}
+
+#[test]
+fn lapack_jobs_map_to_their_characters() {
+    assert_eq!(EigenJob::ValuesOnly.to_lapack() as u8, b'N');
+    assert_eq!(EigenJob::ValuesAndVectors.to_lapack() as u8, b'V');
+    assert_eq!(SvdJob::All.to_lapack() as u8, b'A');
+    assert_eq!(SvdJob::Thin.to_lapack() as u8, b'S');
+    assert_eq!(SvdJob::Overwrite.to_lapack() as u8, b'O');
+    assert_eq!(EigenRange::<f64>::All.to_lapack() as u8, b'A');
+    assert_eq!(EigenRange::Values(0.0, 1.0).to_lapack() as u8, b'V');
+    assert_eq!(EigenRange::<f64>::Indices(0..2).to_lapack() as u8, b'I');
+    assert_eq!(SvdJob::None.to_lapack() as u8, b'N');
+}
+
+#[test]
+fn gemm_shapes_swap_transposed_operands() {
+    let shapes = gemm_shapes(CblasTranspose::Trans, CblasTranspose::NoTrans, 2, 3, 4);
+    assert_eq!(
+        shapes,
+        GemmShapes {
+            a_rows: 4,
+            a_cols: 2,
+            b_rows: 4,
+            b_cols: 3,
+            c_rows: 2,
+            c_cols: 3
+        }
+    );
+    let shapes = gemm_shapes(CblasTranspose::NoTrans, CblasTranspose::ConjTrans, 2, 3, 4);
+    assert_eq!((shapes.a_rows, shapes.a_cols), (2, 4));
+    assert_eq!((shapes.b_rows, shapes.b_cols), (3, 4));
+}
+
+#[test]
+fn enums_debug_copy_and_compare() {
+    let order = CblasOrder::ColMajor;
+    let copy = order;
+    assert_eq!(order, copy);
+    assert_ne!(order, CblasOrder::RowMajor);
+    assert_eq!(format!("{:?}", copy), "ColMajor");
+    assert_eq!(format!("{:?}", CblasTranspose::ConjTrans), "ConjTrans");
+    assert_eq!(format!("{:?}", CblasUpLow::Lower), "Lower");
+    assert_eq!(format!("{:?}", CblasDiag::Unit), "Unit");
+    assert_eq!(format!("{:?}", CblasSide::Right), "Right");
+    assert_eq!(format!("{:?}", EigenJob::ValuesOnly), "ValuesOnly");
+    assert_eq!(format!("{:?}", SvdJob::Thin), "Thin");
+
+    let set: std::collections::HashSet<_> = [CblasSide::Left, CblasSide::Left, CblasSide::Right]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn option_characters_round_trip() {
+    for trans in [
+        CblasTranspose::NoTrans,
+        CblasTranspose::Trans,
+        CblasTranspose::ConjTrans,
+        CblasTranspose::AtlasConj,
+    ] {
+        assert_eq!(CblasTranspose::try_from(trans.as_char()), Ok(trans));
+    }
+    assert_eq!(CblasTranspose::try_from('c'), Ok(CblasTranspose::ConjTrans));
+    assert_eq!(CblasUpLow::try_from('l'), Ok(CblasUpLow::Lower));
+    assert_eq!(CblasUpLow::Upper.as_char(), 'U');
+    assert_eq!(CblasDiag::try_from('U'), Ok(CblasDiag::Unit));
+    assert_eq!(CblasDiag::NonUnit.as_char(), 'N');
+    assert_eq!(CblasSide::try_from('r'), Ok(CblasSide::Right));
+    assert_eq!(CblasSide::Left.as_char(), 'L');
+}
+
+#[test]
+fn unknown_option_characters_are_rejected() {
+    assert_eq!(
+        CblasTranspose::try_from('X'),
+        Err(BlasError::InvalidFlag {
+            which: "trans",
+            flag: 'X'
+        })
+    );
+    assert_eq!(
+        CblasDiag::try_from('L'),
+        Err(BlasError::InvalidFlag {
+            which: "diag",
+            flag: 'L'
+        })
+    );
+    assert!(CblasSide::try_from('u').is_err());
+}
@@ -0,0 +1,137 @@
+use accelerate_general::constants::CblasOrder;
+use accelerate_general::dense::DenseMatrix;
+use accelerate_general::error::BlasError;
+use num_complex::Complex;
+
+#[test]
+fn gemm_into_accumulates_without_reallocating() {
+    // A = [[1, 2], [3, 4]] row-major, B = [[1, 0], [1, 1]] column-major.
+    let a =
+        DenseMatrix::from_vec_with_lda(vec![1.0f64, 2.0, 3.0, 4.0], 2, 2, 2, CblasOrder::RowMajor)
+            .unwrap();
+    let b = DenseMatrix::from_vec_with_lda(vec![1.0, 1.0, 0.0, 1.0], 2, 2, 2, CblasOrder::ColMajor)
+        .unwrap();
+    let mut c = DenseMatrix::zeros(2, 2, CblasOrder::RowMajor);
+    let ptr = c.as_slice().as_ptr();
+    for _ in 0..3 {
+        a.gemm_into(&b, &mut c, 1.0, 1.0).unwrap();
+    }
+    // A * B = [[3, 2], [7, 4]], accumulated three times.
+    assert_eq!(c.as_slice(), &[9.0, 6.0, 21.0, 12.0]);
+    assert_eq!(c.as_slice().as_ptr(), ptr);
+}
+
+#[test]
+fn gemm_allocates_in_left_operand_order() {
+    let i = Complex::new(0.0f32, 1.0);
+    let a = DenseMatrix::from_vec_with_lda(vec![i, i], 2, 1, 2, CblasOrder::ColMajor).unwrap();
+    let b = DenseMatrix::from_vec_with_lda(
+        vec![i, Complex::new(1.0, 0.0)],
+        1,
+        2,
+        2,
+        CblasOrder::RowMajor,
+    )
+    .unwrap();
+    let c = a.gemm(&b).unwrap();
+    assert!(matches!(c.order(), CblasOrder::ColMajor));
+    // [[i], [i]] * [[i, 1]] = [[-1, i], [-1, i]], stored column by column.
+    let minus_one = Complex::new(-1.0, 0.0);
+    assert_eq!(c.as_slice(), &[minus_one, minus_one, i, i]);
+}
+
+#[test]
+fn gemm_into_rejects_mismatched_shapes() {
+    let a = DenseMatrix::from_vec_with_lda(vec![1.0f32; 6], 2, 3, 3, CblasOrder::RowMajor).unwrap();
+    let mut c = DenseMatrix::zeros(2, 2, CblasOrder::RowMajor);
+    assert_eq!(
+        a.gemm_into(
+            &DenseMatrix::zeros(2, 2, CblasOrder::RowMajor),
+            &mut c,
+            1.0,
+            0.0
+        ),
+        Err(BlasError::DimensionMismatch {
+            which: "b",
+            expected: 3,
+            got: 2
+        })
+    );
+    assert_eq!(
+        a.gemm_into(
+            &DenseMatrix::zeros(3, 3, CblasOrder::RowMajor),
+            &mut c,
+            1.0,
+            0.0
+        ),
+        Err(BlasError::DimensionMismatch {
+            which: "c",
+            expected: 3,
+            got: 2
+        })
+    );
+}
+
+#[test]
+fn new_requires_exact_tight_length() {
+    let m = DenseMatrix::new(vec![1.0f32; 6], 2, 3, CblasOrder::ColMajor).unwrap();
+    assert_eq!((m.rows(), m.cols(), m.lda()), (2, 3, 2));
+    assert_eq!(
+        DenseMatrix::new(vec![0.0f64; 7], 2, 3, CblasOrder::RowMajor).err(),
+        Some(BlasError::DimensionMismatch {
+            which: "data",
+            expected: 6,
+            got: 7
+        })
+    );
+    assert!(DenseMatrix::new(
+        vec![Complex::new(0.0f64, 0.0); 5],
+        2,
+        3,
+        CblasOrder::RowMajor
+    )
+    .is_err());
+    assert!(DenseMatrix::new(Vec::<Complex<f32>>::new(), 0, 4, CblasOrder::RowMajor).is_ok());
+}
+
+#[test]
+fn col_major_gemm_with_identity_and_rectangular_factors() {
+    // A = [[1, 2, 3], [4, 5, 6]], stored column by column.
+    let a = DenseMatrix::new(
+        vec![1.0f32, 4.0, 2.0, 5.0, 3.0, 6.0],
+        2,
+        3,
+        CblasOrder::ColMajor,
+    )
+    .unwrap();
+    assert_eq!(a.get(1, 0), Some(&4.0));
+    assert_eq!(a.get(0, 2), Some(&3.0));
+    assert_eq!(a.get(2, 0), None);
+
+    let mut identity = DenseMatrix::zeros(3, 3, CblasOrder::ColMajor);
+    for i in 0..3 {
+        *identity.get_mut(i, i).unwrap() = 1.0;
+    }
+    assert_eq!(a.gemm(&identity).unwrap().as_slice(), a.as_slice());
+
+    // B = [[1, 0], [0, 1], [1, 1]], so A * B = [[4, 5], [10, 11]].
+    let b = DenseMatrix::new(
+        vec![1.0f32, 0.0, 1.0, 0.0, 1.0, 1.0],
+        3,
+        2,
+        CblasOrder::ColMajor,
+    )
+    .unwrap();
+    let c = a.gemm(&b).unwrap();
+    assert_eq!((c.rows(), c.cols()), (2, 2));
+    assert_eq!(c.as_slice(), &[4.0, 10.0, 5.0, 11.0]);
+
+    assert_eq!(
+        b.gemm(&b).err(),
+        Some(BlasError::DimensionMismatch {
+            which: "b",
+            expected: 2,
+            got: 3
+        })
+    );
+}
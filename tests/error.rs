@@ -0,0 +1,108 @@
+use accelerate_general::error::{BlasError, Result};
+
+#[test]
+fn int_overflow_names_parameter_and_value() {
+    let err = BlasError::IntOverflow {
+        which: "n",
+        value: 1 << 31,
+    };
+    assert_eq!(
+        err.to_string(),
+        "`n` = 2147483648 does not fit in a C int (max 2147483647)"
+    );
+}
+
+#[test]
+fn not_positive_definite_names_failing_minor() {
+    assert_eq!(
+        BlasError::NotPositiveDefinite { minor: 3 }.to_string(),
+        "matrix is not positive definite: the leading minor of order 3 is not positive"
+    );
+}
+
+#[test]
+fn no_convergence_display() {
+    assert_eq!(
+        BlasError::NoConvergence { unconverged: 2 }.to_string(),
+        "iteration did not converge: 2 values remain unconverged"
+    );
+}
+
+#[test]
+fn shape_errors_name_the_operand() {
+    let cases = [
+        (
+            BlasError::BufferTooSmall {
+                which: "a",
+                needed: 6,
+                got: 5,
+            },
+            "buffer `a` is too small: needed 6 elements, got 5",
+        ),
+        (
+            BlasError::DimensionMismatch {
+                which: "y",
+                expected: 3,
+                got: 2,
+            },
+            "dimension mismatch for `y`: expected 3, got 2",
+        ),
+        (
+            BlasError::InvalidLeadingDimension {
+                which: "lda",
+                min: 4,
+                got: 3,
+            },
+            "leading dimension `lda` is invalid: must be at least 4, got 3",
+        ),
+        (
+            BlasError::IndexOutOfBounds {
+                which: "indices",
+                index: 7,
+                len: 5,
+            },
+            "index 7 into `indices` is out of bounds for length 5",
+        ),
+        (
+            BlasError::RepeatedIndex {
+                which: "rotations",
+                index: 2,
+            },
+            "index 2 appears twice in `rotations`, which needs distinct indices",
+        ),
+        (
+            BlasError::Misaligned {
+                which: "c",
+                align: 8,
+            },
+            "buffer `c` is not aligned to 8 bytes for its element type",
+        ),
+        (
+            BlasError::ZeroIncrement { which: "inc_x" },
+            "increment `inc_x` is zero: BLAS needs a non-zero stride between vector elements",
+        ),
+        (
+            BlasError::InvalidBounds { which: "max" },
+            "bound `max` is invalid: bounds must not be NaN and `min` must not exceed `max`",
+        ),
+        (
+            BlasError::InvalidFlag {
+                which: "uplo",
+                flag: 'x',
+            },
+            "'x' is not a valid `uplo` character",
+        ),
+    ];
+    for (err, message) in cases {
+        assert_eq!(err.to_string(), message);
+    }
+}
+
+#[test]
+fn result_alias_converts_into_boxed_error() {
+    fn fails() -> Result<()> {
+        Err(BlasError::ZeroIncrement { which: "inc_y" })
+    }
+    let boxed: Box<dyn std::error::Error> = fails().unwrap_err().into();
+    assert!(boxed.to_string().starts_with("increment `inc_y`"));
+}
@@ -0,0 +1,99 @@
+use accelerate_general::error::BlasError;
+use accelerate_general::givens;
+use num_complex::Complex;
+
+#[test]
+fn csrot_rotates_complex_pairs_with_real_coefficients() {
+    let mut x = [Complex::new(1.0, 2.0)];
+    let mut y = [Complex::new(3.0, -1.0)];
+    // A quarter turn: c = 0, s = 1 maps (x, y) to (y, -x).
+    givens::csrot(1, &mut x, 1, &mut y, 1, 0.0, 1.0).unwrap();
+    assert_eq!(x, [Complex::new(3.0, -1.0)]);
+    assert_eq!(y, [Complex::new(-1.0, -2.0)]);
+}
+
+#[test]
+fn csrot_rejects_short_buffer() {
+    let mut x = [Complex::new(0.0, 0.0); 2];
+    let mut y = [Complex::new(0.0, 0.0); 1];
+    assert_eq!(
+        givens::csrot(2, &mut x, 1, &mut y, 1, 1.0, 0.0),
+        Err(BlasError::BufferTooSmall {
+            which: "y",
+            needed: 2,
+            got: 1
+        })
+    );
+}
+
+#[test]
+fn srotg_stable_zeroes_second_component() {
+    let (r, z, c, s) = givens::srotg_stable(3.0, 4.0);
+    assert!((r - 5.0).abs() < 1e-6);
+    assert!((c - 0.6).abs() < 1e-6 && (s - 0.8).abs() < 1e-6);
+    assert!((z - 1.0 / 0.6).abs() < 1e-5);
+    assert!((-s * 3.0 + c * 4.0).abs() < 1e-6);
+}
+
+#[test]
+fn srotg_stable_follows_lapack_signs_and_edge_cases() {
+    let (r, _, c, s) = givens::srotg_stable(-3.0, 4.0);
+    assert!((r + 5.0).abs() < 1e-6 && c > 0.0 && s < 0.0);
+    assert_eq!(givens::srotg_stable(2.0, 0.0), (2.0, 0.0, 1.0, 0.0));
+    assert_eq!(givens::srotg_stable(0.0, -2.0), (2.0, 1.0, 0.0, -1.0));
+    let (r, _, _, _) = givens::srotg_stable(3e30, 4e30);
+    assert!((r / 5e30 - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn csrot_rejects_zero_increment() {
+    let mut x = [Complex::new(1.0f32, 0.0); 2];
+    let mut y = [Complex::new(0.0f32, 1.0); 2];
+    assert_eq!(
+        givens::csrot(2, &mut x, 0, &mut y, 1, 1.0, 0.0),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+    assert_eq!(
+        givens::csrot(2, &mut x, 1, &mut y, 0, 1.0, 0.0),
+        Err(BlasError::ZeroIncrement { which: "inc_y" })
+    );
+}
+
+#[test]
+fn drotg_zeroes_b_and_returns_hypotenuse() {
+    let (mut a, mut b, mut c, mut s) = (3.0f64, 4.0f64, 0.0f64, 0.0f64);
+    unsafe { givens::givens_gen_f64(&mut a, &mut b, &mut c, &mut s) };
+    assert!((a - 5.0).abs() < 1e-12);
+    assert!((c - 0.6).abs() < 1e-12 && (s - 0.8).abs() < 1e-12);
+    // Applying the rotation to the original pair zeroes the second component.
+    assert!((-s * 3.0 + c * 4.0).abs() < 1e-12);
+}
+
+#[test]
+fn real_rotg_by_value_is_a_rotation_that_zeroes_b() {
+    let (a, b) = (1.0f32, -2.0f32);
+    let (r, _, c, s) = givens::srotg(a, b);
+    assert!((c * c + s * s - 1.0).abs() < 1e-6);
+    assert!((c * a + s * b - r).abs() < 1e-6);
+    assert!((-s * a + c * b).abs() < 1e-6);
+
+    let (a, b) = (-5.0f64, 12.0f64);
+    let (r, _, c, s) = givens::drotg(a, b);
+    assert!((c * c + s * s - 1.0).abs() < 1e-12);
+    assert!((r.abs() - 13.0).abs() < 1e-12);
+    assert!((-s * a + c * b).abs() < 1e-12);
+}
+
+#[test]
+fn complex_rotg_by_value_zeroes_b() {
+    let (a, b) = (Complex::new(1.0f32, 1.0), Complex::new(0.0f32, 2.0));
+    let (r, _, c, s) = givens::crotg(a, b);
+    assert!((c * a + s * b - r).norm() < 1e-5);
+    assert!((-s.conj() * a + c * b).norm() < 1e-5);
+
+    let (a, b) = (Complex::new(3.0f64, 0.0), Complex::new(0.0f64, 4.0));
+    let (r, _, c, s) = givens::zrotg(a, b);
+    assert!((r.norm() - 5.0).abs() < 1e-12);
+    assert!((c * c + s.norm_sqr() - 1.0).abs() < 1e-12);
+    assert!((-s.conj() * a + c * b).norm() < 1e-12);
+}
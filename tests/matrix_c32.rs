@@ -0,0 +1,194 @@
+use accelerate_general::constants::{CblasDiag, CblasOrder, CblasTranspose, CblasUpLow};
+use accelerate_general::error::BlasError;
+use accelerate_general::matrix_c32;
+use num_complex::Complex;
+
+fn assert_close(actual: &[Complex<f32>], expected: &[Complex<f32>]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).norm() < 1e-5, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn ctbsv_solves_lower_bidiagonal() {
+    // A = [[1 + i, 0], [2, 3]] in row-major band storage (k = 1, lda = 2).
+    let a = [
+        Complex::new(0.0, 0.0),
+        Complex::new(1.0, 1.0),
+        Complex::new(2.0, 0.0),
+        Complex::new(3.0, 0.0),
+    ];
+    let mut x = [Complex::new(1.0, 1.0), Complex::new(2.0, 3.0)];
+    matrix_c32::ctbsv(
+        CblasOrder::RowMajor,
+        CblasUpLow::Lower,
+        CblasTranspose::NoTrans,
+        CblasDiag::NonUnit,
+        2,
+        1,
+        &a,
+        2,
+        &mut x,
+        1,
+    )
+    .unwrap();
+    assert_close(&x, &[Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+}
+
+#[test]
+fn kron_does_not_conjugate() {
+    let i = Complex::new(0.0, 1.0);
+    let one = Complex::new(1.0, 0.0);
+    let k = matrix_c32::kron(CblasOrder::RowMajor, &[i], (1, 1), &[one, i], (1, 2)).unwrap();
+    assert_eq!(k, [i, Complex::new(-1.0, 0.0)]);
+}
+
+#[test]
+fn hermitianize_conjugates_mirrored_entries() {
+    let z = Complex::new(9.0, 9.0);
+    let mut a = [
+        Complex::new(1.0, 0.5),
+        Complex::new(2.0, 3.0),
+        z,
+        Complex::new(4.0, 0.0),
+    ];
+    matrix_c32::hermitianize(CblasOrder::RowMajor, CblasUpLow::Upper, 2, &mut a, 2).unwrap();
+    assert_eq!(
+        a,
+        [
+            Complex::new(1.0, 0.0),
+            Complex::new(2.0, 3.0),
+            Complex::new(2.0, -3.0),
+            Complex::new(4.0, 0.0)
+        ]
+    );
+
+    let mut a = [Complex::new(1.0, 0.0), Complex::new(2.0, 3.0), z, z];
+    matrix_c32::symmetrize(CblasOrder::ColMajor, CblasUpLow::Lower, 2, &mut a, 2).unwrap();
+    assert_eq!(a[2], Complex::new(2.0, 3.0));
+}
+
+#[test]
+fn csymv_mirrors_without_conjugation() {
+    // A = [[1, i], [i, 2]] is complex symmetric; only the upper triangle is stored (the lower entry is junk).
+    let i = Complex::new(0.0, 1.0);
+    let one = Complex::new(1.0, 0.0);
+    let junk = Complex::new(f32::NAN, f32::NAN);
+    let a = [one, i, junk, Complex::new(2.0, 0.0)];
+    let x = [one, one];
+    let mut y = [junk; 2];
+    matrix_c32::csymv(
+        CblasOrder::RowMajor,
+        CblasUpLow::Upper,
+        2,
+        one,
+        &a,
+        2,
+        &x,
+        Complex::new(0.0, 0.0),
+        &mut y,
+    )
+    .unwrap();
+    assert_eq!(y, [Complex::new(1.0, 1.0), Complex::new(2.0, 1.0)]);
+
+    let mut y = [one, one];
+    let a = [one, junk, i, Complex::new(2.0, 0.0)];
+    matrix_c32::csymv(
+        CblasOrder::RowMajor,
+        CblasUpLow::Lower,
+        2,
+        i,
+        &a,
+        2,
+        &x,
+        one,
+        &mut y,
+    )
+    .unwrap();
+    // i * [1 + i, 2 + i] + [1, 1]
+    assert_eq!(y, [Complex::new(0.0, 1.0), Complex::new(0.0, 2.0)]);
+}
+
+#[test]
+fn gemv_banded_auto_packs_complex_diagonal() {
+    let i = Complex::new(0.0f32, 1.0);
+    let zero = Complex::new(0.0, 0.0);
+    let mut a = vec![zero; 16];
+    for k in 0..4 {
+        a[k * 4 + k] = i * (k as f32 + 1.0);
+    }
+    let x = [Complex::new(1.0, 0.0); 4];
+    let y = matrix_c32::gemv_banded_auto(&a, 4, 4, 4, CblasOrder::ColMajor, &x).unwrap();
+    assert_close(&y, &[i, i * 2.0, i * 3.0, i * 4.0]);
+}
+
+#[test]
+fn is_hermitian_checks_conjugate_pairs_and_real_diagonal() {
+    let c = Complex::new;
+    // [[2, 1 - i], [1 + i, 3]] column-major.
+    let h = [c(2.0, 0.0), c(1.0, 1.0), c(1.0, -1.0), c(3.0, 0.0)];
+    assert_eq!(
+        matrix_c32::is_hermitian(&h, 2, 2, CblasOrder::ColMajor, 0.0),
+        Ok(true)
+    );
+    // Hermitian but not symmetric.
+    assert_eq!(
+        matrix_c32::is_symmetric(&h, 2, 2, CblasOrder::ColMajor, 0.0),
+        Ok(false)
+    );
+    // A complex diagonal entry is never Hermitian.
+    let mut bad = h;
+    bad[3] = c(3.0, 0.5);
+    assert_eq!(
+        matrix_c32::is_hermitian(&bad, 2, 2, CblasOrder::ColMajor, 0.1),
+        Ok(false)
+    );
+}
+
+#[test]
+fn ctbsv_rejects_zero_increment() {
+    let a = [Complex::new(1.0f32, 0.0); 2];
+    let mut x = [Complex::new(1.0f32, 0.0); 2];
+    assert_eq!(
+        matrix_c32::ctbsv(
+            CblasOrder::ColMajor,
+            CblasUpLow::Upper,
+            CblasTranspose::NoTrans,
+            CblasDiag::NonUnit,
+            2,
+            0,
+            &a,
+            1,
+            &mut x,
+            0
+        ),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+}
+
+#[test]
+fn matrix_rank_counts_complex_singular_values() {
+    let c = |re: f32, im: f32| Complex::new(re, im);
+    // The second row is i times the first, so the 3 x 3 matrix and its first two rows are rank deficient.
+    let a = [
+        c(1.0, 0.0),
+        c(0.0, 1.0),
+        c(2.0, 0.0),
+        c(0.0, 1.0),
+        c(-1.0, 0.0),
+        c(0.0, 2.0),
+        c(0.0, 0.0),
+        c(1.0, 0.0),
+        c(1.0, 1.0),
+    ];
+    assert_eq!(matrix_c32::matrix_rank(&a, 3, 3, None), Ok(2));
+    assert_eq!(matrix_c32::matrix_rank(&a[..6], 2, 3, None), Ok(1));
+    assert_eq!(matrix_c32::matrix_rank(&a[3..], 3, 2, None), Ok(2));
+
+    // diag(1, 1e-3 i) has full rank by default but rank 1 with a coarse tolerance.
+    let d = [c(1.0, 0.0), c(0.0, 0.0), c(0.0, 0.0), c(0.0, 1e-3)];
+    assert_eq!(matrix_c32::matrix_rank(&d, 2, 2, None), Ok(2));
+    assert_eq!(matrix_c32::matrix_rank(&d, 2, 2, Some(1e-2)), Ok(1));
+    assert_eq!(matrix_c32::matrix_rank(&[], 3, 0, None), Ok(0));
+}
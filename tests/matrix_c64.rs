@@ -0,0 +1,222 @@
+use accelerate_general::constants::{CblasDiag, CblasOrder, CblasSide, CblasTranspose, CblasUpLow};
+use accelerate_general::error::BlasError;
+use accelerate_general::matrix_c64;
+use num_complex::Complex;
+
+fn assert_close(actual: &[Complex<f64>], expected: &[Complex<f64>]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).norm() < 1e-12, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn solve_tridiagonal_complex() {
+    let lower = [Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+    let diag = [
+        Complex::new(4.0, 0.0),
+        Complex::new(4.0, 1.0),
+        Complex::new(4.0, 0.0),
+    ];
+    let upper = [Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)];
+    let mut b = [
+        Complex::new(4.0, 1.0),
+        Complex::new(1.0, 4.0),
+        Complex::new(3.0, 0.0),
+    ];
+    matrix_c64::solve_tridiagonal(&lower, &diag, &upper, &mut b).unwrap();
+    assert_close(
+        &b,
+        &[
+            Complex::new(1.0, 0.0),
+            Complex::new(0.0, 1.0),
+            Complex::new(1.0, 0.0),
+        ],
+    );
+}
+
+#[test]
+fn from_diagonal_places_complex_entries() {
+    let d = [Complex::new(1.0, -1.0), Complex::new(0.0, 2.0)];
+    let a = matrix_c64::from_diagonal(&d);
+    let zero = Complex::new(0.0, 0.0);
+    assert_eq!(a, [d[0], zero, zero, d[1]]);
+    assert_eq!(matrix_c64::diagonal(2, &a, 2), Ok(d.to_vec()));
+}
+
+#[test]
+fn swap_rows_moves_complex_rows() {
+    let i = Complex::new(0.0, 1.0);
+    let one = Complex::new(1.0, 0.0);
+    let mut a = [one, i, i, one];
+    matrix_c64::swap_rows(&mut a, 2, 2, 2, CblasOrder::ColMajor, 0, 1).unwrap();
+    assert_eq!(a, [i, one, one, i]);
+}
+
+#[test]
+fn real_and_complex_conversions_round_trip() {
+    let z = matrix_c64::real_to_complex(&[1.0, -2.5]);
+    assert_eq!(z, [Complex::new(1.0, 0.0), Complex::new(-2.5, 0.0)]);
+    let w = [Complex::new(1.0, 4.0), Complex::new(-3.0, 0.5)];
+    assert_eq!(matrix_c64::complex_real_part(&w), [1.0, -3.0]);
+    assert_eq!(matrix_c64::complex_imag_part(&w), [4.0, 0.5]);
+}
+
+#[test]
+fn ztbsv_rejects_zero_increment() {
+    let a = [Complex::new(1.0f64, 0.0); 2];
+    let mut x = [Complex::new(1.0f64, 0.0); 2];
+    assert_eq!(
+        matrix_c64::ztbsv(
+            CblasOrder::ColMajor,
+            CblasUpLow::Upper,
+            CblasTranspose::NoTrans,
+            CblasDiag::NonUnit,
+            2,
+            0,
+            &a,
+            1,
+            &mut x,
+            0
+        ),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+}
+
+#[test]
+fn zgemm_multiplies_plain_and_conjugate_transposed() {
+    let c = |re, im| Complex::new(re, im);
+    // A = [[1 + i, 2], [0, i]], B = [[1, i], [1, 0]], row-major.
+    let a = [c(1.0, 1.0), c(2.0, 0.0), c(0.0, 0.0), c(0.0, 1.0)];
+    let b = [c(1.0, 0.0), c(0.0, 1.0), c(1.0, 0.0), c(0.0, 0.0)];
+    let (alpha, beta) = (c(1.0, 0.0), c(0.0, 0.0));
+    let product = |trans_a| {
+        let mut out = [c(0.0, 0.0); 4];
+        unsafe {
+            matrix_c64::mat_mul_add(
+                CblasOrder::RowMajor,
+                trans_a,
+                CblasTranspose::NoTrans,
+                2,
+                2,
+                2,
+                &alpha,
+                a.as_ptr(),
+                2,
+                b.as_ptr(),
+                2,
+                &beta,
+                out.as_mut_ptr(),
+                2,
+            );
+        }
+        out
+    };
+    assert_eq!(
+        product(CblasTranspose::NoTrans),
+        [c(3.0, 1.0), c(-1.0, 1.0), c(0.0, 1.0), c(0.0, 0.0)]
+    );
+    // A^H = [[1 - i, 0], [2, -i]]
+    assert_eq!(
+        product(CblasTranspose::ConjTrans),
+        [c(1.0, -1.0), c(1.0, 1.0), c(2.0, -1.0), c(0.0, 2.0)]
+    );
+}
+
+#[test]
+fn zher_keeps_the_diagonal_real() {
+    let c = |re, im| Complex::new(re, im);
+    let x = [c(1.0, 1.0), c(2.0, -1.0)];
+    // Identity, with a sentinel in the strictly lower triangle that an upper update must not touch.
+    let mut a = [c(1.0, 0.0), c(0.0, 0.0), c(-9.0, 0.0), c(1.0, 0.0)];
+    unsafe {
+        matrix_c64::herm_rank1_update(
+            CblasOrder::RowMajor,
+            CblasUpLow::Upper,
+            2,
+            1.0,
+            x.as_ptr(),
+            1,
+            a.as_mut_ptr(),
+            2,
+        );
+    }
+    // x x^H = [[2, (1 + i)(2 + i)], [., 5]] = [[2, 1 + 3i], [., 5]]
+    assert_close(&a, &[c(3.0, 0.0), c(1.0, 3.0), c(-9.0, 0.0), c(6.0, 0.0)]);
+    assert_eq!((a[0].im, a[3].im), (0.0, 0.0));
+}
+
+#[test]
+fn ztrsm_solves_upper_triangular_system() {
+    let c = |re, im| Complex::new(re, im);
+    // Row-major upper-triangular A, with junk below the diagonal, and a known 3 x 2 solution X.
+    let a = [
+        c(2.0, 1.0),
+        c(1.0, -1.0),
+        c(0.0, 3.0),
+        c(9.0, 9.0),
+        c(1.0, 0.0),
+        c(2.0, 2.0),
+        c(9.0, 9.0),
+        c(9.0, 9.0),
+        c(0.0, -1.0),
+    ];
+    let x = [
+        c(1.0, 0.0),
+        c(0.0, 1.0),
+        c(2.0, -1.0),
+        c(1.0, 1.0),
+        c(-1.0, 0.5),
+        c(3.0, 0.0),
+    ];
+    let mut b = [c(0.0, 0.0); 6];
+    for i in 0..3 {
+        for j in 0..2 {
+            b[i * 2 + j] = (i..3).map(|k| a[i * 3 + k] * x[k * 2 + j]).sum();
+        }
+    }
+    let one = c(1.0, 0.0);
+    unsafe {
+        matrix_c64::tri_solve_multiple(
+            CblasOrder::RowMajor,
+            CblasSide::Left,
+            CblasUpLow::Upper,
+            CblasTranspose::NoTrans,
+            CblasDiag::NonUnit,
+            3,
+            2,
+            &one,
+            a.as_ptr(),
+            3,
+            b.as_mut_ptr(),
+            2,
+        );
+    }
+    assert_close(&b, &x);
+}
+
+#[test]
+fn matrix_rank_counts_complex_singular_values() {
+    let c = |re: f64, im: f64| Complex::new(re, im);
+    // The second row is i times the first, so the 3 x 3 matrix and its first two rows are rank deficient.
+    let a = [
+        c(1.0, 0.0),
+        c(0.0, 1.0),
+        c(2.0, 0.0),
+        c(0.0, 1.0),
+        c(-1.0, 0.0),
+        c(0.0, 2.0),
+        c(0.0, 0.0),
+        c(1.0, 0.0),
+        c(1.0, 1.0),
+    ];
+    assert_eq!(matrix_c64::matrix_rank(&a, 3, 3, None), Ok(2));
+    assert_eq!(matrix_c64::matrix_rank(&a[..6], 2, 3, None), Ok(1));
+    assert_eq!(matrix_c64::matrix_rank(&a[3..], 3, 2, None), Ok(2));
+
+    // diag(1, 1e-3 i) has full rank by default but rank 1 with a coarse tolerance.
+    let d = [c(1.0, 0.0), c(0.0, 0.0), c(0.0, 0.0), c(0.0, 1e-3)];
+    assert_eq!(matrix_c64::matrix_rank(&d, 2, 2, None), Ok(2));
+    assert_eq!(matrix_c64::matrix_rank(&d, 2, 2, Some(1e-2)), Ok(1));
+    assert_eq!(matrix_c64::matrix_rank(&[], 3, 0, None), Ok(0));
+}
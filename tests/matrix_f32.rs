@@ -0,0 +1,983 @@
+use accelerate_general::constants::{
+    CblasDiag, CblasOrder, CblasTranspose, CblasUpLow, EigenJob, EigenRange,
+};
+use accelerate_general::error::BlasError;
+use accelerate_general::matrix_f32;
+
+fn assert_close(actual: &[f32], expected: &[f32]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn stbsv_solves_upper_bidiagonal() {
+    // A = [[2, 1, 0], [0, 3, 1], [0, 0, 4]] in column-major band storage (k = 1, lda = 2).
+    let a = [0.0, 2.0, 1.0, 3.0, 1.0, 4.0];
+    let mut x = [4.0, 9.0, 12.0];
+    matrix_f32::stbsv(
+        CblasOrder::ColMajor,
+        CblasUpLow::Upper,
+        CblasTranspose::NoTrans,
+        CblasDiag::NonUnit,
+        3,
+        1,
+        &a,
+        2,
+        &mut x,
+        1,
+    )
+    .unwrap();
+    assert_close(&x, &[1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn stbsv_rejects_bad_band_layout() {
+    let a = [0.0, 2.0, 1.0, 3.0, 1.0, 4.0];
+    let mut x = [4.0, 9.0, 12.0];
+    let err = matrix_f32::stbsv(
+        CblasOrder::ColMajor,
+        CblasUpLow::Upper,
+        CblasTranspose::NoTrans,
+        CblasDiag::NonUnit,
+        3,
+        1,
+        &a,
+        1,
+        &mut x,
+        1,
+    );
+    assert_eq!(
+        err,
+        Err(BlasError::InvalidLeadingDimension {
+            which: "lda",
+            min: 2,
+            got: 1
+        })
+    );
+
+    let err = matrix_f32::stbsv(
+        CblasOrder::ColMajor,
+        CblasUpLow::Upper,
+        CblasTranspose::NoTrans,
+        CblasDiag::NonUnit,
+        3,
+        1,
+        &a,
+        2,
+        &mut x,
+        2,
+    );
+    assert_eq!(
+        err,
+        Err(BlasError::BufferTooSmall {
+            which: "x",
+            needed: 5,
+            got: 3
+        })
+    );
+}
+
+#[test]
+fn solve_tridiagonal_matches_known_solution() {
+    // The 1-D Laplacian [-1, 2, -1] with x = [1, 2, 3, 4].
+    let lower = [-1.0; 3];
+    let diag = [2.0; 4];
+    let upper = [-1.0; 3];
+    let mut b = [0.0, 0.0, 0.0, 5.0];
+    matrix_f32::solve_tridiagonal(&lower, &diag, &upper, &mut b).unwrap();
+    assert_close(&b, &[1.0, 2.0, 3.0, 4.0]);
+}
+
+#[test]
+fn solve_tridiagonal_rejects_mismatched_lengths() {
+    let mut b = [0.0; 4];
+    let err = matrix_f32::solve_tridiagonal(&[-1.0; 2], &[2.0; 4], &[-1.0; 3], &mut b);
+    assert_eq!(
+        err,
+        Err(BlasError::DimensionMismatch {
+            which: "lower",
+            expected: 3,
+            got: 2
+        })
+    );
+}
+
+#[test]
+fn quadratic_form_general_and_symmetric() {
+    // A = [[2, 1], [1, 3]], x = [1, 2]: x^T A x = 2 + 2 + 2 + 12 = 18.
+    let a = [2.0, 1.0, 1.0, 3.0];
+    let x = [1.0, 2.0];
+    let q = matrix_f32::quadratic_form(CblasOrder::RowMajor, 2, &a, 2, &x).unwrap();
+    assert!((q - 18.0).abs() < 1e-5);
+
+    // Only the upper triangle is read, so the lower one may hold garbage.
+    let upper = [2.0, 1.0, f32::NAN, 3.0];
+    let q =
+        matrix_f32::quadratic_form_sym(CblasOrder::RowMajor, CblasUpLow::Upper, 2, &upper, 2, &x)
+            .unwrap();
+    assert!((q - 18.0).abs() < 1e-5);
+}
+
+#[test]
+fn covariance_biased_and_unbiased() {
+    // Three samples of two features; feature 1 = 2 * feature 0.
+    let data = [1.0, 2.0, 2.0, 4.0, 3.0, 6.0];
+    let biased = matrix_f32::covariance(CblasOrder::RowMajor, 3, 2, &data).unwrap();
+    assert_close(&biased, &[2.0 / 3.0, 4.0 / 3.0, 4.0 / 3.0, 8.0 / 3.0]);
+
+    let col_major = [1.0, 2.0, 3.0, 2.0, 4.0, 6.0];
+    let unbiased = matrix_f32::covariance_unbiased(CblasOrder::ColMajor, 3, 2, &col_major).unwrap();
+    assert_close(&unbiased, &[1.0, 2.0, 2.0, 4.0]);
+}
+
+#[test]
+fn from_diagonal_and_diagonal_round_trip() {
+    let a = matrix_f32::from_diagonal(&[1.0, 2.0, 3.0]);
+    assert_eq!(a, [1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0]);
+    assert_eq!(matrix_f32::diagonal(3, &a, 3), Ok(vec![1.0, 2.0, 3.0]));
+}
+
+#[test]
+fn diagonal_honours_leading_dimension() {
+    // 2 x 2 matrix [[1, 2], [3, 4]] stored with lda = 3.
+    let a = [1.0, 2.0, 9.0, 3.0, 4.0];
+    assert_eq!(matrix_f32::diagonal(2, &a, 3), Ok(vec![1.0, 4.0]));
+    assert_eq!(
+        matrix_f32::diagonal(2, &a[..4], 3),
+        Err(BlasError::BufferTooSmall {
+            which: "a",
+            needed: 5,
+            got: 4
+        })
+    );
+}
+
+#[test]
+fn kron_builds_block_matrix_in_both_orders() {
+    // A = [[1, 2]], B = [[0, 1], [1, 0]] -> [[0, 1, 0, 2], [1, 0, 2, 0]]
+    let a = [1.0, 2.0];
+    let b = [0.0, 1.0, 1.0, 0.0];
+    let row = matrix_f32::kron(CblasOrder::RowMajor, &a, (1, 2), &b, (2, 2)).unwrap();
+    assert_eq!(row, [0.0, 1.0, 0.0, 2.0, 1.0, 0.0, 2.0, 0.0]);
+    let col = matrix_f32::kron(CblasOrder::ColMajor, &a, (1, 2), &b, (2, 2)).unwrap();
+    assert_eq!(col, [0.0, 1.0, 1.0, 0.0, 0.0, 2.0, 2.0, 0.0]);
+}
+
+#[test]
+fn kron_rejects_mismatched_shape() {
+    assert_eq!(
+        matrix_f32::kron(CblasOrder::RowMajor, &[1.0; 3], (2, 2), &[1.0], (1, 1)),
+        Err(BlasError::DimensionMismatch {
+            which: "a",
+            expected: 4,
+            got: 3
+        })
+    );
+}
+
+#[test]
+fn normal_equations_picks_smaller_gram_matrix() {
+    // A = [[1, 2], [3, 4], [5, 6]] (3 x 2): A^T A = [[35, 44], [44, 56]].
+    let tall = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let (gram, transposed) =
+        matrix_f32::normal_equations(CblasOrder::RowMajor, 3, 2, &tall).unwrap();
+    assert!(!transposed);
+    assert_close(&gram, &[35.0, 44.0, 44.0, 56.0]);
+
+    // The same data read as a column-major 2 x 3 matrix is A^T, whose A A^T is the same 2 x 2 matrix.
+    let (gram, transposed) =
+        matrix_f32::normal_equations(CblasOrder::ColMajor, 2, 3, &tall).unwrap();
+    assert!(transposed);
+    assert_close(&gram, &[35.0, 44.0, 44.0, 56.0]);
+}
+
+#[test]
+fn symmetrize_copies_either_triangle() {
+    // Upper triangle of [[1, 2], [., 3]] in row-major order with lda = 3.
+    let mut a = [1.0, 2.0, 0.0, 9.0, 3.0];
+    matrix_f32::symmetrize(CblasOrder::RowMajor, CblasUpLow::Upper, 2, &mut a, 3).unwrap();
+    assert_eq!(a, [1.0, 2.0, 0.0, 2.0, 3.0]);
+
+    let mut a = [1.0, 2.0, 9.0, 3.0];
+    matrix_f32::symmetrize(CblasOrder::ColMajor, CblasUpLow::Lower, 2, &mut a, 2).unwrap();
+    assert_eq!(a, [1.0, 2.0, 2.0, 3.0]);
+}
+
+#[test]
+fn apply_givens_sequence_rotates_rows_in_both_orders() {
+    // Zero m[1][0] of [[3, 1], [4, 2]] with c = 0.6, s = 0.8, then rotate a quarter turn (c = 0, s = 1).
+    let rotations = [(0, 1, 0.6, 0.8), (0, 1, 0.0, 1.0)];
+
+    let mut row = [3.0, 1.0, 4.0, 2.0];
+    matrix_f32::apply_givens_sequence(&rotations, &mut row, 2, 2, CblasOrder::RowMajor).unwrap();
+    assert_close(&row, &[0.0, 0.4, -5.0, -2.2]);
+
+    let mut col = [3.0, 4.0, 1.0, 2.0];
+    matrix_f32::apply_givens_sequence(&rotations, &mut col, 2, 2, CblasOrder::ColMajor).unwrap();
+    assert_close(&col, &[0.0, -5.0, 0.4, -2.2]);
+}
+
+#[test]
+fn apply_givens_sequence_validates_before_rotating() {
+    let mut m = [1.0, 2.0, 3.0, 4.0];
+    assert_eq!(
+        matrix_f32::apply_givens_sequence(
+            &[(0, 1, 0.0, 1.0), (0, 2, 1.0, 0.0)],
+            &mut m,
+            2,
+            2,
+            CblasOrder::RowMajor
+        ),
+        Err(BlasError::IndexOutOfBounds {
+            which: "rotations",
+            index: 2,
+            len: 2
+        })
+    );
+    assert_eq!(m, [1.0, 2.0, 3.0, 4.0]);
+}
+
+#[test]
+fn apply_givens_sequence_rejects_rotating_a_row_with_itself() {
+    let mut m = [1.0, 2.0, 3.0, 4.0];
+    assert_eq!(
+        matrix_f32::apply_givens_sequence(
+            &[(0, 1, 0.0, 1.0), (1, 1, 0.6, 0.8)],
+            &mut m,
+            2,
+            2,
+            CblasOrder::ColMajor
+        ),
+        Err(BlasError::RepeatedIndex {
+            which: "rotations",
+            index: 1
+        })
+    );
+    assert_eq!(m, [1.0, 2.0, 3.0, 4.0]);
+}
+
+#[test]
+fn stpmv_to_vec_leaves_input_untouched() {
+    // Packed upper triangle of [[1, 2], [0, 3]] in row-major order.
+    let ap = [1.0, 2.0, 3.0];
+    let x = [1.0, 1.0];
+    let y = matrix_f32::stpmv_to_vec(
+        CblasOrder::RowMajor,
+        CblasUpLow::Upper,
+        CblasTranspose::NoTrans,
+        CblasDiag::NonUnit,
+        &ap,
+        2,
+        &x,
+    )
+    .unwrap();
+    assert_close(&y, &[3.0, 3.0]);
+    assert_eq!(x, [1.0, 1.0]);
+}
+
+fn dense_mat_vec(a: &[f32], m: usize, n: usize, x: &[f32]) -> Vec<f32> {
+    (0..m)
+        .map(|i| (0..n).map(|j| a[i * n + j] * x[j]).sum())
+        .collect()
+}
+
+#[test]
+fn gemv_banded_auto_matches_dense_for_banded_matrix() {
+    // 12 x 16 with one sub-diagonal and two super-diagonals, so the band (4) is narrow enough to pack.
+    let (m, n) = (12, 16);
+    let mut a = vec![0.0f32; m * n];
+    for i in 0..m {
+        for j in i.saturating_sub(1)..n.min(i + 3) {
+            a[i * n + j] = (i * n + j) as f32 + 1.0;
+        }
+    }
+    let x: Vec<f32> = (0..n).map(|j| j as f32 - 2.5).collect();
+    let expected = dense_mat_vec(&a, m, n, &x);
+
+    let row = matrix_f32::gemv_banded_auto(&a, m, n, n, CblasOrder::RowMajor, &x).unwrap();
+    assert_close(&row, &expected);
+
+    let mut col = vec![0.0f32; m * n];
+    for i in 0..m {
+        for j in 0..n {
+            col[j * m + i] = a[i * n + j];
+        }
+    }
+    let col = matrix_f32::gemv_banded_auto(&col, m, n, m, CblasOrder::ColMajor, &x).unwrap();
+    assert_close(&col, &expected);
+}
+
+#[test]
+fn gemv_banded_auto_falls_back_for_dense_matrix() {
+    let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let y =
+        matrix_f32::gemv_banded_auto(&a, 2, 3, 3, CblasOrder::RowMajor, &[1.0, 0.0, -1.0]).unwrap();
+    assert_close(&y, &[-2.0, -2.0]);
+    assert_eq!(
+        matrix_f32::gemv_banded_auto(&a, 2, 3, 3, CblasOrder::RowMajor, &[1.0]),
+        Err(BlasError::DimensionMismatch {
+            which: "x",
+            expected: 3,
+            got: 1
+        })
+    );
+}
+
+#[test]
+fn sgemm_packed_reuses_transposed_column_major_a() {
+    // A = [[1, 2], [3, 4], [5, 6]] given as A^T stored column-major with padding (lda = 3).
+    let a_t = [1.0, 2.0, 0.0, 3.0, 4.0, 0.0, 5.0, 6.0];
+    let packed =
+        matrix_f32::pack_a(CblasOrder::ColMajor, CblasTranspose::Trans, 3, 2, &a_t, 3).unwrap();
+    assert_eq!((packed.rows(), packed.cols()), (3, 2));
+
+    let mut c = [0.0; 3];
+    matrix_f32::sgemm_packed(&packed, &[1.0, -1.0], &mut c).unwrap();
+    assert_eq!(c, [-1.0, -1.0, -1.0]);
+
+    let mut c = [0.0; 6];
+    matrix_f32::sgemm_packed(&packed, &[1.0, 0.0, 0.0, 1.0], &mut c).unwrap();
+    assert_eq!(c, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    assert!(matches!(
+        matrix_f32::sgemm_packed(&packed, &[1.0, 0.0, 0.0, 1.0], &mut [0.0; 5]),
+        Err(BlasError::DimensionMismatch { which: "c", .. })
+    ));
+}
+
+#[test]
+fn sgemv_into_writes_strided_segment_only() {
+    // A = [[1, 2], [3, 4]], x = [1, 1]; A * x = [3, 7].
+    let a = [1.0, 2.0, 3.0, 4.0];
+    let mut y = [-1.0; 8];
+    matrix_f32::sgemv_into(
+        CblasOrder::RowMajor,
+        CblasTranspose::NoTrans,
+        &a,
+        2,
+        2,
+        2,
+        1.0,
+        &[1.0, 1.0],
+        0.0,
+        &mut y,
+        3,
+        2,
+    )
+    .unwrap();
+    assert_eq!(y, [-1.0, -1.0, -1.0, 3.0, -1.0, 7.0, -1.0, -1.0]);
+
+    // A negative increment fills the same segment back to front.
+    matrix_f32::sgemv_into(
+        CblasOrder::RowMajor,
+        CblasTranspose::NoTrans,
+        &a,
+        2,
+        2,
+        2,
+        1.0,
+        &[1.0, 1.0],
+        0.0,
+        &mut y,
+        3,
+        -2,
+    )
+    .unwrap();
+    assert_eq!(y[3], 7.0);
+    assert_eq!(y[5], 3.0);
+}
+
+#[test]
+fn sgemv_into_rejects_segment_past_end() {
+    let a = [1.0; 4];
+    let mut y = [0.0; 6];
+    assert_eq!(
+        matrix_f32::sgemv_into(
+            CblasOrder::ColMajor,
+            CblasTranspose::Trans,
+            &a,
+            2,
+            2,
+            2,
+            1.0,
+            &[1.0, 1.0],
+            0.0,
+            &mut y,
+            3,
+            3,
+        ),
+        Err(BlasError::BufferTooSmall {
+            which: "y",
+            needed: 7,
+            got: 6
+        })
+    );
+    assert!(matches!(
+        matrix_f32::sgemv_into(
+            CblasOrder::ColMajor,
+            CblasTranspose::Trans,
+            &a,
+            2,
+            2,
+            2,
+            1.0,
+            &[1.0, 1.0],
+            0.0,
+            &mut y,
+            usize::MAX,
+            1,
+        ),
+        Err(BlasError::BufferTooSmall {
+            needed: usize::MAX,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn swap_rows_and_columns_in_both_orders() {
+    // [[1, 2, 3], [4, 5, 6]] row-major with padding (lda = 4).
+    let mut a = [1.0, 2.0, 3.0, 0.0, 4.0, 5.0, 6.0];
+    matrix_f32::swap_rows(&mut a, 2, 3, 4, CblasOrder::RowMajor, 0, 1).unwrap();
+    assert_eq!(a, [4.0, 5.0, 6.0, 0.0, 1.0, 2.0, 3.0]);
+    matrix_f32::swap_columns(&mut a, 2, 3, 4, CblasOrder::RowMajor, 0, 2).unwrap();
+    assert_eq!(a, [6.0, 5.0, 4.0, 0.0, 3.0, 2.0, 1.0]);
+
+    // The same matrix column-major: columns are contiguous, rows are strided.
+    let mut a = [1.0, 4.0, 2.0, 5.0, 3.0, 6.0];
+    matrix_f32::swap_rows(&mut a, 2, 3, 2, CblasOrder::ColMajor, 1, 0).unwrap();
+    assert_eq!(a, [4.0, 1.0, 5.0, 2.0, 6.0, 3.0]);
+    matrix_f32::swap_columns(&mut a, 2, 3, 2, CblasOrder::ColMajor, 1, 1).unwrap();
+    assert_eq!(a, [4.0, 1.0, 5.0, 2.0, 6.0, 3.0]);
+
+    assert_eq!(
+        matrix_f32::swap_columns(&mut a, 2, 3, 2, CblasOrder::ColMajor, 0, 3),
+        Err(BlasError::IndexOutOfBounds {
+            which: "j",
+            index: 3,
+            len: 3
+        })
+    );
+}
+
+#[test]
+fn gemv_residual_norm_forms_b_minus_ax() {
+    // [[1, 2], [3, 4], [5, 6]] column-major with lda = 4.
+    let a = [1.0, 3.0, 5.0, 0.0, 2.0, 4.0, 6.0];
+    let (r, norm) = matrix_f32::gemv_residual_norm(
+        &a,
+        3,
+        2,
+        4,
+        CblasOrder::ColMajor,
+        &[1.0, 1.0],
+        &[3.0, 3.0, 15.0],
+    )
+    .unwrap();
+    assert_close(&r, &[0.0, -4.0, 4.0]);
+    assert!((norm - 32.0f32.sqrt()).abs() < 1e-5);
+
+    assert_eq!(
+        matrix_f32::gemv_residual_norm(&a, 3, 2, 4, CblasOrder::ColMajor, &[1.0, 1.0], &[0.0; 2]),
+        Err(BlasError::DimensionMismatch {
+            which: "b",
+            expected: 3,
+            got: 2
+        })
+    );
+}
+
+#[test]
+fn transpose_into_handles_multiple_tiles() {
+    let (rows, cols) = (33, 70);
+    let a: Vec<f32> = (0..rows * cols).map(|v| v as f32).collect();
+    let mut t = vec![0.0f32; rows * cols];
+    matrix_f32::transpose_into(&a, rows, cols, &mut t).unwrap();
+    for i in 0..rows {
+        for j in 0..cols {
+            assert_eq!(t[j * rows + i], a[i * cols + j]);
+        }
+    }
+}
+
+#[test]
+fn sgemm_materialized_transpose_matches_flags() {
+    // A^T is 2 x 3 with A stored 3 x 2; B is 3 x 2 as stored.
+    let a = [1.0, 4.0, 2.0, 5.0, 3.0, 6.0];
+    let b = [1.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+    let mut c = [0.0f32; 4];
+    matrix_f32::sgemm_materialized_transpose(
+        &a,
+        &b,
+        &mut c,
+        (2, 2, 3),
+        CblasTranspose::Trans,
+        CblasTranspose::NoTrans,
+    )
+    .unwrap();
+    // [[1, 2, 3], [4, 5, 6]] * [[1, 0], [0, 1], [1, 1]]
+    assert_close(&c, &[4.0, 5.0, 10.0, 11.0]);
+
+    // B^T with B stored 2 x 3.
+    let b_t = [1.0, 0.0, 1.0, 0.0, 1.0, 1.0];
+    matrix_f32::sgemm_materialized_transpose(
+        &a,
+        &b_t,
+        &mut c,
+        (2, 2, 3),
+        CblasTranspose::Trans,
+        CblasTranspose::Trans,
+    )
+    .unwrap();
+    assert_close(&c, &[4.0, 5.0, 10.0, 11.0]);
+
+    assert_eq!(
+        matrix_f32::sgemm_materialized_transpose(
+            &a,
+            &b,
+            &mut c[..3],
+            (2, 2, 3),
+            CblasTranspose::Trans,
+            CblasTranspose::NoTrans,
+        ),
+        Err(BlasError::DimensionMismatch {
+            which: "c",
+            expected: 4,
+            got: 3
+        })
+    );
+}
+
+#[test]
+fn is_symmetric_honours_tolerance_and_padding() {
+    // [[1, 2], [2.001, 3]] row-major with a junk pad (lda = 3).
+    let a = [1.0, 2.0, 99.0, 2.001, 3.0];
+    assert_eq!(
+        matrix_f32::is_symmetric(&a, 2, 3, CblasOrder::RowMajor, 1e-2),
+        Ok(true)
+    );
+    assert_eq!(
+        matrix_f32::is_symmetric(&a, 2, 3, CblasOrder::RowMajor, 0.0),
+        Ok(false)
+    );
+    let nan = [1.0, f32::NAN, f32::NAN, 1.0];
+    assert_eq!(
+        matrix_f32::is_symmetric(&nan, 2, 2, CblasOrder::ColMajor, 1.0),
+        Ok(false)
+    );
+}
+
+#[test]
+fn sgemv_blocked_matches_single_call_in_both_orders() {
+    // 7 x 3, split into blocks of 3, 3 and 1 rows.
+    let (m, n) = (7, 3);
+    let a: Vec<f32> = (0..m * n).map(|v| v as f32 * 0.5 - 3.0).collect();
+    let x = [1.0, -2.0, 0.5];
+    let expected = dense_mat_vec(&a, m, n, &x);
+
+    let mut y = vec![f32::NAN; m];
+    matrix_f32::sgemv_blocked(&a, m, n, n, CblasOrder::RowMajor, &x, &mut y, 3).unwrap();
+    assert_close(&y, &expected);
+
+    // Column-major with padding (lda = 8).
+    let mut col = vec![0.0f32; 8 * n];
+    for i in 0..m {
+        for j in 0..n {
+            col[j * 8 + i] = a[i * n + j];
+        }
+    }
+    let mut y = vec![f32::NAN; m];
+    matrix_f32::sgemv_blocked(&col, m, n, 8, CblasOrder::ColMajor, &x, &mut y, 3).unwrap();
+    assert_close(&y, &expected);
+
+    assert_eq!(
+        matrix_f32::sgemv_blocked(&a, m, n, n, CblasOrder::RowMajor, &x, &mut y[..6], 3),
+        Err(BlasError::DimensionMismatch {
+            which: "y",
+            expected: 7,
+            got: 6
+        })
+    );
+}
+
+#[test]
+fn sgemv_blocked_rejects_zero_block_rows() {
+    let a = [1.0f32; 6];
+    let mut y = [5.0f32; 2];
+    assert_eq!(
+        matrix_f32::sgemv_blocked(&a, 2, 3, 3, CblasOrder::RowMajor, &[1.0; 3], &mut y, 0),
+        Err(BlasError::DimensionMismatch {
+            which: "block_rows",
+            expected: 1,
+            got: 0
+        })
+    );
+    assert_eq!(y, [5.0, 5.0]);
+}
+
+#[test]
+fn sgemm_validates_and_timed_variant_reports_duration() {
+    // A = [[1, 2], [3, 4]] times the transpose of the stored [[5, 6], [7, 8]].
+    let a = [1.0, 2.0, 3.0, 4.0];
+    let b_t = [5.0, 6.0, 7.0, 8.0];
+    let mut c = [1.0f32; 4];
+    matrix_f32::sgemm(
+        CblasOrder::RowMajor,
+        CblasTranspose::NoTrans,
+        CblasTranspose::Trans,
+        2,
+        2,
+        2,
+        1.0,
+        &a,
+        2,
+        &b_t,
+        2,
+        1.0,
+        &mut c,
+        2,
+    )
+    .unwrap();
+    // A * [[5, 7], [6, 8]] = [[17, 23], [39, 53]], plus the initial ones.
+    assert_close(&c, &[18.0, 24.0, 40.0, 54.0]);
+
+    let (result, elapsed) = matrix_f32::sgemm_timed(
+        CblasOrder::RowMajor,
+        CblasTranspose::NoTrans,
+        CblasTranspose::NoTrans,
+        2,
+        2,
+        2,
+        1.0,
+        &a,
+        2,
+        &b_t[..3],
+        2,
+        0.0,
+        &mut c,
+        2,
+    );
+    assert_eq!(
+        result,
+        Err(BlasError::BufferTooSmall {
+            which: "b",
+            needed: 4,
+            got: 3
+        })
+    );
+    assert!(elapsed < std::time::Duration::from_secs(1));
+}
+
+fn outer_product_sum(xs: &[Vec<f32>], ys: &[Vec<f32>], alpha: f32) -> Vec<f32> {
+    let (m, n) = (xs[0].len(), ys[0].len());
+    let mut sum = vec![1.0f32; m * n];
+    for (x, y) in xs.iter().zip(ys) {
+        for i in 0..m {
+            for j in 0..n {
+                sum[i * n + j] += alpha * x[i] * y[j];
+            }
+        }
+    }
+    sum
+}
+
+#[test]
+fn accumulate_outer_products_matches_repeated_sger() {
+    let (m, n) = (3, 2);
+    for &k in &[2usize, 6] {
+        let xs: Vec<Vec<f32>> = (0..k)
+            .map(|p| (0..m).map(|i| (p + i) as f32 - 1.0).collect())
+            .collect();
+        let ys: Vec<Vec<f32>> = (0..k)
+            .map(|p| (0..n).map(|j| (p * j) as f32 * 0.5 + 1.0).collect())
+            .collect();
+        let x_refs: Vec<&[f32]> = xs.iter().map(Vec::as_slice).collect();
+        let y_refs: Vec<&[f32]> = ys.iter().map(Vec::as_slice).collect();
+        let expected = outer_product_sum(&xs, &ys, 0.5);
+
+        let mut row = vec![1.0f32; m * n];
+        matrix_f32::accumulate_outer_products(
+            &x_refs,
+            &y_refs,
+            &mut row,
+            m,
+            n,
+            n,
+            CblasOrder::RowMajor,
+            0.5,
+        )
+        .unwrap();
+        assert_close(&row, &expected);
+
+        // Column-major with padding (lda = 4); the pad must survive.
+        let mut col = vec![1.0f32; 4 * n];
+        col[3] = -7.0;
+        matrix_f32::accumulate_outer_products(
+            &x_refs,
+            &y_refs,
+            &mut col,
+            m,
+            n,
+            4,
+            CblasOrder::ColMajor,
+            0.5,
+        )
+        .unwrap();
+        assert_eq!(col[3], -7.0);
+        for i in 0..m {
+            for j in 0..n {
+                assert!((col[j * 4 + i] - expected[i * n + j]).abs() < 1e-4);
+            }
+        }
+    }
+}
+
+/// Element `(row, col)` of a matrix stored in `order` with leading dimension `ld`, as a buffer offset.
+fn offset(order: CblasOrder, ld: usize, row: usize, col: usize) -> usize {
+    match order {
+        CblasOrder::RowMajor => row * ld + col,
+        CblasOrder::ColMajor => col * ld + row,
+    }
+}
+
+#[test]
+fn sgemm_matches_column_by_column_sgemv() {
+    let (m, n, k) = (3, 4, 5);
+    for &order in &[CblasOrder::RowMajor, CblasOrder::ColMajor] {
+        for &trans_a in &[CblasTranspose::NoTrans, CblasTranspose::Trans] {
+            for &trans_b in &[CblasTranspose::NoTrans, CblasTranspose::Trans] {
+                // Stored shapes, and leading dimensions padded by one so lda mix-ups show.
+                let (a_rows, a_cols) = match trans_a {
+                    CblasTranspose::NoTrans => (m, k),
+                    _ => (k, m),
+                };
+                let (b_rows, b_cols) = match trans_b {
+                    CblasTranspose::NoTrans => (k, n),
+                    _ => (n, k),
+                };
+                let lda = order.leading_dimension(a_rows as i32, a_cols as i32) as usize + 1;
+                let ldb = order.leading_dimension(b_rows as i32, b_cols as i32) as usize + 1;
+                let ldc = order.leading_dimension(m as i32, n as i32) as usize + 1;
+                let a: Vec<f32> = (0..lda * a_rows.max(a_cols))
+                    .map(|v| (v % 7) as f32 - 3.0)
+                    .collect();
+                let b: Vec<f32> = (0..ldb * b_rows.max(b_cols))
+                    .map(|v| (v % 5) as f32 * 0.5 - 1.0)
+                    .collect();
+
+                let mut c = vec![0.0f32; ldc * m.max(n)];
+                matrix_f32::sgemm(
+                    order, trans_a, trans_b, m, n, k, 1.0, &a, lda, &b, ldb, 0.0, &mut c, ldc,
+                )
+                .unwrap();
+
+                // Column j of C is op(A) times column j of op(B), read in place with its stride.
+                let mut c_ref = vec![0.0f32; c.len()];
+                for j in 0..n {
+                    let (b_start, b_next) = match trans_b {
+                        CblasTranspose::NoTrans => {
+                            (offset(order, ldb, 0, j), offset(order, ldb, 1, j))
+                        }
+                        _ => (offset(order, ldb, j, 0), offset(order, ldb, j, 1)),
+                    };
+                    let c_start = offset(order, ldc, 0, j);
+                    let c_inc = offset(order, ldc, 1, j) - c_start;
+                    unsafe {
+                        matrix_f32::mat_vec_mul(
+                            order,
+                            trans_a,
+                            a_rows as i32,
+                            a_cols as i32,
+                            1.0,
+                            a.as_ptr(),
+                            lda as i32,
+                            b[b_start..].as_ptr(),
+                            (b_next - b_start) as i32,
+                            0.0,
+                            c_ref[c_start..].as_mut_ptr(),
+                            c_inc as i32,
+                        );
+                    }
+                }
+                assert_close(&c, &c_ref);
+            }
+        }
+    }
+}
+
+#[test]
+fn stbsv_and_sgemv_into_reject_zero_increment() {
+    let a = [1.0f32; 4];
+    let mut x = [1.0f32; 2];
+    assert_eq!(
+        matrix_f32::stbsv(
+            CblasOrder::ColMajor,
+            CblasUpLow::Upper,
+            CblasTranspose::NoTrans,
+            CblasDiag::NonUnit,
+            2,
+            0,
+            &a,
+            1,
+            &mut x,
+            0
+        ),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+    let mut y = [0.0f32; 2];
+    assert_eq!(
+        matrix_f32::sgemv_into(
+            CblasOrder::RowMajor,
+            CblasTranspose::NoTrans,
+            &a,
+            2,
+            2,
+            2,
+            1.0,
+            &x,
+            0.0,
+            &mut y,
+            0,
+            0
+        ),
+        Err(BlasError::ZeroIncrement { which: "inc_y" })
+    );
+}
+
+#[test]
+fn sgemm_rejects_dimensions_beyond_c_int() {
+    // With n = k = 0 no element is addressed, so empty buffers pass the length checks and only the
+    // narrowing of `m` to a C `int` can fail.
+    let huge = i32::MAX as usize + 1;
+    let err = matrix_f32::sgemm(
+        CblasOrder::RowMajor,
+        CblasTranspose::NoTrans,
+        CblasTranspose::NoTrans,
+        huge,
+        0,
+        0,
+        1.0,
+        &[],
+        1,
+        &[],
+        1,
+        0.0,
+        &mut [],
+        1,
+    );
+    assert_eq!(
+        err,
+        Err(BlasError::IntOverflow {
+            which: "m",
+            value: huge
+        })
+    );
+}
+
+#[test]
+fn ssyevr_eigenpairs_satisfy_av_eq_lambda_v_in_both_orders() {
+    // A symmetric matrix with eigenvalues 3 - sqrt(3), 3 and 3 + sqrt(3).
+    let full = [4.0f32, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0];
+    for order in [CblasOrder::RowMajor, CblasOrder::ColMajor] {
+        // Store only the upper triangle of A; the strictly lower part holds junk that must be ignored.
+        let mut a = [99.0f32; 9];
+        for i in 0..3 {
+            for j in i..3 {
+                match order {
+                    CblasOrder::RowMajor => a[i * 3 + j] = full[i * 3 + j],
+                    CblasOrder::ColMajor => a[j * 3 + i] = full[i * 3 + j],
+                }
+            }
+        }
+        let eigen = |range| {
+            matrix_f32::ssyevr(
+                EigenJob::ValuesAndVectors,
+                CblasUpLow::Upper,
+                &a,
+                3,
+                3,
+                order,
+                range,
+                None,
+            )
+            .unwrap()
+        };
+        for (range, count) in [
+            (EigenRange::Indices(0..2), 2),
+            (EigenRange::Values(2.5, 5.0), 2),
+        ] {
+            let (w, z) = eigen(range);
+            let z = z.unwrap();
+            assert_eq!((w.len(), z.len()), (count, 3 * count));
+            for (j, lambda) in w.iter().enumerate() {
+                let v: Vec<f32> = (0..3)
+                    .map(|i| match order {
+                        CblasOrder::RowMajor => z[i * count + j],
+                        CblasOrder::ColMajor => z[j * 3 + i],
+                    })
+                    .collect();
+                for i in 0..3 {
+                    let av: f32 = (0..3).map(|k| full[i * 3 + k] * v[k]).sum();
+                    assert!((av - lambda * v[i]).abs() < 1e-4, "{:?} {}", order, lambda);
+                }
+            }
+        }
+        let (w, _) = eigen(EigenRange::Values(2.5, 5.0));
+        let s = 3f32.sqrt();
+        assert!((w[0] - 3.0).abs() < 1e-4 && (w[1] - (3.0 + s)).abs() < 1e-4);
+
+        // Empty ranges return early with an empty (but present) eigenvector matrix.
+        assert_eq!(
+            eigen(EigenRange::Values(10.0, 20.0)),
+            (vec![], Some(vec![]))
+        );
+        assert_eq!(eigen(EigenRange::Indices(1..1)), (vec![], Some(vec![])));
+    }
+}
+
+#[test]
+fn matrix_rank_uses_scaled_default_tolerance() {
+    // Rows 1 and 3 are dependent, so the rank is 2 at any scale.
+    let a = [1.0f32, 2.0, 3.0, 0.0, 1.0, 4.0, 2.0, 4.0, 6.0];
+    assert_eq!(matrix_f32::matrix_rank(&a, 3, 3, None), Ok(2));
+    let tiny: Vec<f32> = a.iter().map(|v| v * 1e-20).collect();
+    assert_eq!(matrix_f32::matrix_rank(&tiny, 3, 3, None), Ok(2));
+
+    // diag(1, 1e-3) has full rank by default but rank 1 with a coarse tolerance.
+    let d = [1.0f32, 0.0, 0.0, 1e-3];
+    assert_eq!(matrix_f32::matrix_rank(&d, 2, 2, None), Ok(2));
+    assert_eq!(matrix_f32::matrix_rank(&d, 2, 2, Some(1e-2)), Ok(1));
+    assert_eq!(matrix_f32::matrix_rank(&[], 0, 3, None), Ok(0));
+    assert_eq!(
+        matrix_f32::matrix_rank(&a, 2, 2, None),
+        Err(BlasError::DimensionMismatch {
+            which: "a",
+            expected: 4,
+            got: 9
+        })
+    );
+}
+
+#[test]
+fn covariance_rejects_too_few_samples() {
+    let one_sample = [1.0f32, 2.0];
+    assert_eq!(
+        matrix_f32::covariance(CblasOrder::RowMajor, 0, 2, &[]),
+        Err(BlasError::DimensionMismatch {
+            which: "n_samples",
+            expected: 1,
+            got: 0
+        })
+    );
+    assert!(matrix_f32::covariance(CblasOrder::RowMajor, 1, 2, &one_sample).is_ok());
+    assert_eq!(
+        matrix_f32::covariance_unbiased(CblasOrder::RowMajor, 1, 2, &one_sample),
+        Err(BlasError::DimensionMismatch {
+            which: "n_samples",
+            expected: 2,
+            got: 1
+        })
+    );
+}
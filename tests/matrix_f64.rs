@@ -0,0 +1,502 @@
+use accelerate_general::constants::{
+    CblasDiag, CblasOrder, CblasTranspose, CblasUpLow, EigenJob, EigenRange,
+};
+use accelerate_general::error::BlasError;
+use accelerate_general::matrix_f64;
+
+fn assert_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-12, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn solve_diagnostics_reports_residual_norms() {
+    // A = [[2, 0], [0, 4]], b = [2, 4]: the exact solution is [1, 1].
+    let a = [2.0, 0.0, 0.0, 4.0];
+    let b = [2.0, 4.0];
+    let exact = matrix_f64::solve_diagnostics(&a, 2, &[1.0, 1.0], &b).unwrap();
+    assert_eq!(exact.residual_norm, 0.0);
+    assert_eq!(exact.relative_residual, 0.0);
+    assert!((exact.rhs_norm - 20f64.sqrt()).abs() < 1e-12);
+
+    // Off by 0.5 in x[1]: residual [0, 2].
+    let off = matrix_f64::solve_diagnostics(&a, 2, &[1.0, 1.5], &b).unwrap();
+    assert!((off.residual_norm - 2.0).abs() < 1e-12);
+    assert!((off.relative_residual - 2.0 / 20f64.sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn solve_diagnostics_handles_zero_rhs_and_bad_lengths() {
+    let a = [1.0];
+    let d = matrix_f64::solve_diagnostics(&a, 1, &[1.0], &[0.0]).unwrap();
+    assert_eq!(d.relative_residual, f64::INFINITY);
+    assert_eq!(
+        matrix_f64::solve_diagnostics(&a, 1, &[1.0, 2.0], &[0.0]),
+        Err(BlasError::DimensionMismatch {
+            which: "x",
+            expected: 1,
+            got: 2
+        })
+    );
+}
+
+#[test]
+fn solve_banded_spd_handles_both_triangles() {
+    // A = tridiag(-1, 2, -1) of order 3, x = [1, 2, 3], b = A * x = [0, 0, 4].
+    let mut upper = [0.0, 2.0, -1.0, 2.0, -1.0, 2.0];
+    let mut b = [0.0, 0.0, 4.0];
+    matrix_f64::solve_banded_spd(CblasUpLow::Upper, &mut upper, 3, 1, &mut b).unwrap();
+    assert_close(&b, &[1.0, 2.0, 3.0]);
+
+    // Two right-hand sides, column-major, against the lower band.
+    let mut lower = [2.0, -1.0, 2.0, -1.0, 2.0, 0.0];
+    let mut b = [0.0, 0.0, 4.0, 1.0, 0.0, 1.0];
+    matrix_f64::solve_banded_spd(CblasUpLow::Lower, &mut lower, 3, 1, &mut b).unwrap();
+    assert_close(&b, &[1.0, 2.0, 3.0, 1.0, 1.0, 1.0]);
+}
+
+#[test]
+fn solve_banded_spd_reports_indefinite_and_bad_layout() {
+    // [[1, 2], [2, 1]] has eigenvalues 3 and -1.
+    let mut ab = [0.0, 1.0, 2.0, 1.0];
+    let mut b = [1.0, 1.0];
+    assert_eq!(
+        matrix_f64::solve_banded_spd(CblasUpLow::Upper, &mut ab, 2, 1, &mut b),
+        Err(BlasError::NotPositiveDefinite { minor: 2 })
+    );
+    assert_eq!(b, [1.0, 1.0]);
+    assert_eq!(
+        matrix_f64::solve_banded_spd(CblasUpLow::Upper, &mut ab, 2, 2, &mut b),
+        Err(BlasError::DimensionMismatch {
+            which: "ab",
+            expected: 6,
+            got: 4
+        })
+    );
+}
+
+#[test]
+fn cond_2norm_is_ratio_of_extreme_singular_values() {
+    // [[4, 0, 0], [0, 2, 0]]: singular values 4 and 2.
+    let a = [4.0, 0.0, 0.0, 0.0, 2.0, 0.0];
+    assert!((matrix_f64::cond_2norm(&a, 2, 3).unwrap() - 2.0).abs() < 1e-12);
+    // [[3, 0], [0, 0], [0, 1]] exercises the tall case.
+    let tall = [3.0, 0.0, 0.0, 0.0, 0.0, 1.0];
+    assert!((matrix_f64::cond_2norm(&tall, 3, 2).unwrap() - 3.0).abs() < 1e-12);
+
+    // [[1, 1], [1, 1]] is singular.
+    assert_eq!(
+        matrix_f64::cond_2norm(&[1.0; 4], 2, 2).unwrap(),
+        f64::INFINITY
+    );
+    assert_eq!(
+        matrix_f64::cond_2norm(&[1.0; 3], 2, 2),
+        Err(BlasError::DimensionMismatch {
+            which: "a",
+            expected: 4,
+            got: 3
+        })
+    );
+}
+
+#[test]
+fn covariance_accumulator_matches_batch_covariance() {
+    let data = [1.0, 2.0, 3.0, 5.0, 4.0, 4.0, 10.0, -1.0];
+    let mut acc = matrix_f64::CovarianceAccumulator::new(2);
+    assert_eq!(acc.covariance(), None);
+    for sample in data.chunks(2) {
+        acc.update(sample).unwrap();
+    }
+    assert_eq!(acc.count(), 4);
+    assert_close(acc.mean(), &[4.5, 2.5]);
+    let batch = matrix_f64::covariance(CblasOrder::RowMajor, 4, 2, &data).unwrap();
+    assert_close(&acc.covariance().unwrap(), &batch);
+    let batch = matrix_f64::covariance_unbiased(CblasOrder::RowMajor, 4, 2, &data).unwrap();
+    assert_close(&acc.covariance_unbiased().unwrap(), &batch);
+
+    assert_eq!(
+        acc.update(&[1.0]),
+        Err(BlasError::DimensionMismatch {
+            which: "x",
+            expected: 2,
+            got: 1
+        })
+    );
+}
+
+#[test]
+fn extract_l_and_u_split_combined_lu() {
+    // L = [[1, 0], [0.5, 1]], U = [[4, 2], [0, 3]] overlaid, column-major with lda = 3.
+    let lu = [4.0, 0.5, -9.0, 2.0, 3.0];
+    let l = matrix_f64::extract_l(&lu, 2, 3, CblasOrder::ColMajor).unwrap();
+    let u = matrix_f64::extract_u(&lu, 2, 3, CblasOrder::ColMajor).unwrap();
+    assert_eq!(l, [1.0, 0.5, 0.0, 1.0]);
+    assert_eq!(u, [4.0, 0.0, 2.0, 3.0]);
+
+    // The same factors row-major.
+    let lu = [4.0, 2.0, 0.5, 3.0];
+    assert_eq!(
+        matrix_f64::extract_l(&lu, 2, 2, CblasOrder::RowMajor).unwrap(),
+        [1.0, 0.0, 0.5, 1.0]
+    );
+    assert_eq!(
+        matrix_f64::extract_u(&lu, 2, 2, CblasOrder::RowMajor).unwrap(),
+        [4.0, 2.0, 0.0, 3.0]
+    );
+}
+
+#[test]
+fn matrix_rank_uses_scaled_default_tolerance() {
+    // Rows 1 and 3 are dependent, so the rank is 2 at any scale.
+    let a = [1.0, 2.0, 3.0, 0.0, 1.0, 4.0, 2.0, 4.0, 6.0];
+    assert_eq!(matrix_f64::matrix_rank(&a, 3, 3, None), Ok(2));
+    let tiny: Vec<f64> = a.iter().map(|v| v * 1e-100).collect();
+    assert_eq!(matrix_f64::matrix_rank(&tiny, 3, 3, None), Ok(2));
+
+    // diag(1, 1e-3) has full rank by default but rank 1 with a coarse tolerance.
+    let d = [1.0, 0.0, 0.0, 1e-3];
+    assert_eq!(matrix_f64::matrix_rank(&d, 2, 2, None), Ok(2));
+    assert_eq!(matrix_f64::matrix_rank(&d, 2, 2, Some(1e-2)), Ok(1));
+    assert_eq!(matrix_f64::matrix_rank(&[], 0, 3, None), Ok(0));
+}
+
+#[test]
+fn dsyevr_selects_eigenvalues_by_index_and_value() {
+    // The second-difference matrix has eigenvalues 2 - sqrt(2), 2 and 2 + sqrt(2). Only the upper
+    // triangle is read, so the lower one holds junk.
+    let a = [2.0, -1.0, 0.0, 99.0, 2.0, -1.0, 99.0, 99.0, 2.0];
+    let s = 2f64.sqrt();
+    let eigen = |range| {
+        matrix_f64::dsyevr(
+            EigenJob::ValuesOnly,
+            CblasUpLow::Upper,
+            &a,
+            3,
+            3,
+            CblasOrder::RowMajor,
+            range,
+            None,
+        )
+        .unwrap()
+    };
+    let (w, z) = eigen(EigenRange::Indices(0..2));
+    assert!(z.is_none());
+    assert!((w[0] - (2.0 - s)).abs() < 1e-10 && (w[1] - 2.0).abs() < 1e-10);
+    assert_eq!(w.len(), 2);
+    let (w, _) = eigen(EigenRange::Values(1.0, 3.0));
+    assert_eq!(w.len(), 1);
+    assert!((w[0] - 2.0).abs() < 1e-10);
+    assert!(eigen(EigenRange::Values(3.0, 1.0)).0.is_empty());
+    assert!(eigen(EigenRange::Indices(1..1)).0.is_empty());
+    assert_eq!(
+        matrix_f64::dsyevr(
+            EigenJob::ValuesOnly,
+            CblasUpLow::Upper,
+            &a,
+            3,
+            3,
+            CblasOrder::RowMajor,
+            EigenRange::Indices(2..4),
+            None,
+        ),
+        Err(BlasError::IndexOutOfBounds {
+            which: "range",
+            index: 3,
+            len: 3
+        })
+    );
+}
+
+#[test]
+fn dsyevr_returns_eigenvectors_in_the_requested_order() {
+    let full = [2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0];
+    for order in [CblasOrder::RowMajor, CblasOrder::ColMajor] {
+        let (w, z) = matrix_f64::dsyevr(
+            EigenJob::ValuesAndVectors,
+            CblasUpLow::Lower,
+            &full,
+            3,
+            3,
+            order,
+            EigenRange::Indices(1..3),
+            None,
+        )
+        .unwrap();
+        let z = z.unwrap();
+        assert_eq!((w.len(), z.len()), (2, 6));
+        for (j, lambda) in w.iter().enumerate() {
+            let v: Vec<f64> = (0..3)
+                .map(|i| match order {
+                    CblasOrder::RowMajor => z[i * 2 + j],
+                    CblasOrder::ColMajor => z[j * 3 + i],
+                })
+                .collect();
+            for i in 0..3 {
+                let av: f64 = (0..3).map(|k| full[i * 3 + k] * v[k]).sum();
+                assert!((av - lambda * v[i]).abs() < 1e-10);
+            }
+        }
+    }
+}
+
+#[test]
+fn dtbsv_rejects_zero_increment() {
+    let a = [1.0f64; 2];
+    let mut x = [1.0f64; 2];
+    assert_eq!(
+        matrix_f64::dtbsv(
+            CblasOrder::ColMajor,
+            CblasUpLow::Upper,
+            CblasTranspose::NoTrans,
+            CblasDiag::NonUnit,
+            2,
+            0,
+            &a,
+            1,
+            &mut x,
+            0
+        ),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+}
+
+#[test]
+fn dgemm_and_dgemv_match_hand_computed_products() {
+    // A = [[1, 2, 3], [4, 5, 6]], B = [[7, 8], [9, 10], [11, 12]], both row-major.
+    let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let b = [7.0, 8.0, 9.0, 10.0, 11.0, 12.0];
+    let mut c = [1.0; 4];
+    let mut y = [1.0; 2];
+    unsafe {
+        matrix_f64::mat_mul(
+            CblasOrder::RowMajor,
+            CblasTranspose::NoTrans,
+            CblasTranspose::NoTrans,
+            2,
+            2,
+            3,
+            1.0,
+            a.as_ptr(),
+            3,
+            b.as_ptr(),
+            2,
+            0.5,
+            c.as_mut_ptr(),
+            2,
+        );
+        matrix_f64::mat_vec_mul(
+            CblasOrder::RowMajor,
+            CblasTranspose::NoTrans,
+            2,
+            3,
+            2.0,
+            a.as_ptr(),
+            3,
+            [1.0, 0.0, -1.0].as_ptr(),
+            1,
+            1.0,
+            y.as_mut_ptr(),
+            1,
+        );
+    }
+    assert_close(&c, &[58.5, 64.5, 139.5, 154.5]);
+    // 2 * [1 - 3, 4 - 6] + [1, 1]
+    assert_close(&y, &[-3.0, -3.0]);
+}
+
+#[test]
+fn dger_and_dgbmv_match_hand_computed_results() {
+    let mut a = [1.0, 0.0, 0.0, 1.0];
+    // The same tridiagonal [[2, 1, 0], [1, 2, 1], [0, 1, 2]] in row-major band storage, kl = ku = 1.
+    let band = [0.0, 2.0, 1.0, 1.0, 2.0, 1.0, 1.0, 2.0, 0.0];
+    let mut y = [0.0; 3];
+    unsafe {
+        matrix_f64::mat_rank1_update(
+            CblasOrder::ColMajor,
+            2,
+            2,
+            2.0,
+            [1.0, 2.0].as_ptr(),
+            1,
+            [3.0, 4.0].as_ptr(),
+            1,
+            a.as_mut_ptr(),
+            2,
+        );
+        matrix_f64::band_mat_mul_vec(
+            CblasOrder::RowMajor,
+            CblasTranspose::NoTrans,
+            3,
+            3,
+            1,
+            1,
+            1.0,
+            band.as_ptr(),
+            3,
+            [1.0, 2.0, 3.0].as_ptr(),
+            1,
+            0.0,
+            y.as_mut_ptr(),
+            1,
+        );
+    }
+    // Column-major I + 2 * [1, 2]^T [3, 4]
+    assert_close(&a, &[7.0, 12.0, 8.0, 17.0]);
+    assert_close(&y, &[4.0, 8.0, 8.0]);
+}
+
+#[test]
+fn dsyr_updates_only_the_requested_triangle() {
+    let x = [1.0, 2.0, 3.0];
+    // Strictly lower entries hold a sentinel that must survive an upper-triangle update.
+    let mut a = [1.0, 0.0, 0.0, -9.0, 1.0, 0.0, -9.0, -9.0, 1.0];
+    unsafe {
+        matrix_f64::sym_rank_1_update(
+            CblasOrder::RowMajor,
+            CblasUpLow::Upper,
+            3,
+            0.5,
+            x.as_ptr(),
+            1,
+            a.as_mut_ptr(),
+            3,
+        );
+    }
+    let mut expected = [1.0, 0.0, 0.0, -9.0, 1.0, 0.0, -9.0, -9.0, 1.0];
+    for i in 0..3 {
+        for j in i..3 {
+            expected[i * 3 + j] += 0.5 * x[i] * x[j];
+        }
+    }
+    assert_close(&a, &expected);
+}
+
+#[test]
+fn dtrsv_back_substitution_has_small_residual() {
+    // U = [[2, 1, -1], [0, 3, 2], [0, 0, 4]] column-major, with a junk strictly lower triangle.
+    let u = [2.0, 99.0, 99.0, 1.0, 3.0, 99.0, -1.0, 2.0, 4.0];
+    let b = [3.0, 13.0, 8.0];
+    let mut x = b;
+    unsafe {
+        matrix_f64::tri_solve(
+            CblasOrder::ColMajor,
+            CblasUpLow::Upper,
+            CblasTranspose::NoTrans,
+            CblasDiag::NonUnit,
+            3,
+            u.as_ptr(),
+            3,
+            x.as_mut_ptr(),
+            1,
+        );
+    }
+    assert_close(&x, &[1.0, 3.0, 2.0]);
+    for i in 0..3 {
+        let ux: f64 = (i..3).map(|j| u[j * 3 + i] * x[j]).sum();
+        assert!((ux - b[i]).abs() < 1e-12);
+    }
+}
+
+fn reference_gemm(a: &[f64], b: &[f64], m: usize, n: usize, k: usize) -> Vec<f64> {
+    // Row-major, untransposed operands.
+    let mut c = vec![0.0; m * n];
+    for i in 0..m {
+        for j in 0..n {
+            c[i * n + j] = (0..k).map(|p| a[i * k + p] * b[p * n + j]).sum();
+        }
+    }
+    c
+}
+
+#[test]
+fn dgemm_matches_reference_with_transposed_column_major_a() {
+    let (m, n, k) = (2, 3, 4);
+    let a: Vec<f64> = (0..m * k).map(|v| v as f64 - 3.0).collect();
+    let b: Vec<f64> = (0..k * n).map(|v| (v as f64).sin()).collect();
+    // Column-major k x m storage of A^T puts element (p, i) at p + i * k = a[i * k + p], which is exactly
+    // the row-major buffer of A, so it is passed as is with op(A) = Trans.
+    // Column-major B: element (p, j) at p + j * k.
+    let mut b_col = vec![0.0; k * n];
+    for p in 0..k {
+        for j in 0..n {
+            b_col[p + j * k] = b[p * n + j];
+        }
+    }
+    let mut c = vec![0.0; m * n];
+    matrix_f64::dgemm(
+        CblasOrder::ColMajor,
+        CblasTranspose::Trans,
+        CblasTranspose::NoTrans,
+        m,
+        n,
+        k,
+        1.0,
+        &a,
+        k,
+        &b_col,
+        k,
+        0.0,
+        &mut c,
+        m,
+    )
+    .unwrap();
+    let expected = reference_gemm(&a, &b, m, n, k);
+    for i in 0..m {
+        for j in 0..n {
+            assert!((c[i + j * m] - expected[i * n + j]).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn dgemm_reports_each_short_buffer() {
+    let (a, b, mut c) = ([1.0; 6], [1.0; 6], [0.0; 4]);
+    let call = |a: &[f64], b: &[f64], c: &mut [f64]| {
+        matrix_f64::dgemm(
+            CblasOrder::RowMajor,
+            CblasTranspose::NoTrans,
+            CblasTranspose::NoTrans,
+            2,
+            2,
+            3,
+            1.0,
+            a,
+            3,
+            b,
+            2,
+            0.0,
+            c,
+            2,
+        )
+    };
+    let short = |which, needed, got| Err(BlasError::BufferTooSmall { which, needed, got });
+    assert_eq!(call(&a[..5], &b, &mut c), short("a", 6, 5));
+    assert_eq!(call(&a, &b[..5], &mut c), short("b", 6, 5));
+    assert_eq!(call(&a, &b, &mut c[..3]), short("c", 4, 3));
+    assert_eq!(call(&a, &b, &mut c), Ok(()));
+    assert_eq!(c, [3.0; 4]);
+}
+
+#[test]
+fn covariance_rejects_too_few_samples() {
+    let one_sample = [1.0f64, 2.0];
+    assert_eq!(
+        matrix_f64::covariance(CblasOrder::RowMajor, 0, 2, &[]),
+        Err(BlasError::DimensionMismatch {
+            which: "n_samples",
+            expected: 1,
+            got: 0
+        })
+    );
+    assert!(matrix_f64::covariance(CblasOrder::RowMajor, 1, 2, &one_sample).is_ok());
+    assert_eq!(
+        matrix_f64::covariance_unbiased(CblasOrder::RowMajor, 1, 2, &one_sample),
+        Err(BlasError::DimensionMismatch {
+            which: "n_samples",
+            expected: 2,
+            got: 1
+        })
+    );
+}
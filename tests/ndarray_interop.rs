@@ -0,0 +1,69 @@
+#![cfg(feature = "ndarray")]
+
+use accelerate_general::error::BlasError;
+use accelerate_general::ndarray_interop::{gemm, gemv};
+use ndarray::{s, Array1, Array2, ShapeBuilder};
+use num_complex::Complex;
+
+fn sample(rows: usize, cols: usize) -> Array2<f64> {
+    Array2::from_shape_fn((rows, cols), |(i, j)| (i * cols + j) as f64 * 0.5 - 1.0)
+}
+
+#[test]
+fn gemm_matches_dot_for_c_and_fortran_layouts() {
+    let a = sample(3, 4);
+    let b = sample(4, 2);
+    let expected = a.dot(&b);
+    assert_eq!(gemm(a.view(), b.view()).unwrap(), expected);
+
+    // The same matrices stored in Fortran order.
+    let a_f = Array2::from_shape_vec((3, 4).f(), a.t().iter().copied().collect()).unwrap();
+    let b_f = Array2::from_shape_vec((4, 2).f(), b.t().iter().copied().collect()).unwrap();
+    assert_eq!(a_f, a);
+    assert_eq!(gemm(a_f.view(), b.view()).unwrap(), expected);
+    assert_eq!(gemm(a.view(), b_f.view()).unwrap(), expected);
+    assert_eq!(gemm(b.t(), a.t()).unwrap(), expected.t());
+}
+
+#[test]
+fn gemm_copies_strided_views() {
+    let big = sample(6, 8);
+    let a = big.slice(s![..;2, 1..;2]);
+    let b = big.slice(s![..4;-1, ..3]);
+    assert_eq!(gemm(a, b).unwrap(), a.dot(&b));
+}
+
+#[test]
+fn gemm_f32_and_complex_match_dot() {
+    let a = sample(2, 3).mapv(|v| v as f32);
+    let b = sample(3, 2).mapv(|v| v as f32);
+    assert_eq!(gemm(a.view(), b.view()).unwrap(), a.dot(&b));
+
+    let a = sample(2, 3).mapv(|v| Complex::new(v, 1.0 - v));
+    let b = sample(3, 2).mapv(|v| Complex::new(0.5, v));
+    let got = gemm(a.view(), b.view()).unwrap();
+    for (g, w) in got.iter().zip(a.dot(&b).iter()) {
+        assert!((g - w).norm() < 1e-12);
+    }
+}
+
+#[test]
+fn gemv_matches_dot() {
+    let a = sample(3, 4);
+    let x = Array1::from_iter((0..4).map(|v| v as f64 - 1.5));
+    assert_eq!(gemv(a.view(), x.view()).unwrap(), a.dot(&x));
+    assert_eq!(gemv(a.t(), a.column(1)).unwrap(), a.t().dot(&a.column(1)));
+}
+
+#[test]
+fn mismatched_shapes_are_rejected() {
+    let a = sample(2, 3);
+    assert!(matches!(
+        gemm(a.view(), a.view()),
+        Err(BlasError::DimensionMismatch { which: "b", .. })
+    ));
+    assert!(matches!(
+        gemv(a.view(), a.row(0).slice(s![..2])),
+        Err(BlasError::DimensionMismatch { which: "x", .. })
+    ));
+}
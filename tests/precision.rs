@@ -0,0 +1,162 @@
+use accelerate_general::constants::{CblasOrder, CblasTranspose};
+use accelerate_general::error::BlasError;
+use accelerate_general::precision::{
+    gemm_dynamic, narrow_complex, narrow_matrix, symbol_name, widen_complex, widen_matrix,
+    Precision,
+};
+use num_complex::Complex;
+
+#[test]
+fn symbol_name_uses_blas_prefixes() {
+    assert_eq!(symbol_name("gemm", Precision::F32), Some("cblas_sgemm"));
+    assert_eq!(symbol_name("gemm", Precision::F64), Some("cblas_dgemm"));
+    assert_eq!(symbol_name("gemm", Precision::C32), Some("cblas_cgemm"));
+    assert_eq!(symbol_name("gemm", Precision::C64), Some("cblas_zgemm"));
+}
+
+#[test]
+fn symbol_name_handles_irregular_names() {
+    assert_eq!(symbol_name("nrm2", Precision::C32), Some("cblas_scnrm2"));
+    assert_eq!(symbol_name("asum", Precision::C64), Some("cblas_dzasum"));
+    assert_eq!(symbol_name("iamax", Precision::F64), Some("cblas_idamax"));
+    assert_eq!(symbol_name("dotc", Precision::C32), Some("cblas_cdotc_sub"));
+    assert_eq!(symbol_name("axpby", Precision::F32), Some("catlas_saxpby"));
+}
+
+#[test]
+fn symbol_name_rejects_missing_combinations() {
+    assert_eq!(symbol_name("dot", Precision::C32), None);
+    assert_eq!(symbol_name("hemv", Precision::F64), None);
+    assert_eq!(symbol_name("not_an_op", Precision::F32), None);
+}
+
+fn bytes_of(values: &[f32]) -> &[u8] {
+    unsafe { std::slice::from_raw_parts(values.as_ptr().cast(), std::mem::size_of_val(values)) }
+}
+
+fn bytes_of_mut(values: &mut [f32]) -> &mut [u8] {
+    let len = std::mem::size_of_val(values);
+    unsafe { std::slice::from_raw_parts_mut(values.as_mut_ptr().cast(), len) }
+}
+
+#[test]
+fn gemm_dynamic_runs_f32_from_bytes() {
+    let a = [1.0f32, 2.0, 3.0, 4.0];
+    let b = [5.0f32, 6.0, 7.0, 8.0];
+    let mut c = [0.0f32; 4];
+    gemm_dynamic(
+        Precision::F32,
+        CblasOrder::RowMajor,
+        CblasTranspose::NoTrans,
+        CblasTranspose::NoTrans,
+        2,
+        2,
+        2,
+        Complex::new(1.0, 0.0),
+        bytes_of(&a),
+        2,
+        bytes_of(&b),
+        2,
+        Complex::new(0.0, 0.0),
+        bytes_of_mut(&mut c),
+        2,
+    )
+    .unwrap();
+    assert_eq!(c, [19.0, 22.0, 43.0, 50.0]);
+}
+
+#[test]
+fn gemm_dynamic_rejects_misaligned_and_short_buffers() {
+    let a = [1.0f32; 5];
+    let b = [1.0f32; 4];
+    let mut c = [0.0f32; 4];
+    let err = gemm_dynamic(
+        Precision::F32,
+        CblasOrder::RowMajor,
+        CblasTranspose::NoTrans,
+        CblasTranspose::NoTrans,
+        2,
+        2,
+        2,
+        Complex::new(1.0, 0.0),
+        &bytes_of(&a)[1..17],
+        2,
+        bytes_of(&b),
+        2,
+        Complex::new(0.0, 0.0),
+        bytes_of_mut(&mut c),
+        2,
+    );
+    assert_eq!(
+        err,
+        Err(BlasError::Misaligned {
+            which: "a",
+            align: 4
+        })
+    );
+
+    let err = gemm_dynamic(
+        Precision::F64,
+        CblasOrder::RowMajor,
+        CblasTranspose::NoTrans,
+        CblasTranspose::NoTrans,
+        2,
+        2,
+        2,
+        Complex::new(1.0, 0.0),
+        bytes_of(&a[..4]),
+        2,
+        bytes_of(&b),
+        2,
+        Complex::new(0.0, 0.0),
+        bytes_of_mut(&mut c),
+        2,
+    );
+    assert!(matches!(
+        err,
+        Err(BlasError::Misaligned { .. }) | Err(BlasError::BufferTooSmall { which: "a", .. })
+    ));
+}
+
+#[test]
+fn gemm_dynamic_narrows_complex_scalars_for_c32() {
+    let a = [Complex::new(1.0f32, 1.0)];
+    let b = [Complex::new(2.0f32, 0.0)];
+    let mut c = [Complex::new(1.0f32, 0.0)];
+    unsafe {
+        gemm_dynamic(
+            Precision::C32,
+            CblasOrder::RowMajor,
+            CblasTranspose::NoTrans,
+            CblasTranspose::NoTrans,
+            1,
+            1,
+            1,
+            Complex::new(0.0, 1.0),
+            std::slice::from_raw_parts(a.as_ptr().cast(), 8),
+            1,
+            std::slice::from_raw_parts(b.as_ptr().cast(), 8),
+            1,
+            Complex::new(2.0, 0.0),
+            std::slice::from_raw_parts_mut(c.as_mut_ptr().cast(), 8),
+            1,
+        )
+        .unwrap();
+    }
+    // i * (1 + i) * 2 + 2 * 1 = 2i
+    assert_eq!(c, [Complex::new(0.0, 2.0)]);
+}
+
+#[test]
+fn widen_and_narrow_round_trip_f32_values() {
+    let a = [1.5f32, -0.1, f32::MAX];
+    let wide = widen_matrix(&a);
+    assert_eq!(wide[1], f64::from(-0.1f32));
+    assert_eq!(narrow_matrix(&wide), a);
+    // Narrowing rounds and saturates to infinity.
+    assert_eq!(narrow_matrix(&[0.1, 1e300]), [0.1f32, f32::INFINITY]);
+
+    let z = [Complex::new(0.25f32, -3.0)];
+    assert_eq!(widen_complex(&z), [Complex::new(0.25, -3.0)]);
+    assert_eq!(narrow_complex(&widen_complex(&z)), z);
+}
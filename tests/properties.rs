@@ -0,0 +1,358 @@
+//! Property tests checking the safe wrappers against straightforward Rust reference kernels over random
+//! dimensions, strides (including negative ones), leading-dimension padding and mode flags.
+//!
+//! These call into Accelerate, so they are only built on macOS.
+#![cfg(target_os = "macos")]
+
+use accelerate_general::constants::{CblasDiag, CblasOrder, CblasSide, CblasTranspose, CblasUpLow};
+use accelerate_general::trsm::TrsmBuilder;
+use accelerate_general::{matrix_f64, vector_c32, vector_f64};
+use num_complex::Complex;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+fn order() -> impl Strategy<Value = CblasOrder> {
+    prop_oneof![Just(CblasOrder::RowMajor), Just(CblasOrder::ColMajor)]
+}
+
+fn uplo() -> impl Strategy<Value = CblasUpLow> {
+    prop_oneof![Just(CblasUpLow::Upper), Just(CblasUpLow::Lower)]
+}
+
+fn trans() -> impl Strategy<Value = CblasTranspose> {
+    prop_oneof![Just(CblasTranspose::NoTrans), Just(CblasTranspose::Trans)]
+}
+
+fn diag() -> impl Strategy<Value = CblasDiag> {
+    prop_oneof![Just(CblasDiag::NonUnit), Just(CblasDiag::Unit)]
+}
+
+fn side() -> impl Strategy<Value = CblasSide> {
+    prop_oneof![Just(CblasSide::Left), Just(CblasSide::Right)]
+}
+
+fn stride() -> impl Strategy<Value = i32> {
+    prop_oneof![1..4i32, -3..0i32]
+}
+
+/// A pool of random values; tests draw as many as the generated dimensions need.
+fn pool() -> impl Strategy<Value = Vec<f64>> {
+    vec(-1.0..1.0f64, 256)
+}
+
+/// Buffer position of logical element `i` of an `n`-element strided vector, following the BLAS rule that a
+/// negative increment walks the buffer backwards.
+fn at(n: usize, inc: i32, i: usize) -> usize {
+    let step = inc.unsigned_abs() as usize;
+    if inc > 0 {
+        i * step
+    } else {
+        (n - 1 - i) * step
+    }
+}
+
+/// Buffer position of element `(i, j)` of a dense matrix.
+fn dense(order: CblasOrder, lda: usize, i: usize, j: usize) -> usize {
+    match order {
+        CblasOrder::RowMajor => i * lda + j,
+        CblasOrder::ColMajor => j * lda + i,
+    }
+}
+
+/// A random `n x n` triangular matrix whose diagonal is kept away from zero so solves stay well conditioned.
+fn triangular(n: usize, uplo: CblasUpLow, values: &mut impl Iterator<Item = f64>) -> Vec<Vec<f64>> {
+    let mut a = vec![vec![0.0; n]; n];
+    for (i, row) in a.iter_mut().enumerate() {
+        for (j, x) in row.iter_mut().enumerate() {
+            let inside = match uplo {
+                CblasUpLow::Upper => j >= i,
+                CblasUpLow::Lower => j <= i,
+            };
+            if i == j {
+                *x = 2.0 + values.next().unwrap().abs();
+            } else if inside {
+                *x = 0.5 * values.next().unwrap();
+            }
+        }
+    }
+    a
+}
+
+/// `op(A)[i][j]`, with a unit diagonal substituted when `diag` says so.
+fn op_at(a: &[Vec<f64>], trans: CblasTranspose, diag: CblasDiag, i: usize, j: usize) -> f64 {
+    if i == j {
+        if let CblasDiag::Unit = diag {
+            return 1.0;
+        }
+    }
+    match trans {
+        CblasTranspose::NoTrans => a[i][j],
+        _ => a[j][i],
+    }
+}
+
+fn close(actual: f64, expected: f64, scale: f64) -> bool {
+    (actual - expected).abs() <= 1e-9 * scale.max(1.0)
+}
+
+proptest! {
+    #[test]
+    fn dtbsv_matches_reference(
+        n in 1usize..8,
+        k_seed in 0usize..4,
+        pad in 0usize..3,
+        order in order(),
+        uplo in uplo(),
+        trans in trans(),
+        diag in diag(),
+        inc in stride(),
+        values in pool(),
+    ) {
+        let k = k_seed.min(n - 1);
+        let mut values = values.into_iter().cycle();
+        let mut a = triangular(n, uplo, &mut values);
+        // Drop everything outside the band.
+        for (i, row) in a.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                if i.max(j) - i.min(j) > k {
+                    *x = 0.0;
+                }
+            }
+        }
+        let lda = k + 1 + pad;
+        let mut band = vec![f64::NAN; n * lda];
+        for (i, row) in a.iter().enumerate() {
+            for (j, &v) in row.iter().enumerate() {
+                if v == 0.0 {
+                    continue;
+                }
+                let idx = match (order, uplo) {
+                    (CblasOrder::ColMajor, CblasUpLow::Upper) => k + i - j + j * lda,
+                    (CblasOrder::ColMajor, CblasUpLow::Lower) => i - j + j * lda,
+                    (CblasOrder::RowMajor, CblasUpLow::Upper) => i * lda + j - i,
+                    (CblasOrder::RowMajor, CblasUpLow::Lower) => i * lda + k + j - i,
+                };
+                band[idx] = v;
+            }
+        }
+
+        let expected: Vec<f64> = (0..n).map(|_| values.next().unwrap()).collect();
+        let step = inc.unsigned_abs() as usize;
+        let mut x = vec![0.0; (n - 1) * step + 1];
+        for i in 0..n {
+            x[at(n, inc, i)] = (0..n).map(|j| op_at(&a, trans, diag, i, j) * expected[j]).sum();
+        }
+        matrix_f64::dtbsv(order, uplo, trans, diag, n, k, &band, lda, &mut x, inc).unwrap();
+        for (i, &e) in expected.iter().enumerate() {
+            let got = x[at(n, inc, i)];
+            prop_assert!(close(got, e, 1e3), "x[{}] = {} != {}", i, got, e);
+        }
+    }
+
+    #[test]
+    fn trsm_builder_matches_reference(
+        m in 1usize..6,
+        n in 1usize..6,
+        pad_a in 0usize..3,
+        pad_b in 0usize..3,
+        order in order(),
+        side in side(),
+        uplo in uplo(),
+        trans in trans(),
+        diag in diag(),
+        alpha in prop_oneof![Just(1.0f64), 0.5..2.0f64],
+        values in pool(),
+    ) {
+        let mut values = values.into_iter().cycle();
+        let k = match side {
+            CblasSide::Left => m,
+            CblasSide::Right => n,
+        };
+        let tri = triangular(k, uplo, &mut values);
+        let lda = k + pad_a;
+        let mut a = vec![f64::NAN; k * lda];
+        for i in 0..k {
+            for j in 0..k {
+                a[dense(order, lda, i, j)] = tri[i][j];
+            }
+        }
+
+        let x: Vec<Vec<f64>> = (0..m)
+            .map(|_| (0..n).map(|_| values.next().unwrap()).collect())
+            .collect();
+        let ldb = match order {
+            CblasOrder::RowMajor => n,
+            CblasOrder::ColMajor => m,
+        } + pad_b;
+        let outer = match order {
+            CblasOrder::RowMajor => m,
+            CblasOrder::ColMajor => n,
+        };
+        let mut b = vec![0.0; outer * ldb];
+        for i in 0..m {
+            for j in 0..n {
+                // B = op(A) * X / alpha or X * op(A) / alpha, so that the solve recovers X.
+                let product: f64 = match side {
+                    CblasSide::Left => {
+                        (0..m).map(|p| op_at(&tri, trans, diag, i, p) * x[p][j]).sum()
+                    }
+                    CblasSide::Right => {
+                        (0..n).map(|p| x[i][p] * op_at(&tri, trans, diag, p, j)).sum()
+                    }
+                };
+                b[dense(order, ldb, i, j)] = product / alpha;
+            }
+        }
+
+        TrsmBuilder::new(m, n)
+            .order(order)
+            .side(side)
+            .uplo(uplo)
+            .trans(trans)
+            .diag(diag)
+            .alpha(alpha)
+            .lda(lda)
+            .ldb(ldb)
+            .run(&a, &mut b)
+            .unwrap();
+        for i in 0..m {
+            for j in 0..n {
+                let got = b[dense(order, ldb, i, j)];
+                prop_assert!(close(got, x[i][j], 1e3), "X[{}][{}] = {} != {}", i, j, got, x[i][j]);
+            }
+        }
+    }
+
+    #[test]
+    fn quadratic_form_matches_reference(
+        n in 0usize..8,
+        pad in 0usize..3,
+        order in order(),
+        values in pool(),
+    ) {
+        let mut values = values.into_iter().cycle();
+        let lda = n.max(1) + pad;
+        let a: Vec<f64> = (0..n * lda).map(|_| values.next().unwrap()).collect();
+        let x: Vec<f64> = (0..n).map(|_| values.next().unwrap()).collect();
+        let mut expected = 0.0;
+        for i in 0..n {
+            for j in 0..n {
+                expected += x[i] * a[dense(order, lda, i, j)] * x[j];
+            }
+        }
+        let got = matrix_f64::quadratic_form(order, n, &a, lda, &x).unwrap();
+        prop_assert!(close(got, expected, 10.0), "{} != {}", got, expected);
+    }
+
+    #[test]
+    fn kron_matches_reference(
+        ma in 0usize..4,
+        na in 0usize..4,
+        mb in 0usize..4,
+        nb in 0usize..4,
+        order in order(),
+        values in pool(),
+    ) {
+        let mut values = values.into_iter().cycle();
+        let a: Vec<f64> = (0..ma * na).map(|_| values.next().unwrap()).collect();
+        let b: Vec<f64> = (0..mb * nb).map(|_| values.next().unwrap()).collect();
+        let (lda, ldb, ldk) = match order {
+            CblasOrder::RowMajor => (na, nb, na * nb),
+            CblasOrder::ColMajor => (ma, mb, ma * mb),
+        };
+        let k = matrix_f64::kron(order, &a, (ma, na), &b, (mb, nb)).unwrap();
+        prop_assert_eq!(k.len(), ma * mb * na * nb);
+        for i in 0..ma {
+            for j in 0..na {
+                for p in 0..mb {
+                    for q in 0..nb {
+                        let expected = a[dense(order, lda, i, j)] * b[dense(order, ldb, p, q)];
+                        let got = k[dense(order, ldk, i * mb + p, j * nb + q)];
+                        prop_assert!(close(got, expected, 1.0));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn symmetrize_mirrors_source_triangle(
+        n in 0usize..7,
+        pad in 0usize..3,
+        order in order(),
+        uplo in uplo(),
+        values in pool(),
+    ) {
+        let mut values = values.into_iter().cycle();
+        let lda = n.max(1) + pad;
+        let original: Vec<f64> = (0..n * lda).map(|_| values.next().unwrap()).collect();
+        let mut a = original.clone();
+        matrix_f64::symmetrize(order, uplo, n, &mut a, lda).unwrap();
+        for i in 0..n {
+            for j in 0..n {
+                let source = match uplo {
+                    CblasUpLow::Upper => (i.min(j), i.max(j)),
+                    CblasUpLow::Lower => (i.max(j), i.min(j)),
+                };
+                let expected = original[dense(order, lda, source.0, source.1)];
+                prop_assert_eq!(a[dense(order, lda, i, j)], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn cdot_both_matches_reference(
+        n in 0usize..10,
+        inc_x in stride(),
+        inc_y in stride(),
+        values in pool(),
+    ) {
+        let mut values = values.into_iter().cycle().map(|v| v as f32);
+        let len = |inc: i32| if n == 0 { 0 } else { (n - 1) * inc.unsigned_abs() as usize + 1 };
+        let mut complex = || Complex::new(values.next().unwrap(), values.next().unwrap());
+        let x: Vec<Complex<f32>> = (0..len(inc_x)).map(|_| complex()).collect();
+        let y: Vec<Complex<f32>> = (0..len(inc_y)).map(|_| complex()).collect();
+        let mut dotc = Complex::new(0.0, 0.0);
+        let mut dotu = Complex::new(0.0, 0.0);
+        for i in 0..n {
+            let (xi, yi) = (x[at(n, inc_x, i)], y[at(n, inc_y, i)]);
+            dotc += xi.conj() * yi;
+            dotu += xi * yi;
+        }
+        let (got_c, got_u) = vector_c32::cdot_both(n, &x, inc_x, &y, inc_y).unwrap();
+        prop_assert!((got_c - dotc).norm() < 1e-4, "{} != {}", got_c, dotc);
+        prop_assert!((got_u - dotu).norm() < 1e-4, "{} != {}", got_u, dotu);
+    }
+
+    #[test]
+    fn cosine_similarity_matches_reference(n in 1usize..16, values in pool()) {
+        let x = &values[..n];
+        let y = &values[n..2 * n];
+        let dot: f64 = x.iter().zip(y).map(|(a, b)| a * b).sum();
+        let norm = |v: &[f64]| v.iter().map(|a| a * a).sum::<f64>().sqrt();
+        let expected = dot / (norm(x) * norm(y));
+        let got = vector_f64::cosine_similarity(x, y).unwrap();
+        prop_assert!(close(got, expected, 1.0), "{} != {}", got, expected);
+        prop_assert!((-1.0 - 1e-12..=1.0 + 1e-12).contains(&got));
+    }
+
+    #[test]
+    fn gather_scatter_add_match_reference(
+        len in 1usize..10,
+        indices in vec(0usize..10, 0..12),
+        values in pool(),
+    ) {
+        let indices: Vec<usize> = indices.into_iter().map(|i| i % len).collect();
+        let src = &values[..len];
+        let picked = vector_f64::gather(src, &indices).unwrap();
+        prop_assert_eq!(picked.len(), indices.len());
+        let mut expected = src.to_vec();
+        for (&i, &v) in indices.iter().zip(&picked) {
+            prop_assert_eq!(v, src[i]);
+            expected[i] += v;
+        }
+        let mut dst = src.to_vec();
+        vector_f64::scatter_add(&mut dst, &indices, &picked).unwrap();
+        prop_assert_eq!(dst, expected);
+    }
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "rand")]
+
+use accelerate_general::constants::CblasOrder;
+use accelerate_general::random::{random_matrix, random_spd};
+
+#[test]
+fn random_matrix_is_seeded_and_layout_independent() {
+    let row = random_matrix(3, 4, CblasOrder::RowMajor, 7);
+    assert_eq!(row, random_matrix(3, 4, CblasOrder::RowMajor, 7));
+    assert_ne!(row, random_matrix(3, 4, CblasOrder::RowMajor, 8));
+    assert!(row.iter().all(|v| (-1.0..1.0).contains(v)));
+
+    let col = random_matrix(3, 4, CblasOrder::ColMajor, 7);
+    for i in 0..3 {
+        for j in 0..4 {
+            assert_eq!(row[i * 4 + j], col[j * 3 + i]);
+        }
+    }
+}
+
+#[test]
+fn random_spd_is_symmetric_with_shifted_diagonal() {
+    let n = 5;
+    let a = random_spd(n, CblasOrder::ColMajor, 42);
+    let x = random_matrix(n, n, CblasOrder::ColMajor, 42);
+    for i in 0..n {
+        for j in 0..n {
+            assert_eq!(a[i * n + j], a[j * n + i]);
+        }
+        // Diagonal entry i is n plus the squared norm of column i of X.
+        let norm2: f32 = x[i * n..(i + 1) * n].iter().map(|v| v * v).sum();
+        assert!((a[i * n + i] - (n as f32 + norm2)).abs() < 1e-4);
+    }
+}
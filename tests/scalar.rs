@@ -0,0 +1,167 @@
+use accelerate_general::constants::{CblasOrder, CblasTranspose};
+use accelerate_general::error::BlasError;
+use accelerate_general::scalar::{axpy, dot, gemm, gemv, nrm2, BlasScalar};
+use num_complex::Complex;
+use std::ops::{Add, Mul};
+
+/// Row-major `m x k` times `k x n`, computed naively.
+fn reference<T>(a: &[T], b: &[T], m: usize, n: usize, k: usize) -> Vec<T>
+where
+    T: BlasScalar + Add<Output = T> + Mul<Output = T>,
+{
+    let mut c = vec![T::ZERO; m * n];
+    for i in 0..m {
+        for j in 0..n {
+            for p in 0..k {
+                c[i * n + j] = c[i * n + j] + a[i * k + p] * b[p * n + j];
+            }
+        }
+    }
+    c
+}
+
+#[test]
+fn gemm_f32_matches_reference() {
+    let a: Vec<f32> = (1..=6).map(|v| v as f32).collect();
+    let b: Vec<f32> = (1..=12).map(|v| v as f32 * 0.5).collect();
+    let mut c = vec![0.0f32; 8];
+    gemm::<f32>(
+        CblasOrder::RowMajor,
+        CblasTranspose::NoTrans,
+        CblasTranspose::NoTrans,
+        2,
+        4,
+        3,
+        1.0,
+        &a,
+        3,
+        &b,
+        4,
+        0.0,
+        &mut c,
+        4,
+    )
+    .unwrap();
+    assert_eq!(c, reference(&a, &b, 2, 4, 3));
+}
+
+#[test]
+fn gemm_c64_matches_reference() {
+    let a: Vec<Complex<f64>> = (0..6)
+        .map(|v| Complex::new(v as f64, 1.0 - v as f64))
+        .collect();
+    let b: Vec<Complex<f64>> = (0..6).map(|v| Complex::new(0.5 * v as f64, 2.0)).collect();
+    let mut c = vec![Complex::new(9.0, 9.0); 4];
+    gemm::<Complex<f64>>(
+        CblasOrder::RowMajor,
+        CblasTranspose::NoTrans,
+        CblasTranspose::NoTrans,
+        2,
+        2,
+        3,
+        Complex::ONE,
+        &a,
+        3,
+        &b,
+        2,
+        Complex::ZERO,
+        &mut c,
+        2,
+    )
+    .unwrap();
+    let expected = reference(&a, &b, 2, 2, 3);
+    for (got, want) in c.iter().zip(&expected) {
+        assert!((got - want).norm() < 1e-12, "{got} != {want}");
+    }
+}
+
+#[test]
+fn gemm_rejects_short_buffers() {
+    let a = [1.0f64; 4];
+    let b = [1.0f64; 3];
+    let mut c = [0.0f64; 4];
+    let err = gemm(
+        CblasOrder::RowMajor,
+        CblasTranspose::NoTrans,
+        CblasTranspose::NoTrans,
+        2,
+        2,
+        2,
+        1.0,
+        &a,
+        2,
+        &b,
+        2,
+        0.0,
+        &mut c,
+        2,
+    );
+    assert_eq!(
+        err,
+        Err(BlasError::BufferTooSmall {
+            which: "b",
+            needed: 4,
+            got: 3
+        })
+    );
+}
+
+#[test]
+fn gemv_c32_matches_reference() {
+    let a = [
+        Complex::new(1.0f32, 0.0),
+        Complex::new(0.0, 1.0),
+        Complex::new(2.0, 0.0),
+        Complex::new(1.0, -1.0),
+    ];
+    let x = [Complex::new(1.0f32, 1.0), Complex::new(2.0, 0.0)];
+    let mut y = [Complex::new(0.0f32, 0.0); 2];
+    gemv(
+        CblasOrder::RowMajor,
+        CblasTranspose::NoTrans,
+        2,
+        2,
+        Complex::ONE,
+        &a,
+        2,
+        &x,
+        Complex::ZERO,
+        &mut y,
+    )
+    .unwrap();
+    assert_eq!(y, [Complex::new(1.0, 3.0), Complex::new(4.0, 0.0)]);
+    assert!(matches!(
+        gemv(
+            CblasOrder::RowMajor,
+            CblasTranspose::NoTrans,
+            2,
+            2,
+            Complex::ONE,
+            &a,
+            2,
+            &x[..1],
+            Complex::ZERO,
+            &mut y,
+        ),
+        Err(BlasError::DimensionMismatch { which: "x", .. })
+    ));
+}
+
+#[test]
+fn axpy_dot_and_nrm2_dispatch_by_type() {
+    let x = [3.0f64, 4.0];
+    let mut y = [1.0f64, 1.0];
+    axpy(2, 2.0, &x, 1, &mut y, 1).unwrap();
+    assert_eq!(y, [7.0, 9.0]);
+    assert_eq!(dot(&x, &y).unwrap(), 57.0);
+    assert_eq!(nrm2(&x).unwrap(), 5.0);
+
+    // The complex dot conjugates the first vector.
+    let z = [Complex::new(0.0f32, 1.0), Complex::new(1.0, 0.0)];
+    assert_eq!(dot(&z, &z).unwrap(), Complex::new(2.0, 0.0));
+    assert!((nrm2(&z).unwrap() - 2.0f32.sqrt()).abs() < 1e-6);
+    assert_eq!(
+        axpy(2, Complex::ONE, &z, 0, &mut [Complex::ZERO; 2], 1),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+}
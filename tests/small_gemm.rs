@@ -0,0 +1,70 @@
+use accelerate_general::constants::{CblasOrder, CblasTranspose};
+use accelerate_general::error::BlasError;
+use accelerate_general::matrix_f32;
+use accelerate_general::small_gemm::{sgemm_small, small_gemm_threshold};
+
+#[test]
+fn threshold_is_a_candidate_size_and_cached() {
+    let threshold = small_gemm_threshold();
+    assert!([0, 2, 4, 8, 16, 32, 64].contains(&threshold));
+    assert_eq!(small_gemm_threshold(), threshold);
+}
+
+#[test]
+fn sgemm_small_agrees_with_sgemm_on_both_sides_of_the_threshold() {
+    // The threshold depends on the machine, so sweep shapes below and above every candidate size.
+    let threshold = small_gemm_threshold();
+    for dims in [
+        (1, 1, 1),
+        (3, 2, 4),
+        (8, 8, 8),
+        (17, 5, 9),
+        (64, 64, 64),
+        (65, 3, 70),
+    ] {
+        let (m, n, k) = dims;
+        let a: Vec<f32> = (0..m * k).map(|i| (i % 7) as f32 - 3.0).collect();
+        let b: Vec<f32> = (0..k * n).map(|i| (i % 5) as f32 * 0.5 - 1.0).collect();
+        let mut small = vec![f32::NAN; m * n];
+        sgemm_small(&a, &b, &mut small, dims).unwrap();
+        let mut reference = vec![0.0; m * n];
+        matrix_f32::sgemm(
+            CblasOrder::RowMajor,
+            CblasTranspose::NoTrans,
+            CblasTranspose::NoTrans,
+            m,
+            n,
+            k,
+            1.0,
+            &a,
+            k,
+            &b,
+            n,
+            0.0,
+            &mut reference,
+            n,
+        )
+        .unwrap();
+        for (x, y) in small.iter().zip(&reference) {
+            assert!(
+                (x - y).abs() <= 1e-4 * y.abs().max(1.0),
+                "{:?} (threshold {})",
+                dims,
+                threshold
+            );
+        }
+    }
+}
+
+#[test]
+fn sgemm_small_rejects_mismatched_buffers() {
+    let mut c = [0.0f32; 4];
+    assert_eq!(
+        sgemm_small(&[1.0; 5], &[1.0; 6], &mut c, (2, 2, 3)),
+        Err(BlasError::DimensionMismatch {
+            which: "a",
+            expected: 6,
+            got: 5
+        })
+    );
+}
@@ -0,0 +1,157 @@
+use accelerate_general::constants::{CblasDiag, CblasOrder, CblasTranspose, CblasUpLow};
+use accelerate_general::error::BlasError;
+use accelerate_general::square::SquareMatrix;
+use accelerate_general::view::MatrixView;
+use num_complex::Complex;
+use std::convert::TryFrom;
+
+#[test]
+fn new_validates_lda_and_length() {
+    let a = [0.0f32; 5];
+    assert!(SquareMatrix::new(&a, 2, 3, CblasOrder::RowMajor).is_ok());
+    assert_eq!(
+        SquareMatrix::new(&a, 2, 1, CblasOrder::RowMajor).err(),
+        Some(BlasError::InvalidLeadingDimension {
+            which: "lda",
+            min: 2,
+            got: 1
+        })
+    );
+    assert_eq!(
+        SquareMatrix::new(&a, 3, 3, CblasOrder::RowMajor).err(),
+        Some(BlasError::BufferTooSmall {
+            which: "data",
+            needed: 9,
+            got: 5
+        })
+    );
+}
+
+#[test]
+fn try_from_view_rejects_rectangular() {
+    let data = [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let rect = MatrixView::new(&data, 2, 3, 3, CblasOrder::RowMajor).unwrap();
+    assert_eq!(
+        SquareMatrix::try_from(rect).err(),
+        Some(BlasError::DimensionMismatch {
+            which: "cols",
+            expected: 2,
+            got: 3
+        })
+    );
+
+    let square = MatrixView::new(&data, 2, 2, 3, CblasOrder::RowMajor).unwrap();
+    let square = SquareMatrix::try_from(square).unwrap();
+    assert_eq!(square.n(), 2);
+    assert_eq!(square.lda(), 3);
+    let back = MatrixView::from(square);
+    assert_eq!(back.get(1, 1), Some(&5.0));
+}
+
+#[test]
+fn trmv_and_trsv_round_trip() {
+    // Upper triangle of [[2, 1], [0, 4]] row-major; the lower entry is ignored.
+    let a = [2.0f32, 1.0, 99.0, 4.0];
+    let a = SquareMatrix::new(&a, 2, 2, CblasOrder::RowMajor).unwrap();
+    let mut x = [1.0f32, 2.0];
+    a.trmv(
+        CblasUpLow::Upper,
+        CblasTranspose::NoTrans,
+        CblasDiag::NonUnit,
+        &mut x,
+        1,
+    )
+    .unwrap();
+    assert_eq!(x, [4.0, 8.0]);
+    a.trsv(
+        CblasUpLow::Upper,
+        CblasTranspose::NoTrans,
+        CblasDiag::NonUnit,
+        &mut x,
+        1,
+    )
+    .unwrap();
+    assert_eq!(x, [1.0, 2.0]);
+    assert_eq!(
+        a.trsv(
+            CblasUpLow::Upper,
+            CblasTranspose::NoTrans,
+            CblasDiag::NonUnit,
+            &mut x,
+            2
+        ),
+        Err(BlasError::BufferTooSmall {
+            which: "x",
+            needed: 3,
+            got: 2
+        })
+    );
+}
+
+#[test]
+fn symv_reads_one_triangle() {
+    // Lower triangle of [[1, 2], [2, 3]] column-major with a junk upper entry.
+    let a = [1.0f64, 2.0, -7.0, 3.0];
+    let a = SquareMatrix::new(&a, 2, 2, CblasOrder::ColMajor).unwrap();
+    assert_eq!(a.symv(CblasUpLow::Lower, &[1.0, 1.0]).unwrap(), [3.0, 5.0]);
+
+    assert_eq!(a.hemv(CblasUpLow::Lower, &[1.0, 1.0]).unwrap(), [3.0, 5.0]);
+}
+
+#[test]
+fn complex_symv_mirrors_without_conjugating_and_hemv_conjugates() {
+    // Upper triangle [[1, -i], [_, 1]] with a junk lower entry. Read as symmetric the lower entry is -i,
+    // read as Hermitian it is i.
+    let i = Complex::new(0.0f32, 1.0);
+    let one = Complex::new(1.0f32, 0.0);
+    let h = [one, -i, Complex::new(5.0, 5.0), one];
+    let h = SquareMatrix::new(&h, 2, 2, CblasOrder::RowMajor).unwrap();
+    assert_eq!(
+        h.hemv(CblasUpLow::Upper, &[one, one]).unwrap(),
+        [one - i, one + i]
+    );
+    assert_eq!(
+        h.symv(CblasUpLow::Upper, &[one, one]).unwrap(),
+        [one - i, one - i]
+    );
+
+    // The same matrix column-major, from its lower triangle, in double precision.
+    let i = Complex::new(0.0f64, 1.0);
+    let one = Complex::new(1.0f64, 0.0);
+    let zero = Complex::new(0.0f64, 0.0);
+    let s = [one, -i, Complex::new(5.0, 5.0), one];
+    let s = SquareMatrix::new(&s, 2, 2, CblasOrder::ColMajor).unwrap();
+    assert_eq!(
+        s.symv(CblasUpLow::Lower, &[one, i]).unwrap(),
+        [one + one, zero]
+    );
+    assert_eq!(s.hemv(CblasUpLow::Lower, &[one, i]).unwrap(), [zero, zero]);
+}
+
+#[test]
+fn trmv_and_trsv_reject_zero_increment() {
+    let a = [1.0f32, 0.0, 0.0, 1.0];
+    let m = SquareMatrix::new(&a, 2, 2, CblasOrder::RowMajor).unwrap();
+    let mut x = [1.0f32; 2];
+    let zero = Err(BlasError::ZeroIncrement { which: "inc_x" });
+    assert_eq!(
+        m.trmv(
+            CblasUpLow::Upper,
+            CblasTranspose::NoTrans,
+            CblasDiag::NonUnit,
+            &mut x,
+            0
+        ),
+        zero
+    );
+    assert_eq!(
+        m.trsv(
+            CblasUpLow::Upper,
+            CblasTranspose::NoTrans,
+            CblasDiag::NonUnit,
+            &mut x,
+            0
+        ),
+        zero
+    );
+}
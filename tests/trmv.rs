@@ -0,0 +1,159 @@
+//! Exhaustive flag coverage for `cblas_?trmv`: every order, triangle, transpose mode and diagonal kind,
+//! checked against a reference kernel in all four precisions.
+
+use accelerate_general::constants::{CblasDiag, CblasOrder, CblasTranspose, CblasUpLow};
+use accelerate_general::{matrix_c32, matrix_c64, matrix_f32, matrix_f64};
+use num_complex::Complex;
+use std::ops::{Add, Mul};
+
+const N: usize = 3;
+/// Padded so every case also checks that `lda` is honoured.
+const LDA: usize = 4;
+
+const ORDERS: [CblasOrder; 2] = [CblasOrder::RowMajor, CblasOrder::ColMajor];
+const TRIS: [CblasUpLow; 2] = [CblasUpLow::Upper, CblasUpLow::Lower];
+const TRANSES: [CblasTranspose; 3] = [
+    CblasTranspose::NoTrans,
+    CblasTranspose::Trans,
+    CblasTranspose::ConjTrans,
+];
+const DIAGS: [CblasDiag; 2] = [CblasDiag::NonUnit, CblasDiag::Unit];
+
+/// Computes `op(A) * x` for the triangle of the dense `N x N` matrix `full` (row-major, unpadded).
+#[allow(clippy::too_many_arguments)]
+fn reference<T>(
+    full: &[T],
+    tri: CblasUpLow,
+    trans: CblasTranspose,
+    diag: CblasDiag,
+    x: &[T],
+    zero: T,
+    one: T,
+    conj: fn(T) -> T,
+) -> Vec<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    let effective = |i: usize, j: usize| {
+        let referenced = match tri {
+            CblasUpLow::Upper => i <= j,
+            CblasUpLow::Lower => i >= j,
+        };
+        if !referenced {
+            zero
+        } else if i == j && matches!(diag, CblasDiag::Unit) {
+            one
+        } else {
+            full[i * N + j]
+        }
+    };
+    (0..N)
+        .map(|i| {
+            (0..N).fold(zero, |acc, j| {
+                let op = match trans {
+                    CblasTranspose::NoTrans => effective(i, j),
+                    CblasTranspose::Trans => effective(j, i),
+                    _ => conj(effective(j, i)),
+                };
+                acc + op * x[j]
+            })
+        })
+        .collect()
+}
+
+/// Lays out the dense `N x N` matrix `full` in `order` with leading dimension `LDA`, padding with `pad`.
+fn store<T: Copy>(full: &[T], order: CblasOrder, pad: T) -> Vec<T> {
+    let mut a = vec![pad; LDA * N];
+    for i in 0..N {
+        for j in 0..N {
+            match order {
+                CblasOrder::RowMajor => a[i * LDA + j] = full[i * N + j],
+                CblasOrder::ColMajor => a[j * LDA + i] = full[i * N + j],
+            }
+        }
+    }
+    a
+}
+
+macro_rules! trmv_flag_matrix {
+    ($name:ident, $module:ident, $t:ty, $value:expr, $zero:expr, $one:expr, $conj:expr) => {
+        #[test]
+        fn $name() {
+            let value: fn(usize) -> $t = $value;
+            // Every entry is non-zero, including the unreferenced triangle and the diagonal, so reading
+            // anything the flags exclude shows up in the result.
+            let full: Vec<$t> = (0..N * N).map(|k| value(k + 2)).collect();
+            let x: Vec<$t> = (0..N).map(|k| value(k + 1)).collect();
+            let mut cases = 0;
+            for &order in ORDERS.iter() {
+                for &tri in TRIS.iter() {
+                    for &trans in TRANSES.iter() {
+                        for &diag in DIAGS.iter() {
+                            let a = store(&full, order, value(99));
+                            let mut y = x.clone();
+                            unsafe {
+                                $module::tri_mat_vec_mul(
+                                    order,
+                                    tri,
+                                    trans,
+                                    diag,
+                                    N as i32,
+                                    a.as_ptr(),
+                                    LDA as i32,
+                                    y.as_mut_ptr(),
+                                    1,
+                                );
+                            }
+                            let expected =
+                                reference(&full, tri, trans, diag, &x, $zero, $one, $conj);
+                            assert_eq!(
+                                y, expected,
+                                "order {} tri {} trans {} diag {}",
+                                order as i32, tri as i32, trans as i32, diag as i32
+                            );
+                            cases += 1;
+                        }
+                    }
+                }
+            }
+            assert_eq!(cases, 24);
+        }
+    };
+}
+
+trmv_flag_matrix!(
+    strmv_all_flag_combinations,
+    matrix_f32,
+    f32,
+    |k| k as f32,
+    0.0,
+    1.0,
+    |v| v
+);
+trmv_flag_matrix!(
+    dtrmv_all_flag_combinations,
+    matrix_f64,
+    f64,
+    |k| k as f64,
+    0.0,
+    1.0,
+    |v| v
+);
+trmv_flag_matrix!(
+    ctrmv_all_flag_combinations,
+    matrix_c32,
+    Complex<f32>,
+    |k| Complex::new(k as f32, (k % 3) as f32 - 1.0),
+    Complex::new(0.0, 0.0),
+    Complex::new(1.0, 0.0),
+    |v| v.conj()
+);
+trmv_flag_matrix!(
+    ztrmv_all_flag_combinations,
+    matrix_c64,
+    Complex<f64>,
+    |k| Complex::new(k as f64, (k % 3) as f64 - 1.0),
+    Complex::new(0.0, 0.0),
+    Complex::new(1.0, 0.0),
+    |v| v.conj()
+);
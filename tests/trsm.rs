@@ -0,0 +1,78 @@
+use accelerate_general::constants::{CblasOrder, CblasSide, CblasTranspose, CblasUpLow};
+use accelerate_general::error::BlasError;
+use accelerate_general::trsm::TrsmBuilder;
+use num_complex::Complex;
+
+fn assert_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-12, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn defaults_solve_upper_left_system() {
+    // U = [[2, 1], [0, 4]], B = U * [[1, 2], [3, 4]]
+    let u = [2.0, 1.0, 0.0, 4.0];
+    let mut b = [5.0, 8.0, 12.0, 16.0];
+    TrsmBuilder::new(2, 2).run(&u, &mut b).unwrap();
+    assert_close(&b, &[1.0, 2.0, 3.0, 4.0]);
+}
+
+#[test]
+fn overrides_lower_right_transposed_with_alpha() {
+    // X * L^T = 2 * B with L = [[2, 0], [1, 4]] (column-major), X = [[1, 2, 3]]^T as a 3 x 2 matrix.
+    let l = [2.0, 1.0, 0.0, 4.0];
+    let x = [1.0, 2.0, 3.0, 1.0, 1.0, 1.0];
+    // (X * L^T) / 2, column-major 3 x 2: column 0 = 2 * x0 / 2, column 1 = (x0 + 4 * x1) / 2
+    let mut b = [1.0, 2.0, 3.0, 2.5, 3.0, 3.5];
+    TrsmBuilder::new(3, 2)
+        .order(CblasOrder::ColMajor)
+        .side(CblasSide::Right)
+        .uplo(CblasUpLow::Lower)
+        .trans(CblasTranspose::Trans)
+        .alpha(2.0)
+        .run(&l, &mut b)
+        .unwrap();
+    assert_close(&b, &x);
+}
+
+#[test]
+fn dispatches_complex_precision() {
+    let a = [Complex::new(0.0, 1.0)];
+    let mut b = [Complex::new(2.0, 0.0), Complex::new(0.0, 3.0)];
+    TrsmBuilder::new(1, 2).run(&a, &mut b).unwrap();
+    assert_eq!(b, [Complex::new(0.0, -2.0), Complex::new(3.0, 0.0)]);
+}
+
+#[test]
+fn complex_alpha_from_temporary_reaches_the_solve() {
+    // The builder stores alpha by value, so a scalar built from a temporary that is gone before
+    // `run` must still be the one the solve sees.
+    let builder = TrsmBuilder::new(1, 2).alpha(Complex::new(0.0, 2.0));
+    let a = [Complex::new(1.0, 0.0)];
+    let mut b = [Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+    builder.run(&a, &mut b).unwrap();
+    assert_eq!(b, [Complex::new(0.0, 2.0), Complex::new(-2.0, 0.0)]);
+}
+
+#[test]
+fn rejects_short_buffers_and_leading_dimensions() {
+    let mut b = [0.0f32; 4];
+    assert_eq!(
+        TrsmBuilder::new(2, 2).run(&[1.0, 0.0, 1.0], &mut b),
+        Err(BlasError::BufferTooSmall {
+            which: "a",
+            needed: 4,
+            got: 3
+        })
+    );
+    assert_eq!(
+        TrsmBuilder::new(2, 2).ldb(1).run(&[1.0; 4], &mut b),
+        Err(BlasError::InvalidLeadingDimension {
+            which: "ldb",
+            min: 2,
+            got: 1
+        })
+    );
+}
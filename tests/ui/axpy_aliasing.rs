@@ -0,0 +1,7 @@
+use accelerate_general::vector_f32;
+
+fn main() {
+    let mut v = vec![1.0f32; 4];
+    // `x` and `y` may not alias: BLAS forbids it and the borrow checker enforces it.
+    vector_f32::axpy(4, 2.0, &v, 1, &mut v, 1).unwrap();
+}
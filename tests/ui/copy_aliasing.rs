@@ -0,0 +1,6 @@
+use accelerate_general::vector_f64;
+
+fn main() {
+    let mut v = vec![1.0f64; 4];
+    vector_f64::copy_into(2, &v[..2], 1, &mut v[2..], 1).unwrap();
+}
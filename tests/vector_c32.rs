@@ -0,0 +1,225 @@
+use accelerate_general::error::BlasError;
+use accelerate_general::vector_c32;
+use num_complex::Complex;
+
+#[test]
+fn cdot_both_returns_conjugated_and_unconjugated_products() {
+    let x = [Complex::new(1.0, 2.0), Complex::new(3.0, -1.0)];
+    let y = [Complex::new(2.0, 1.0), Complex::new(0.0, 1.0)];
+    let (dotc, dotu) = vector_c32::cdot_both(2, &x, 1, &y, 1).unwrap();
+    // conj(1+2i)(2+i) + conj(3-i)(i) = (4-3i) + (-1+3i)
+    assert_eq!(dotc, Complex::new(3.0, 0.0));
+    // (1+2i)(2+i) + (3-i)(i) = (0+5i) + (1+3i)
+    assert_eq!(dotu, Complex::new(1.0, 8.0));
+}
+
+#[test]
+fn cdot_both_honours_increments() {
+    let x = [
+        Complex::new(1.0, 0.0),
+        Complex::new(9.0, 9.0),
+        Complex::new(0.0, 1.0),
+    ];
+    let y = [Complex::new(2.0, 0.0), Complex::new(0.0, 2.0)];
+    let (dotc, dotu) = vector_c32::cdot_both(2, &x, 2, &y, 1).unwrap();
+    assert_eq!(dotc, Complex::new(4.0, 0.0));
+    assert_eq!(dotu, Complex::new(0.0, 0.0));
+    assert_eq!(
+        vector_c32::cdot_both(2, &x, 3, &y, 1),
+        Err(BlasError::BufferTooSmall {
+            which: "x",
+            needed: 4,
+            got: 3
+        })
+    );
+}
+
+#[test]
+fn csscal_doubles_real_and_imaginary_parts() {
+    let mut x = [Complex::new(1.0, -2.0), Complex::new(0.5, 3.0)];
+    vector_c32::csscal(2, 2.0, &mut x, 1).unwrap();
+    assert_eq!(x, [Complex::new(2.0, -4.0), Complex::new(1.0, 6.0)]);
+}
+
+#[test]
+fn csscal_skips_elements_between_increments() {
+    let mut x = [Complex::new(1.0, 1.0); 3];
+    vector_c32::csscal(2, -1.0, &mut x, 2).unwrap();
+    assert_eq!(
+        x,
+        [
+            Complex::new(-1.0, -1.0),
+            Complex::new(1.0, 1.0),
+            Complex::new(-1.0, -1.0)
+        ]
+    );
+}
+
+#[test]
+fn icamax_magnitude_differs_from_blas_modulus() {
+    let x = [Complex::new(5.0, 0.0), Complex::new(3.0, 3.0)];
+    assert_eq!(vector_c32::icamax_magnitude(&x, 1), Some(0));
+    let blas = unsafe { vector_c32::argmax_mod(2, x.as_ptr(), 1) };
+    assert_eq!(blas, 1);
+}
+
+#[test]
+fn icamax_magnitude_steps_by_increment() {
+    let x = [
+        Complex::new(1.0, 0.0),
+        Complex::new(9.0, 9.0),
+        Complex::new(0.0, -2.0),
+    ];
+    assert_eq!(vector_c32::icamax_magnitude(&x, 2), Some(1));
+    assert_eq!(vector_c32::icamax_magnitude(&[], 1), None);
+    assert_eq!(vector_c32::icamax_magnitude(&x, 0), None);
+}
+
+#[test]
+fn ema_update_uses_real_weight() {
+    let mut avg = [Complex::new(2.0, -2.0)];
+    vector_c32::ema_update(0.5, &[Complex::new(0.0, 4.0)], &mut avg).unwrap();
+    assert_eq!(avg, [Complex::new(1.0, 1.0)]);
+}
+
+#[test]
+fn cdotc_real_takes_real_part_of_conjugated_product() {
+    let x = [Complex::new(1.0, 2.0), Complex::new(0.0, 1.0)];
+    let y = [Complex::new(3.0, -1.0), Complex::new(2.0, 2.0)];
+    // conj(x) . y = (1 - 2i)(3 - i) + (-i)(2 + 2i) = (1 - 7i) + (2 - 2i) = 3 - 9i
+    assert_eq!(vector_c32::cdotc_real(&x, &y), Ok(3.0));
+    assert_eq!(
+        vector_c32::cdotc_real(&x, &y[..1]),
+        Err(BlasError::DimensionMismatch {
+            which: "y",
+            expected: 2,
+            got: 1
+        })
+    );
+}
+
+#[test]
+fn precondition_diagonal_scales_each_component() {
+    let mut x = [Complex::new(1.0, -2.0), Complex::new(4.0, 0.5)];
+    vector_c32::precondition_diagonal(&[0.5, -2.0], &mut x).unwrap();
+    assert_eq!(x, [Complex::new(0.5, -1.0), Complex::new(-8.0, -1.0)]);
+    assert_eq!(
+        vector_c32::precondition_diagonal(&[1.0], &mut x),
+        Err(BlasError::DimensionMismatch {
+            which: "d",
+            expected: 2,
+            got: 1
+        })
+    );
+}
+
+#[test]
+fn strided_wrappers_reject_zero_increment() {
+    let x = [Complex::new(1.0f32, 0.0); 2];
+    let mut y = [Complex::new(1.0f32, 0.0); 2];
+    assert_eq!(
+        vector_c32::axpy(2, Complex::new(1.0f32, 0.0), &x, 0, &mut y, 1),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+    assert_eq!(
+        vector_c32::copy_into(2, &x, 1, &mut y, 0),
+        Err(BlasError::ZeroIncrement { which: "inc_y" })
+    );
+    assert_eq!(
+        vector_c32::cdot_both(2, &x, 0, &y, 1),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+    assert_eq!(
+        vector_c32::csscal(2, 2.0, &mut y, 0),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+}
+
+#[test]
+fn argmax_is_zero_based_and_prefers_first_tie() {
+    let empty: [Complex<f32>; 0] = [];
+    assert_eq!(vector_c32::argmax(&empty), None);
+    assert_eq!(vector_c32::argmax(&[Complex::new(0.0, -7.5)]), Some(0));
+    assert_eq!(
+        vector_c32::argmax(&[
+            Complex::new(1.0, 0.0),
+            Complex::new(-2.0, 1.0),
+            Complex::new(0.0, 3.0),
+            Complex::new(1.0, 1.0)
+        ]),
+        Some(1)
+    );
+}
+
+#[test]
+fn caxpy_updates_in_place_and_rejects_unequal_lengths() {
+    let x = [Complex::new(1.0, 1.0), Complex::new(0.0, -2.0)];
+    let mut y = [Complex::new(1.0, 0.0); 2];
+    // alpha = i: i * (1 + i) = -1 + i, i * (-2i) = 2.
+    vector_c32::caxpy(Complex::new(0.0, 1.0), &x, &mut y).unwrap();
+    assert_eq!(y, [Complex::new(0.0, 1.0), Complex::new(3.0, 0.0)]);
+
+    assert!(matches!(
+        vector_c32::caxpy(Complex::new(1.0, 0.0), &x, &mut y[..1]),
+        Err(BlasError::DimensionMismatch { which: "y", .. })
+    ));
+}
+
+#[test]
+fn scnrm2_of_3_4_is_5_and_of_empty_is_0() {
+    assert_eq!(
+        vector_c32::scnrm2(&[Complex::new(3.0f32, 4.0)]).unwrap(),
+        5.0
+    );
+    assert_eq!(vector_c32::scnrm2(&[]).unwrap(), 0.0);
+    assert_eq!(
+        vector_c32::scnrm2_strided(
+            2,
+            &[
+                Complex::new(0.0f32, 3.0),
+                Complex::new(9.0, 9.0),
+                Complex::new(4.0, 0.0)
+            ],
+            2
+        )
+        .unwrap(),
+        5.0
+    );
+    assert_eq!(
+        vector_c32::scnrm2_strided(
+            2,
+            &[
+                Complex::new(0.0f32, 3.0),
+                Complex::new(9.0, 9.0),
+                Complex::new(4.0, 0.0)
+            ],
+            0
+        ),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+}
+
+#[test]
+fn cscal_and_cscal_real_match_a_manual_loop() {
+    let x = [
+        Complex::new(1.0, 2.0),
+        Complex::new(-0.5, 0.0),
+        Complex::new(0.0, -4.0),
+    ];
+    for alpha in [
+        Complex::new(0.0, 0.0),
+        Complex::new(-2.0, 0.0),
+        Complex::new(0.5, -1.0),
+    ] {
+        let mut y = x;
+        vector_c32::cscal(alpha, &mut y).unwrap();
+        let expected: Vec<Complex<f32>> = x.iter().map(|v| alpha * v).collect();
+        assert_eq!(y.to_vec(), expected);
+    }
+    for alpha in [0.0, -2.0] {
+        let mut y = x;
+        vector_c32::cscal_real(alpha, &mut y).unwrap();
+        let expected: Vec<Complex<f32>> = x.iter().map(|v| v * alpha).collect();
+        assert_eq!(y.to_vec(), expected);
+    }
+}
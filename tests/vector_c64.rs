@@ -0,0 +1,282 @@
+use accelerate_general::error::BlasError;
+use accelerate_general::vector_c64;
+use num_complex::Complex;
+
+#[test]
+fn gather_then_scatter_add_round_trips() {
+    let src = [
+        Complex::new(1.0, 1.0),
+        Complex::new(2.0, -1.0),
+        Complex::new(0.0, 3.0),
+    ];
+    let picked = vector_c64::gather(&src, &[2, 1]).unwrap();
+    assert_eq!(
+        picked,
+        vec![Complex::new(0.0, 3.0), Complex::new(2.0, -1.0)]
+    );
+
+    let mut dst = [Complex::new(0.0, 0.0); 3];
+    vector_c64::scatter_add(&mut dst, &[2, 1], &picked).unwrap();
+    assert_eq!(
+        dst,
+        [
+            Complex::new(0.0, 0.0),
+            Complex::new(2.0, -1.0),
+            Complex::new(0.0, 3.0)
+        ]
+    );
+}
+
+#[test]
+fn linear_combination_uses_complex_coefficients() {
+    let i = Complex::new(0.0, 1.0);
+    let x = [Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+    let y = [Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)];
+    let out = vector_c64::linear_combination(&[i, Complex::new(1.0, 0.0)], &[&x, &y]).unwrap();
+    assert_eq!(out, vec![Complex::new(1.0, 2.0), Complex::new(1.0, 0.0)]);
+}
+
+#[test]
+fn axpy_multiplies_by_complex_alpha() {
+    let x = [Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+    let mut y = [Complex::new(1.0, 1.0); 2];
+    vector_c64::axpy(2, Complex::new(0.0, 1.0), &x, 1, &mut y, 1).unwrap();
+    assert_eq!(y, [Complex::new(1.0, 2.0), Complex::new(0.0, 1.0)]);
+
+    let mut z = [Complex::new(0.0, 0.0); 2];
+    vector_c64::copy_into(2, &y, -1, &mut z, 1).unwrap();
+    assert_eq!(z, [y[1], y[0]]);
+}
+
+#[test]
+fn izamax_nan_aware_uses_modulus_and_nan_policy() {
+    let x = [Complex::new(5.0, 0.0), Complex::new(3.0, -3.0)];
+    assert_eq!(vector_c64::izamax_nan_aware(&x, 1), Some(1));
+    let x = [Complex::new(5.0, 0.0), Complex::new(1.0, f64::NAN)];
+    assert_eq!(vector_c64::izamax_nan_aware(&x, 1), Some(1));
+}
+
+#[test]
+fn count_subnormals_counts_each_component() {
+    let tiny = f64::MIN_POSITIVE / 2.0;
+    let x = [
+        Complex::new(tiny, tiny),
+        Complex::new(1.0, tiny),
+        Complex::new(0.0, 1.0),
+    ];
+    assert_eq!(vector_c64::count_subnormals(&x), 3);
+}
+
+#[test]
+fn zdotc_real_of_self_is_squared_norm() {
+    let x = [Complex::new(3.0, 4.0), Complex::new(0.0, -1.0)];
+    assert_eq!(vector_c64::zdotc_real(&x, &x), Ok(26.0));
+}
+
+#[test]
+fn zdotu_and_zdotc_differ_by_conjugation() {
+    // x is strided (every other element), y contiguous.
+    let x = [
+        Complex::new(1.0, 2.0),
+        Complex::new(99.0, 99.0),
+        Complex::new(0.0, 1.0),
+    ];
+    let y = [Complex::new(3.0, -1.0), Complex::new(2.0, 2.0)];
+    // x . y = (1 + 2i)(3 - i) + i(2 + 2i) = (5 + 5i) + (-2 + 2i) = 3 + 7i
+    assert_eq!(
+        vector_c64::zdotu(2, &x, 2, &y, 1),
+        Ok(Complex::new(3.0, 7.0))
+    );
+    // conj(x) . y = (1 - 2i)(3 - i) + (-i)(2 + 2i) = (1 - 7i) + (2 - 2i) = 3 - 9i
+    assert_eq!(
+        vector_c64::zdotc(2, &x, 2, &y, 1),
+        Ok(Complex::new(3.0, -9.0))
+    );
+    assert_eq!(
+        vector_c64::zdotu(3, &x, 2, &y, 1),
+        Err(BlasError::BufferTooSmall {
+            which: "x",
+            needed: 5,
+            got: 3
+        })
+    );
+}
+
+#[test]
+fn precondition_diagonal_scales_each_component() {
+    let mut x = [Complex::new(1.0, -2.0), Complex::new(4.0, 0.5)];
+    vector_c64::precondition_diagonal(&[0.5, -2.0], &mut x).unwrap();
+    assert_eq!(x, [Complex::new(0.5, -1.0), Complex::new(-8.0, -1.0)]);
+    assert_eq!(
+        vector_c64::precondition_diagonal(&[1.0], &mut x),
+        Err(BlasError::DimensionMismatch {
+            which: "d",
+            expected: 2,
+            got: 1
+        })
+    );
+}
+
+#[test]
+fn strided_wrappers_reject_zero_increment() {
+    let x = [Complex::new(1.0f64, 0.0); 2];
+    let mut y = [Complex::new(1.0f64, 0.0); 2];
+    assert_eq!(
+        vector_c64::axpy(2, Complex::new(1.0f64, 0.0), &x, 0, &mut y, 1),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+    assert_eq!(
+        vector_c64::copy_into(2, &x, 1, &mut y, 0),
+        Err(BlasError::ZeroIncrement { which: "inc_y" })
+    );
+    assert_eq!(
+        vector_c64::zdotu(2, &x, 1, &y, 0),
+        Err(BlasError::ZeroIncrement { which: "inc_y" })
+    );
+    assert_eq!(
+        vector_c64::zdotc(2, &x, 1, &y, 0),
+        Err(BlasError::ZeroIncrement { which: "inc_y" })
+    );
+}
+
+#[test]
+fn zdscal_scales_both_parts_by_a_real_scalar() {
+    let mut x = [Complex::new(1.0, -2.0), Complex::new(0.5, 4.0)];
+    unsafe { vector_c64::scale_by_f64(2, -2.0, x.as_mut_ptr(), 1) };
+    assert_eq!(x, [Complex::new(-2.0, 4.0), Complex::new(-1.0, -8.0)]);
+}
+
+#[test]
+fn zswap_with_stride_two() {
+    let c = |re, im| Complex::new(re, im);
+    let mut x = [c(1.0, 1.0), c(9.0, 9.0), c(2.0, 2.0), c(9.0, 9.0)];
+    let mut y = [c(-1.0, 0.0), c(8.0, 8.0), c(-2.0, 0.0), c(8.0, 8.0)];
+    unsafe { vector_c64::swap(2, x.as_mut_ptr(), 2, y.as_mut_ptr(), 2) };
+    assert_eq!(x, [c(-1.0, 0.0), c(9.0, 9.0), c(-2.0, 0.0), c(9.0, 9.0)]);
+    assert_eq!(y, [c(1.0, 1.0), c(8.0, 8.0), c(2.0, 2.0), c(8.0, 8.0)]);
+}
+
+#[test]
+fn dznrm2_and_dzasum_use_double_precision() {
+    // 3 + 0.1i and 0 + 4i: the 2-norm is sqrt(25.01), which f32 would round visibly.
+    let x = [Complex::new(3.0, 0.1), Complex::new(0.0, 4.0)];
+    let (norm2, norm1) = unsafe {
+        (
+            vector_c64::norm2(2, x.as_ptr(), 1),
+            vector_c64::norm1(2, x.as_ptr(), 1),
+        )
+    };
+    assert!((norm2 - 25.01f64.sqrt()).abs() <= 4.0 * f64::EPSILON * norm2);
+    assert!((norm1 - 7.1).abs() <= 4.0 * f64::EPSILON * norm1);
+
+    let exact = [Complex::new(3.0, 0.0), Complex::new(0.0, 4.0)];
+    assert_eq!(unsafe { vector_c64::norm2(2, exact.as_ptr(), 1) }, 5.0);
+}
+
+#[test]
+fn argmax_is_zero_based_and_prefers_first_tie() {
+    let empty: [Complex<f64>; 0] = [];
+    assert_eq!(vector_c64::argmax(&empty), None);
+    assert_eq!(vector_c64::argmax(&[Complex::new(0.0, -7.5)]), Some(0));
+    assert_eq!(
+        vector_c64::argmax(&[
+            Complex::new(1.0, 0.0),
+            Complex::new(-2.0, 1.0),
+            Complex::new(0.0, 3.0),
+            Complex::new(1.0, 1.0)
+        ]),
+        Some(1)
+    );
+}
+
+#[test]
+fn zaxpy_updates_in_place_and_rejects_unequal_lengths() {
+    let x = [Complex::new(1.0, 1.0), Complex::new(0.0, -2.0)];
+    let mut y = [Complex::new(1.0, 0.0); 2];
+    // alpha = i: i * (1 + i) = -1 + i, i * (-2i) = 2.
+    vector_c64::zaxpy(Complex::new(0.0, 1.0), &x, &mut y).unwrap();
+    assert_eq!(y, [Complex::new(0.0, 1.0), Complex::new(3.0, 0.0)]);
+
+    assert!(matches!(
+        vector_c64::zaxpy(Complex::new(1.0, 0.0), &x, &mut y[..1]),
+        Err(BlasError::DimensionMismatch { which: "y", .. })
+    ));
+}
+
+#[test]
+fn dznrm2_of_3_4_is_5_and_of_empty_is_0() {
+    assert_eq!(vector_c64::dznrm2(&[Complex::new(3.0, 4.0)]).unwrap(), 5.0);
+    assert_eq!(vector_c64::dznrm2(&[]).unwrap(), 0.0);
+    assert_eq!(
+        vector_c64::dznrm2_strided(
+            2,
+            &[
+                Complex::new(0.0, 3.0),
+                Complex::new(9.0, 9.0),
+                Complex::new(4.0, 0.0)
+            ],
+            2
+        )
+        .unwrap(),
+        5.0
+    );
+    assert_eq!(
+        vector_c64::dznrm2_strided(
+            2,
+            &[
+                Complex::new(0.0, 3.0),
+                Complex::new(9.0, 9.0),
+                Complex::new(4.0, 0.0)
+            ],
+            0
+        ),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+}
+
+#[test]
+fn zscal_and_zscal_real_match_a_manual_loop() {
+    let x = [
+        Complex::new(1.0, 2.0),
+        Complex::new(-0.5, 0.0),
+        Complex::new(0.0, -4.0),
+    ];
+    for alpha in [
+        Complex::new(0.0, 0.0),
+        Complex::new(-2.0, 0.0),
+        Complex::new(0.5, -1.0),
+    ] {
+        let mut y = x;
+        vector_c64::zscal(alpha, &mut y).unwrap();
+        let expected: Vec<Complex<f64>> = x.iter().map(|v| alpha * v).collect();
+        assert_eq!(y.to_vec(), expected);
+    }
+    for alpha in [0.0, -2.0] {
+        let mut y = x;
+        vector_c64::zscal_real(alpha, &mut y).unwrap();
+        let expected: Vec<Complex<f64>> = x.iter().map(|v| v * alpha).collect();
+        assert_eq!(y.to_vec(), expected);
+    }
+}
+
+#[test]
+fn zdscal_honours_increments() {
+    let mut x = [
+        Complex::new(1.0, -1.0),
+        Complex::new(7.0, 7.0),
+        Complex::new(2.0, 3.0),
+    ];
+    vector_c64::zdscal(2, -1.0, &mut x, 2).unwrap();
+    assert_eq!(
+        x,
+        [
+            Complex::new(-1.0, 1.0),
+            Complex::new(7.0, 7.0),
+            Complex::new(-2.0, -3.0)
+        ]
+    );
+    assert_eq!(
+        vector_c64::zdscal(2, 2.0, &mut x, 0),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+}
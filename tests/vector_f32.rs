@@ -0,0 +1,321 @@
+use accelerate_general::error::BlasError;
+use accelerate_general::vector_f32;
+
+#[test]
+fn cosine_similarity_of_parallel_and_orthogonal_vectors() {
+    let x = [1.0, 2.0, 2.0];
+    assert!((vector_f32::cosine_similarity(&x, &[2.0, 4.0, 4.0]).unwrap() - 1.0).abs() < 1e-6);
+    assert!((vector_f32::cosine_similarity(&x, &[-1.0, -2.0, -2.0]).unwrap() + 1.0).abs() < 1e-6);
+    assert!(
+        vector_f32::cosine_similarity(&x, &[2.0, -1.0, 0.0])
+            .unwrap()
+            .abs()
+            < 1e-6
+    );
+}
+
+#[test]
+fn cosine_similarity_of_zero_vector_is_zero() {
+    assert_eq!(
+        vector_f32::cosine_similarity(&[0.0, 0.0], &[1.0, 2.0]),
+        Ok(0.0)
+    );
+    assert_eq!(vector_f32::cosine_similarity(&[], &[]), Ok(0.0));
+}
+
+#[test]
+fn cosine_similarity_rejects_length_mismatch() {
+    assert_eq!(
+        vector_f32::cosine_similarity(&[1.0, 2.0], &[1.0]),
+        Err(BlasError::DimensionMismatch {
+            which: "y",
+            expected: 2,
+            got: 1
+        })
+    );
+}
+
+#[test]
+fn gather_reads_selected_elements() {
+    let src = [10.0, 20.0, 30.0, 40.0];
+    assert_eq!(
+        vector_f32::gather(&src, &[3, 0, 3]),
+        Ok(vec![40.0, 10.0, 40.0])
+    );
+    assert_eq!(
+        vector_f32::gather(&src, &[1, 4]),
+        Err(BlasError::IndexOutOfBounds {
+            which: "src",
+            index: 4,
+            len: 4
+        })
+    );
+}
+
+#[test]
+fn scatter_add_accumulates_repeated_indices() {
+    let mut dst = [1.0, 1.0, 1.0];
+    vector_f32::scatter_add(&mut dst, &[2, 0, 2], &[1.0, 2.0, 3.0]).unwrap();
+    assert_eq!(dst, [3.0, 1.0, 5.0]);
+}
+
+#[test]
+fn scatter_add_rejects_bad_input_without_writing() {
+    let mut dst = [0.0; 2];
+    assert_eq!(
+        vector_f32::scatter_add(&mut dst, &[0, 2], &[1.0, 1.0]),
+        Err(BlasError::IndexOutOfBounds {
+            which: "dst",
+            index: 2,
+            len: 2
+        })
+    );
+    assert_eq!(
+        vector_f32::scatter_add(&mut dst, &[0], &[1.0, 1.0]),
+        Err(BlasError::DimensionMismatch {
+            which: "values",
+            expected: 1,
+            got: 2
+        })
+    );
+    assert_eq!(dst, [0.0; 2]);
+}
+
+#[test]
+fn linear_combination_sums_scaled_vectors() {
+    let a = [1.0, 0.0, 2.0];
+    let b = [0.0, 1.0, 1.0];
+    let c = [1.0, 1.0, 1.0];
+    let out = vector_f32::linear_combination(&[2.0, -1.0, 0.5], &[&a, &b, &c]).unwrap();
+    assert_eq!(out, vec![2.5, -0.5, 3.5]);
+    assert_eq!(vector_f32::linear_combination(&[], &[]), Ok(vec![]));
+}
+
+#[test]
+fn linear_combination_rejects_ragged_input() {
+    assert_eq!(
+        vector_f32::linear_combination(&[1.0, 1.0], &[&[1.0, 2.0], &[1.0]]),
+        Err(BlasError::DimensionMismatch {
+            which: "vectors",
+            expected: 2,
+            got: 1
+        })
+    );
+    assert_eq!(
+        vector_f32::linear_combination(&[1.0], &[&[1.0], &[1.0]]),
+        Err(BlasError::DimensionMismatch {
+            which: "vectors",
+            expected: 1,
+            got: 2
+        })
+    );
+}
+
+#[test]
+fn axpy_and_copy_into_honour_increments() {
+    let x = [1.0, 9.0, 2.0, 9.0, 3.0];
+    let mut y = [10.0, 20.0, 30.0];
+    vector_f32::axpy(3, 2.0, &x, 2, &mut y, 1).unwrap();
+    assert_eq!(y, [12.0, 24.0, 36.0]);
+
+    let mut z = [0.0; 6];
+    vector_f32::copy_into(3, &y, 1, &mut z, 2).unwrap();
+    assert_eq!(z, [12.0, 0.0, 24.0, 0.0, 36.0, 0.0]);
+
+    assert_eq!(
+        vector_f32::copy_into(3, &y, 1, &mut z, 3),
+        Err(BlasError::BufferTooSmall {
+            which: "y",
+            needed: 7,
+            got: 6
+        })
+    );
+}
+
+#[test]
+fn isamax_nan_aware_selects_first_nan() {
+    assert_eq!(vector_f32::isamax_nan_aware(&[1.0, -7.0, 3.0], 1), Some(1));
+    assert_eq!(
+        vector_f32::isamax_nan_aware(&[1.0, -7.0, f32::NAN, 9.0, f32::NAN], 1),
+        Some(2)
+    );
+    // The NaN at position 1 is skipped by the stride.
+    assert_eq!(
+        vector_f32::isamax_nan_aware(&[1.0, f32::NAN, 2.0], 2),
+        Some(1)
+    );
+    assert_eq!(vector_f32::isamax_nan_aware(&[], 1), None);
+    assert_eq!(vector_f32::isamax_nan_aware(&[1.0], 0), None);
+}
+
+#[test]
+fn ema_update_blends_sample_into_average() {
+    let mut avg = [0.0, 10.0];
+    vector_f32::ema_update(0.25, &[4.0, 2.0], &mut avg).unwrap();
+    assert_eq!(avg, [1.0, 8.0]);
+    assert!(matches!(
+        vector_f32::ema_update(0.5, &[1.0], &mut avg),
+        Err(BlasError::DimensionMismatch { which: "x", .. })
+    ));
+}
+
+#[test]
+fn clamp_respects_stride_and_keeps_nan() {
+    let mut x = [-3.0, -3.0, 0.5, 9.0, f32::NAN, 9.0, 7.0];
+    vector_f32::clamp(&mut x, 2, -1.0, 1.0).unwrap();
+    assert_eq!(x[..4], [-1.0, -3.0, 0.5, 9.0]);
+    assert!(x[4].is_nan());
+    assert_eq!(x[5..], [9.0, 1.0]);
+
+    let mut y = [5.0f32];
+    vector_f32::clamp(&mut y, -1, 0.0, 1.0).unwrap();
+    assert_eq!(y, [5.0]);
+}
+
+#[test]
+fn clamp_rejects_bad_bounds_and_zero_increment() {
+    let mut x = [5.0f32];
+    let invalid = |which| Err(BlasError::InvalidBounds { which });
+    assert_eq!(vector_f32::clamp(&mut x, 1, 1.0, -1.0), invalid("min"));
+    assert_eq!(vector_f32::clamp(&mut x, 1, f32::NAN, 1.0), invalid("min"));
+    assert_eq!(vector_f32::clamp(&mut x, 1, 0.0, f32::NAN), invalid("max"));
+    assert_eq!(
+        vector_f32::clamp(&mut x, 0, 0.0, 1.0),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+    assert_eq!(x, [5.0]);
+}
+
+#[test]
+fn count_subnormals_ignores_zero_and_normals() {
+    let tiny = f32::MIN_POSITIVE / 4.0;
+    let x = [0.0, -0.0, tiny, -tiny, f32::MIN_POSITIVE, 1.0, f32::NAN];
+    assert_eq!(vector_f32::count_subnormals(&x), 2);
+}
+
+#[test]
+fn softmax_is_stable_for_large_logits() {
+    let mut x = [1000.0f32, 1000.0, 1000.0 + 2f32.ln()];
+    vector_f32::softmax(&mut x).unwrap();
+    for (v, e) in x.iter().zip(&[0.25, 0.25, 0.5]) {
+        assert!((v - e).abs() < 1e-4, "{:?}", x);
+    }
+    let mut empty: [f32; 0] = [];
+    vector_f32::softmax(&mut empty).unwrap();
+}
+
+#[test]
+fn softmax_handles_infinite_logits() {
+    let inf = f32::INFINITY;
+    let mut x = [inf, 3.0, -inf, inf];
+    vector_f32::softmax(&mut x).unwrap();
+    assert_eq!(x, [0.5, 0.0, 0.0, 0.5]);
+
+    // A -inf logit alongside finite ones just gets probability zero.
+    let mut x = [-inf, 0.0, 0.0];
+    vector_f32::softmax(&mut x).unwrap();
+    assert_eq!(x, [0.0, 0.5, 0.5]);
+
+    let mut x = [-inf, -inf];
+    vector_f32::softmax(&mut x).unwrap();
+    assert!(x.iter().all(|v| v.is_nan()));
+}
+
+#[test]
+fn strided_wrappers_reject_zero_increment() {
+    let x = [1.0f32; 2];
+    let mut y = [1.0f32; 2];
+    assert_eq!(
+        vector_f32::axpy(2, 1.0f32, &x, 0, &mut y, 1),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+    assert_eq!(
+        vector_f32::copy_into(2, &x, 1, &mut y, 0),
+        Err(BlasError::ZeroIncrement { which: "inc_y" })
+    );
+}
+
+#[test]
+fn argmax_is_zero_based_and_prefers_first_tie() {
+    let empty: [f32; 0] = [];
+    assert_eq!(vector_f32::argmax(&empty), None);
+    assert_eq!(vector_f32::argmax(&[-7.5]), Some(0));
+    assert_eq!(vector_f32::argmax(&[1.0, -3.0, 3.0, 2.0]), Some(1));
+}
+
+#[test]
+fn sdot_handles_orthogonal_strided_and_mismatched_vectors() {
+    assert_eq!(vector_f32::sdot(&[1.0, 2.0], &[-2.0, 1.0]), Ok(0.0));
+    // Every other element of x: (1, 3) . (4, 5) = 19.
+    assert_eq!(
+        vector_f32::sdot_strided(2, &[1.0, 9.0, 3.0], 2, &[4.0, 5.0], 1),
+        Ok(19.0)
+    );
+    assert_eq!(
+        vector_f32::sdot(&[1.0; 3], &[1.0; 2]),
+        Err(BlasError::DimensionMismatch {
+            which: "y",
+            expected: 3,
+            got: 2
+        })
+    );
+    assert_eq!(
+        vector_f32::sdot_strided(3, &[1.0; 4], 2, &[1.0; 3], 1),
+        Err(BlasError::BufferTooSmall {
+            which: "x",
+            needed: 5,
+            got: 4
+        })
+    );
+}
+
+#[test]
+fn saxpy_updates_in_place_and_rejects_unequal_lengths() {
+    let x = [1.0, -2.0, 0.5];
+    let mut y = [10.0, 10.0, 10.0];
+    vector_f32::saxpy(2.0, &x, &mut y).unwrap();
+    assert_eq!(y, [12.0, 6.0, 11.0]);
+
+    assert!(matches!(
+        vector_f32::saxpy(2.0, &x[..2], &mut y),
+        Err(BlasError::DimensionMismatch { which: "y", .. })
+    ));
+    assert_eq!(y, [12.0, 6.0, 11.0]);
+}
+
+#[test]
+fn snrm2_of_3_4_is_5_and_of_empty_is_0() {
+    assert_eq!(vector_f32::snrm2(&[3.0f32, 4.0]).unwrap(), 5.0);
+    assert_eq!(vector_f32::snrm2(&[]).unwrap(), 0.0);
+    assert_eq!(
+        vector_f32::snrm2_strided(2, &[3.0f32, 9.0, 4.0], 2).unwrap(),
+        5.0
+    );
+    assert_eq!(
+        vector_f32::snrm2_strided(2, &[3.0f32, 9.0, 4.0], 0),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+}
+
+#[test]
+fn sscal_matches_a_manual_loop() {
+    let x = [1.5, -2.0, 0.25, 8.0];
+    for alpha in [0.0, -3.0, 0.5] {
+        let mut y = x;
+        vector_f32::sscal(alpha, &mut y).unwrap();
+        let expected: Vec<f32> = x.iter().map(|v| alpha * v).collect();
+        assert_eq!(y.to_vec(), expected);
+    }
+    vector_f32::sscal(2.0, &mut []).unwrap();
+}
+
+#[test]
+fn cosine_similarity_of_tiny_and_huge_parallel_vectors() {
+    // The product of the norms underflows (1e-50) or overflows (1e40) in f32.
+    let tiny = [1e-25f32, 2e-25, 2e-25];
+    let cos = vector_f32::cosine_similarity(&tiny, &tiny).unwrap();
+    assert!((cos - 1.0).abs() < 1e-6, "{}", cos);
+    let huge = [1e20f32, 2e20, 2e20];
+    let cos = vector_f32::cosine_similarity(&huge, &[2e20, 4e20, 4e20]).unwrap();
+    assert!((cos - 1.0).abs() < 1e-6, "{}", cos);
+}
@@ -0,0 +1,175 @@
+use accelerate_general::error::BlasError;
+use accelerate_general::vector_f64;
+
+#[test]
+fn cosine_similarity_matches_definition() {
+    let x = [3.0, 4.0];
+    let y = [4.0, 3.0];
+    let expected = 24.0 / 25.0;
+    assert!((vector_f64::cosine_similarity(&x, &y).unwrap() - expected).abs() < 1e-12);
+    assert_eq!(vector_f64::cosine_similarity(&x, &[0.0, 0.0]), Ok(0.0));
+}
+
+#[test]
+fn clamp_saturates_contiguous_vector() {
+    let mut x = [-2.0, 0.25, 2.0];
+    vector_f64::clamp(&mut x, 1, 0.0, 1.0).unwrap();
+    assert_eq!(x, [0.0, 0.25, 1.0]);
+    assert_eq!(
+        vector_f64::clamp(&mut x, 1, 1.0, 0.0),
+        Err(BlasError::InvalidBounds { which: "min" })
+    );
+    assert_eq!(
+        vector_f64::clamp(&mut x, 0, 0.0, 1.0),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+}
+
+#[test]
+fn softmax_is_stable_for_large_logits() {
+    let mut x = [1000.0f64, 1000.0, 1000.0 + 2f64.ln()];
+    vector_f64::softmax(&mut x).unwrap();
+    for (v, e) in x.iter().zip(&[0.25, 0.25, 0.5]) {
+        assert!((v - e).abs() < 1e-6, "{:?}", x);
+    }
+    let mut empty: [f64; 0] = [];
+    vector_f64::softmax(&mut empty).unwrap();
+}
+
+#[test]
+fn softmax_handles_infinite_logits() {
+    let inf = f64::INFINITY;
+    let mut x = [inf, 3.0, -inf, inf];
+    vector_f64::softmax(&mut x).unwrap();
+    assert_eq!(x, [0.5, 0.0, 0.0, 0.5]);
+
+    // A -inf logit alongside finite ones just gets probability zero.
+    let mut x = [-inf, 0.0, 0.0];
+    vector_f64::softmax(&mut x).unwrap();
+    assert_eq!(x, [0.0, 0.5, 0.5]);
+
+    let mut x = [-inf, -inf];
+    vector_f64::softmax(&mut x).unwrap();
+    assert!(x.iter().all(|v| v.is_nan()));
+}
+
+#[test]
+fn strided_wrappers_reject_zero_increment() {
+    let x = [1.0f64; 2];
+    let mut y = [1.0f64; 2];
+    assert_eq!(
+        vector_f64::axpy(2, 1.0f64, &x, 0, &mut y, 1),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+    assert_eq!(
+        vector_f64::copy_into(2, &x, 1, &mut y, 0),
+        Err(BlasError::ZeroIncrement { which: "inc_y" })
+    );
+}
+
+#[test]
+fn dnrm2_of_known_vector() {
+    let x = [3.0, 0.0, 4.0, 12.0];
+    let norm = unsafe { vector_f64::norm2(4, x.as_ptr(), 1) };
+    assert!((norm - 13.0).abs() < 1e-12);
+    // Every other element: |(3, 4)| = 5.
+    let norm = unsafe { vector_f64::norm2(2, x.as_ptr(), 2) };
+    assert!((norm - 5.0).abs() < 1e-12);
+}
+
+#[test]
+fn dswap_and_dscal_with_non_unit_stride() {
+    let mut x = [1.0, -1.0, 2.0, -2.0, 3.0];
+    let mut y = [10.0, 20.0, 30.0];
+    unsafe {
+        vector_f64::swap(3, x.as_mut_ptr(), 2, y.as_mut_ptr(), 1);
+        vector_f64::scale(2, 0.5, y.as_mut_ptr(), 2);
+    }
+    assert_eq!(x, [10.0, -1.0, 20.0, -2.0, 30.0]);
+    assert_eq!(y, [0.5, 2.0, 1.5]);
+}
+
+#[test]
+fn argmax_is_zero_based_and_prefers_first_tie() {
+    let empty: [f64; 0] = [];
+    assert_eq!(vector_f64::argmax(&empty), None);
+    assert_eq!(vector_f64::argmax(&[-7.5]), Some(0));
+    assert_eq!(vector_f64::argmax(&[1.0, -3.0, 3.0, 2.0]), Some(1));
+}
+
+#[test]
+fn ddot_handles_orthogonal_strided_and_mismatched_vectors() {
+    assert_eq!(vector_f64::ddot(&[1.0, 2.0], &[-2.0, 1.0]), Ok(0.0));
+    // Every other element of x: (1, 3) . (4, 5) = 19.
+    assert_eq!(
+        vector_f64::ddot_strided(2, &[1.0, 9.0, 3.0], 2, &[4.0, 5.0], 1),
+        Ok(19.0)
+    );
+    assert_eq!(
+        vector_f64::ddot(&[1.0; 3], &[1.0; 2]),
+        Err(BlasError::DimensionMismatch {
+            which: "y",
+            expected: 3,
+            got: 2
+        })
+    );
+    assert_eq!(
+        vector_f64::ddot_strided(3, &[1.0; 4], 2, &[1.0; 3], 1),
+        Err(BlasError::BufferTooSmall {
+            which: "x",
+            needed: 5,
+            got: 4
+        })
+    );
+}
+
+#[test]
+fn daxpy_updates_in_place_and_rejects_unequal_lengths() {
+    let x = [1.0, -2.0, 0.5];
+    let mut y = [10.0, 10.0, 10.0];
+    vector_f64::daxpy(2.0, &x, &mut y).unwrap();
+    assert_eq!(y, [12.0, 6.0, 11.0]);
+
+    assert!(matches!(
+        vector_f64::daxpy(2.0, &x[..2], &mut y),
+        Err(BlasError::DimensionMismatch { which: "y", .. })
+    ));
+    assert_eq!(y, [12.0, 6.0, 11.0]);
+}
+
+#[test]
+fn dnrm2_of_3_4_is_5_and_of_empty_is_0() {
+    assert_eq!(vector_f64::dnrm2(&[3.0, 4.0]).unwrap(), 5.0);
+    assert_eq!(vector_f64::dnrm2(&[]).unwrap(), 0.0);
+    assert_eq!(
+        vector_f64::dnrm2_strided(2, &[3.0, 9.0, 4.0], 2).unwrap(),
+        5.0
+    );
+    assert_eq!(
+        vector_f64::dnrm2_strided(2, &[3.0, 9.0, 4.0], 0),
+        Err(BlasError::ZeroIncrement { which: "inc_x" })
+    );
+}
+
+#[test]
+fn dscal_matches_a_manual_loop() {
+    let x = [1.5, -2.0, 0.25, 8.0];
+    for alpha in [0.0, -3.0, 0.5] {
+        let mut y = x;
+        vector_f64::dscal(alpha, &mut y).unwrap();
+        let expected: Vec<f64> = x.iter().map(|v| alpha * v).collect();
+        assert_eq!(y.to_vec(), expected);
+    }
+    vector_f64::dscal(2.0, &mut []).unwrap();
+}
+
+#[test]
+fn cosine_similarity_of_tiny_and_huge_parallel_vectors() {
+    // The product of the norms underflows (1e-400) or overflows (1e400) in f64.
+    let tiny = [1e-200, 2e-200, 2e-200];
+    let cos = vector_f64::cosine_similarity(&tiny, &tiny).unwrap();
+    assert!((cos - 1.0).abs() < 1e-12, "{}", cos);
+    let huge = [1e200, 2e200, 2e200];
+    let cos = vector_f64::cosine_similarity(&huge, &[2e200, 4e200, 4e200]).unwrap();
+    assert!((cos - 1.0).abs() < 1e-12, "{}", cos);
+}
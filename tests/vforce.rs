@@ -0,0 +1,31 @@
+use accelerate_general::error::BlasError;
+use accelerate_general::vforce;
+
+#[test]
+fn exp_and_log_are_inverse() {
+    let x = [0.0f32, 1.0, -2.5];
+    let mut e = [0.0f32; 3];
+    vforce::exp(&x, &mut e).unwrap();
+    assert!((e[1] - std::f32::consts::E).abs() < 1e-6);
+    let mut back = [0.0f32; 3];
+    vforce::log(&e, &mut back).unwrap();
+    for (b, x) in back.iter().zip(&x) {
+        assert!((b - x).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn sqrt_handles_f64_and_rejects_mismatched_lengths() {
+    let mut dst = [0.0f64; 3];
+    vforce::sqrt(&[4.0, 0.0, -1.0], &mut dst).unwrap();
+    assert_eq!(&dst[..2], &[2.0, 0.0]);
+    assert!(dst[2].is_nan());
+    assert_eq!(
+        vforce::exp(&[1.0f64; 2], &mut dst),
+        Err(BlasError::DimensionMismatch {
+            which: "dst",
+            expected: 2,
+            got: 3
+        })
+    );
+}
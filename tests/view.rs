@@ -0,0 +1,209 @@
+use accelerate_general::constants::{CblasOrder, CblasTranspose};
+use accelerate_general::error::BlasError;
+use accelerate_general::matrix_f32;
+use accelerate_general::scalar::gemm_views;
+use accelerate_general::view::{MatrixView, MatrixViewMut, SharedMatrix};
+
+#[test]
+fn new_validates_leading_dimension_and_length() {
+    let data = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+    assert_eq!(
+        MatrixView::new(&data, 2, 3, 2, CblasOrder::RowMajor).err(),
+        Some(BlasError::InvalidLeadingDimension {
+            which: "lda",
+            min: 3,
+            got: 2
+        })
+    );
+    assert_eq!(
+        MatrixView::new(&data, 3, 3, 3, CblasOrder::RowMajor).err(),
+        Some(BlasError::BufferTooSmall {
+            which: "data",
+            needed: 9,
+            got: 6
+        })
+    );
+}
+
+#[test]
+fn from_raw_borrows_external_buffer() {
+    // A 2 x 2 column-major sub-matrix of a 3 x 2 buffer (lda = 3).
+    let buffer = [1.0f64, 2.0, 0.0, 3.0, 4.0, 0.0];
+    let view = unsafe { MatrixView::from_raw(buffer.as_ptr(), 2, 2, 3, CblasOrder::ColMajor) };
+    assert_eq!(view.as_slice().len(), 5);
+    assert_eq!(view.as_ptr(), buffer.as_ptr());
+    assert_eq!(view.get(0, 1), Some(&3.0));
+    assert_eq!(view.get(1, 1), Some(&4.0));
+    assert_eq!(view.get(2, 0), None);
+}
+
+#[test]
+fn columns_follow_storage_order() {
+    // [[1, 2], [3, 4], [5, 6]] row-major with one padding element per row.
+    let row_major = [1.0f32, 2.0, 0.0, 3.0, 4.0, 0.0, 5.0, 6.0];
+    let view = MatrixView::new(&row_major, 3, 2, 3, CblasOrder::RowMajor).unwrap();
+    let cols: Vec<Vec<f32>> = view
+        .columns()
+        .map(|c| c.iter().copied().collect())
+        .collect();
+    assert_eq!(cols, [[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]);
+    let second = view.columns().nth(1).unwrap();
+    assert_eq!(
+        (second.len(), second.inc(), second.get(2)),
+        (3, 3, Some(&6.0))
+    );
+
+    let col_major = [1.0f32, 3.0, 5.0, 2.0, 4.0, 6.0];
+    let view = MatrixView::new(&col_major, 3, 2, 3, CblasOrder::ColMajor).unwrap();
+    let second = view.columns().nth(1).unwrap();
+    assert_eq!(second.as_slice(), &[2.0, 4.0, 6.0]);
+    assert_eq!(second.inc(), 1);
+}
+
+#[test]
+fn columns_mut_writes_interleaved_columns() {
+    let mut data = [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let mut view = MatrixViewMut::new(&mut data, 2, 3, 3, CblasOrder::RowMajor).unwrap();
+    let mut cols: Vec<_> = view.columns_mut().collect();
+    // All columns are held at once; scale column j by j + 1.
+    for (j, col) in cols.iter_mut().enumerate() {
+        col.iter_mut().for_each(|x| *x *= (j + 1) as f64);
+    }
+    *cols[0].get_mut(1).unwrap() = -1.0;
+    assert_eq!(data, [1.0, 4.0, 9.0, -1.0, 10.0, 18.0]);
+}
+
+#[test]
+fn shared_matrix_is_read_from_many_threads() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SharedMatrix<f32>>();
+
+    // [[1, 2, 3], [4, 5, 6]]
+    let a = SharedMatrix::new(
+        vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0],
+        2,
+        3,
+        3,
+        CblasOrder::RowMajor,
+    )
+    .unwrap();
+    let handles: Vec<_> = (0..4)
+        .map(|t| {
+            let a = a.clone();
+            std::thread::spawn(move || {
+                let x = [t as f32, 1.0, 0.0];
+                let mut y = [0.0f32; 2];
+                let view = a.view();
+                unsafe {
+                    matrix_f32::mat_vec_mul(
+                        view.order(),
+                        CblasTranspose::NoTrans,
+                        view.rows() as i32,
+                        view.cols() as i32,
+                        1.0,
+                        view.as_ptr(),
+                        view.lda() as i32,
+                        x.as_ptr(),
+                        1,
+                        0.0,
+                        y.as_mut_ptr(),
+                        1,
+                    );
+                }
+                y
+            })
+        })
+        .collect();
+    for (t, handle) in handles.into_iter().enumerate() {
+        let t = t as f32;
+        assert_eq!(handle.join().unwrap(), [t + 2.0, 4.0 * t + 5.0]);
+    }
+}
+
+#[test]
+fn display_prints_logical_rows_aligned() {
+    // [[1, -2, 3], [4, 5, 6]] stored column-major with padding (lda = 3).
+    let data = [1, 4, 0, -2, 5, 0, 3, 6];
+    let view = MatrixView::new(&data, 2, 3, 3, CblasOrder::ColMajor).unwrap();
+    assert_eq!(view.to_string(), "2x3\n1  -2  3\n4   5  6\n");
+    assert_eq!(view.columns().nth(1).unwrap().to_string(), "[-2, 5]");
+
+    let halves = [0.5f64, 0.25];
+    let view = MatrixView::new(&halves, 1, 2, 2, CblasOrder::RowMajor).unwrap();
+    assert_eq!(format!("{:.1}", view), "1x2\n0.5  0.2\n");
+}
+
+#[test]
+fn display_elides_beyond_ten_rows_and_columns() {
+    let data: Vec<u32> = (0..12 * 11).collect();
+    let view = MatrixView::new(&data, 12, 11, 11, CblasOrder::RowMajor).unwrap();
+    let text = view.to_string();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 1 + 10 + 1);
+    assert_eq!(lines[0], "12x11");
+    assert!(lines[1].ends_with("9  ..."));
+    assert_eq!(lines[11], "...");
+    let first_col = view.columns().next().unwrap().to_string();
+    assert!(first_col.ends_with("99, ...]"));
+}
+
+#[test]
+fn flat_tuple_round_trip_destrides_column_major() {
+    // [[1, 2], [3, 4], [5, 6]] column-major with one padding element per column (lda = 4).
+    let data = [1.0f32, 3.0, 5.0, 0.0, 2.0, 4.0, 6.0];
+    let view = MatrixView::new(&data, 3, 2, 4, CblasOrder::ColMajor).unwrap();
+    let flat: (Vec<f32>, usize, usize) = view.into();
+    assert_eq!(flat, (vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3, 2));
+
+    let back = MatrixView::try_from(&flat).unwrap();
+    assert_eq!((back.rows(), back.cols(), back.lda()), (3, 2, 2));
+    assert_eq!(back.get(2, 1), Some(&6.0));
+
+    let short = (vec![1.0f32; 5], 3, 2);
+    assert_eq!(
+        MatrixView::try_from(&short).err(),
+        Some(BlasError::DimensionMismatch {
+            which: "data",
+            expected: 6,
+            got: 5
+        })
+    );
+}
+
+#[test]
+fn gemm_views_multiplies_sub_matrices_in_place() {
+    // A 4 x 4 column-major buffer (lda = 4) with entries 10 * row + col.
+    let big: Vec<f64> = (0..16).map(|i| (10 * (i % 4) + i / 4) as f64).collect();
+    // Rows 1..3 and columns 1..4: [[11, 12, 13], [21, 22, 23]].
+    let a = MatrixView::new(&big[5..], 2, 3, 4, CblasOrder::ColMajor).unwrap();
+    // Rows 0..3 and columns 2..4: [[2, 3], [12, 13], [22, 23]].
+    let b = MatrixView::new(&big[8..], 3, 2, 4, CblasOrder::ColMajor).unwrap();
+    let mut out = [0.0; 4];
+    let mut c = MatrixViewMut::new(&mut out, 2, 2, 2, CblasOrder::ColMajor).unwrap();
+    gemm_views(1.0, a, b, 0.0, &mut c).unwrap();
+    // Column-major [[452, 488], [812, 878]].
+    assert_eq!(out, [452.0, 812.0, 488.0, 878.0]);
+}
+
+#[test]
+fn transposed_view_swaps_shape_without_copying() {
+    let data = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let a = MatrixView::new(&data, 2, 3, 3, CblasOrder::RowMajor).unwrap();
+    let t = a.transposed();
+    assert_eq!((t.rows(), t.cols(), t.lda()), (3, 2, 3));
+    assert_eq!(t.order(), CblasOrder::ColMajor);
+    assert_eq!(t.as_ptr(), a.as_ptr());
+    assert_eq!(t.get(2, 1), a.get(1, 2));
+
+    // A^T * A through the transposed view: [[17, 22, 27], [22, 29, 36], [27, 36, 45]].
+    let mut out = [0.0f32; 9];
+    let mut c = MatrixViewMut::new(&mut out, 3, 3, 3, CblasOrder::RowMajor).unwrap();
+    gemm_views(1.0, t, a, 0.0, &mut c).unwrap();
+    assert_eq!(out, [17.0, 22.0, 27.0, 22.0, 29.0, 36.0, 27.0, 36.0, 45.0]);
+
+    let mut c = MatrixViewMut::new(&mut out, 3, 3, 3, CblasOrder::RowMajor).unwrap();
+    assert!(matches!(
+        gemm_views(1.0, a, a, 0.0, &mut c),
+        Err(BlasError::DimensionMismatch { which: "b", .. })
+    ));
+}
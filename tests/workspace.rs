@@ -0,0 +1,52 @@
+use accelerate_general::constants::SvdJob;
+use accelerate_general::error::BlasError;
+use accelerate_general::matrix_f64;
+use accelerate_general::workspace::{LapackRoutine, LapackWorkspace};
+
+#[test]
+fn reserve_for_only_grows() {
+    let mut ws = LapackWorkspace::new();
+    assert!(ws.work_mut().is_empty());
+    ws.reserve_for(LapackRoutine::Dgesvd(SvdJob::None, SvdJob::None), (20, 10))
+        .unwrap();
+    let large = ws.work_mut().len();
+    assert!(large > 0);
+    ws.reserve_for(LapackRoutine::Dgesvd(SvdJob::None, SvdJob::None), (2, 2))
+        .unwrap();
+    assert_eq!(ws.work_mut().len(), large);
+    assert!(ws.iwork_mut().is_empty());
+    assert!(ws.rwork_mut().is_empty());
+}
+
+#[test]
+fn cond_2norm_with_reuses_workspace() {
+    let mut ws = LapackWorkspace::new();
+    let a = [4.0, 0.0, 0.0, 1.0];
+    assert_eq!(matrix_f64::cond_2norm_with(&mut ws, &a, 2, 2), Ok(4.0));
+    let ptr = ws.work_mut().as_ptr();
+    let b = [2.0, 0.0, 0.0, 1.0];
+    assert_eq!(matrix_f64::cond_2norm_with(&mut ws, &b, 2, 2), Ok(2.0));
+    assert_eq!(ws.work_mut().as_ptr(), ptr);
+}
+
+#[test]
+fn reserve_for_rejects_overwriting_both_sets_of_vectors() {
+    let mut ws = LapackWorkspace::new();
+    assert_eq!(
+        ws.reserve_for(
+            LapackRoutine::Dgesvd(SvdJob::Overwrite, SvdJob::Overwrite),
+            (4, 3)
+        ),
+        Err(BlasError::InvalidFlag {
+            which: "jobvt",
+            flag: 'O'
+        })
+    );
+    assert!(ws.work_mut().is_empty());
+    ws.reserve_for(
+        LapackRoutine::Dgesvd(SvdJob::Overwrite, SvdJob::None),
+        (4, 3),
+    )
+    .unwrap();
+    assert!(!ws.work_mut().is_empty());
+}